@@ -1,24 +1,51 @@
 use crate::auto::{
-    AutoProfile, VerifyIntegrity, WindowMetrics, adapt_concurrency, build_auto_plan,
-    clamp_part_size_for_limit, is_instant_copy, optimize_part_size_for_cost,
-    tune_part_size_from_probe,
+    AutoProfile, DataPath, OnInterrupt, OnSourceChange, VerifyIntegrity, WindowMetrics,
+    adapt_concurrency, build_auto_plan, clamp_part_size_for_limit, is_instant_copy,
+    optimize_part_size_for_cost, tune_part_size_from_probe,
+};
+use crate::control::{Interrupted, TransferControl};
+use crate::events::{CopyAction, DataRoute, EventSink, ProgressEvent, RunSummary, Verification};
+use crate::inventory::{
+    self, InventoryFile, InventoryFormat, InventoryManifest, InventoryRow, parse_s3_uri,
+};
+use crate::journal::{
+    DEFAULT_JOURNAL_DIR, JournalKey, PlannedPart, UploadJournal, UploadMarker, load_journal,
+};
+use crate::manifest::ManifestEntry;
+use crate::progress::{CopyProgress, ListingProgress};
+use crate::report::{ReportEntry, RunReport};
+use crate::s3_utils::{ClientConfig, ClientPool, region_from_location, requires_streaming};
+use crate::throttle::Throttle;
+use crate::uri;
+use crate::verify::{
+    DEEP_VERIFY_RANGE_SIZE, DigestAlgorithm, RangeDigest, digest_range, first_mismatch,
+    multipart_etag,
 };
-use crate::progress::CopyProgress;
 use anyhow::{Context, Result};
+use async_compression::tokio::bufread::GzipDecoder;
+use aws_sdk_s3::Client;
+use aws_sdk_s3::config::http::HttpResponse;
+use aws_sdk_s3::error::{ProvideErrorMetadata, SdkError};
+use aws_sdk_s3::operation::copy_object::CopyObjectError;
 use aws_sdk_s3::operation::head_object::HeadObjectOutput;
+use aws_sdk_s3::operation::upload_part_copy::UploadPartCopyError;
+use aws_sdk_s3::primitives::ByteStream;
+use aws_sdk_s3::types::builders::CompletedPartBuilder;
 use aws_sdk_s3::types::{
-    ChecksumAlgorithm, CompletedPart, ObjectCannedAcl, ServerSideEncryption, StorageClass, Tag,
-    Tagging,
+    ChecksumAlgorithm, ChecksumMode, ChecksumType, CompletedPart, Delete, ObjectAttributes,
+    ObjectCannedAcl, ObjectIdentifier, ServerSideEncryption, StorageClass, Tag, Tagging,
 };
-use aws_sdk_s3::{Client, config::Region};
-use aws_smithy_runtime::client::http::hyper_014::HyperClientBuilder;
-use aws_smithy_types::retry::RetryConfig;
 use globset::{Glob, GlobSet, GlobSetBuilder};
-use indicatif::{ProgressBar, ProgressStyle};
-use std::sync::{Arc, atomic::Ordering};
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+};
 use std::time::{Duration, Instant};
-use tokio::sync::Semaphore;
-use tokio::task;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore, mpsc};
+use tokio::task::{self, JoinSet};
 
 /// Main application structure
 #[derive(Clone)]
@@ -47,12 +74,304 @@ pub struct S3CopyApp {
     pub sse: Option<ServerSideEncryption>,
     pub sse_kms_key_id: Option<String>,
     filters: Option<KeyFilter>,
+    resume: bool,
+    journal_dir: PathBuf,
+    object_concurrency: usize,
+    part_slots: Option<Arc<Semaphore>>,
+    delete_extraneous: bool,
+    max_delete: Option<usize>,
+    separate_identities: bool,
+    data_path: DataPath,
+    streaming: Arc<AtomicBool>,
+    buffer_budget: Arc<Semaphore>,
+    buffer_budget_mb: u32,
+    events: Option<EventSink>,
+    report: Option<RunReport>,
+    source_version_id: Option<String>,
+    /// ETag from the initial HeadObject; every source read is conditional on it
+    source_etag: Option<String>,
+    /// Part layout of a multipart source, discovered with `--match-source-parts`
+    source_layout: Option<SourceLayout>,
+    /// Checksum type adopted from the source along with its algorithm
+    checksum_type: Option<ChecksumType>,
+    /// Inventory entry of the source, carrying its ETag and storage class
+    listed_source: Option<ListedObject>,
+    on_source_change: OnSourceChange,
+    dest_guard: Option<DestinationGuard>,
+    match_source_parts: bool,
+    throttle: Throttle,
+    control: TransferControl,
+    on_interrupt: OnInterrupt,
 }
 
 #[derive(Clone, Debug)]
 pub struct ListedObject {
     pub key: String,
     pub size: i64,
+    /// Explicit destination key (manifest input); otherwise mapped from the prefixes
+    pub dest_key: Option<String>,
+    /// Specific source version (all-versions mode)
+    pub version_id: Option<String>,
+    /// ETag and storage class reported by an inventory; when present, an identical
+    /// destination is detected without reading the source
+    pub e_tag: Option<String>,
+    pub storage_class: Option<String>,
+}
+
+const LISTING_CHANNEL_CAPACITY: usize = 2_000;
+const DELETE_BATCH_SIZE: usize = 1_000;
+
+/// What `copy_file` did for one object.
+#[derive(Copy, Clone, Debug)]
+pub struct CopyOutcome {
+    pub action: CopyAction,
+    pub size: i64,
+    /// Parts of the multipart upload; 0 for the other actions
+    pub parts: usize,
+    /// How the data travelled; `None` when no data was copied (skips, tag syncs)
+    pub data_path: Option<DataRoute>,
+    pub verification: Verification,
+}
+
+/// The copy completed but the destination does not match the source.
+#[derive(Debug)]
+pub struct VerificationFailed(pub String);
+
+impl std::fmt::Display for VerificationFailed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Verification failed: {}", self.0)
+    }
+}
+
+impl std::error::Error for VerificationFailed {}
+
+/// Some objects of a prefix, inventory or manifest copy failed; carries the run totals.
+#[derive(Debug)]
+pub struct ObjectsFailed(pub RunSummary);
+
+impl std::fmt::Display for ObjectsFailed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} objects failed to copy", self.0.failed)
+    }
+}
+
+impl std::error::Error for ObjectsFailed {}
+
+/// The source no longer matches the ETag captured when the copy started.
+#[derive(Debug)]
+pub struct SourceChanged(pub String);
+
+impl std::fmt::Display for SourceChanged {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Source changed during copy: {}", self.0)
+    }
+}
+
+impl std::error::Error for SourceChanged {}
+
+/// The destination was written by someone else, so the copy did not overwrite it.
+#[derive(Debug)]
+pub struct DestinationExists(pub String);
+
+impl std::fmt::Display for DestinationExists {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Destination exists: {}", self.0)
+    }
+}
+
+impl std::error::Error for DestinationExists {}
+
+/// Conditional write applied to the destination object.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DestinationGuard {
+    /// Only create the destination (`If-None-Match: *`)
+    NoClobber,
+    /// Only replace a destination with this (quoted) ETag (`If-Match`)
+    IfETag(String),
+}
+
+impl DestinationGuard {
+    pub fn if_etag(etag: &str) -> Self {
+        Self::IfETag(format!("\"{}\"", etag.trim_matches('"')))
+    }
+}
+
+/// Restarts allowed per object with `--on-source-change restart`.
+const MAX_SOURCE_CHANGE_RESTARTS: usize = 3;
+
+/// True when S3 rejected a conditional request (HTTP 412).
+fn is_precondition_failed<E: ProvideErrorMetadata, R>(err: &SdkError<E, R>) -> bool {
+    err.code() == Some("PreconditionFailed")
+}
+
+/// True when `err` wraps a server-side copy (CopyObject or UploadPartCopy) that S3
+/// rejected with `AccessDenied`.
+fn is_copy_access_denied(err: &anyhow::Error) -> bool {
+    err.chain().any(|cause| {
+        let code = if let Some(e) =
+            cause.downcast_ref::<SdkError<UploadPartCopyError, HttpResponse>>()
+        {
+            e.code()
+        } else if let Some(e) = cause.downcast_ref::<SdkError<CopyObjectError, HttpResponse>>() {
+            e.code()
+        } else {
+            None
+        };
+        code == Some("AccessDenied")
+    })
+}
+
+/// Outcome of copying one object in prefix mode.
+struct ObjectResult {
+    source_key: String,
+    dest_key: String,
+    /// Size counted in the listing totals (0 when the manifest did not give one)
+    listed_size: i64,
+    size: i64,
+    retries: usize,
+    action: Option<CopyAction>,
+    duration: Duration,
+    verification: Verification,
+    error: Option<String>,
+}
+
+impl ObjectResult {
+    /// Final state of a copy attempt that may have failed.
+    fn from_attempt(
+        source_key: String,
+        dest_key: String,
+        size: i64,
+        retries: usize,
+        duration: Duration,
+        attempt: &Result<CopyOutcome>,
+    ) -> Self {
+        match attempt {
+            Ok(outcome) => Self {
+                source_key,
+                dest_key,
+                listed_size: size,
+                size: outcome.size,
+                retries,
+                action: Some(outcome.action),
+                duration,
+                verification: outcome.verification,
+                error: None,
+            },
+            Err(e) => Self {
+                source_key,
+                dest_key,
+                listed_size: size,
+                size,
+                retries,
+                action: None,
+                duration,
+                verification: if e.downcast_ref::<VerificationFailed>().is_some() {
+                    Verification::Failed
+                } else {
+                    Verification::NotRun
+                },
+                error: Some(e.to_string()),
+            },
+        }
+    }
+}
+
+/// Running counters for a prefix copy, updated as object workers finish.
+#[derive(Default)]
+struct PrefixStats {
+    processed: usize,
+    copied: usize,
+    skipped: usize,
+    failed: usize,
+    retried: usize,
+    bytes_copied: i64,
+    bytes_processed: i64,
+    error_samples: Vec<String>,
+}
+
+impl PrefixStats {
+    fn record(&mut self, result: &ObjectResult) {
+        self.processed += 1;
+        self.retried += result.retries;
+        self.bytes_processed += result.size;
+        match &result.error {
+            None => {
+                self.copied += 1;
+                self.bytes_copied += result.size;
+                if result.action == Some(CopyAction::Skipped) {
+                    self.skipped += 1;
+                }
+            }
+            Some(e) => {
+                self.failed += 1;
+                if self.error_samples.len() < 5 {
+                    self.error_samples.push(format!(
+                        "{} -> {}: {}",
+                        result.source_key, result.dest_key, e
+                    ));
+                }
+            }
+        }
+    }
+
+    fn summary(&self, total: usize, deleted: usize, elapsed: Duration) -> RunSummary {
+        RunSummary {
+            total,
+            processed: self.processed,
+            copied: self.copied - self.skipped,
+            skipped: self.skipped,
+            failed: self.failed,
+            retried: self.retried,
+            deleted,
+            bytes_copied: self.bytes_copied,
+            elapsed_ms: elapsed.as_millis() as u64,
+        }
+    }
+
+    fn progress_line(&self, listing: &ListingProgress, elapsed: Duration) -> String {
+        let total_objects = listing.objects();
+        let total_bytes = listing.bytes();
+        let file_pct = (self.processed as f64 / total_objects.max(1) as f64) * 100.0;
+        let byte_pct = if total_bytes <= 0 {
+            100.0
+        } else {
+            (self.bytes_processed as f64 / total_bytes as f64) * 100.0
+        };
+        let throughput_mib = if elapsed.as_secs_f64() > 0.0 {
+            (self.bytes_processed as f64 / (1024.0 * 1024.0)) / elapsed.as_secs_f64()
+        } else {
+            0.0
+        };
+        // ETA against a partial listing would only ever undershoot.
+        let eta = if listing.is_complete() {
+            eta_from_progress(elapsed, self.bytes_processed, total_bytes)
+                .map(|d| format!("{}s", d.as_secs()))
+                .unwrap_or_else(|| "n/a".to_string())
+        } else {
+            "n/a (listing)".to_string()
+        };
+        let bytes_label = if listing.is_complete() {
+            total_bytes.to_string()
+        } else {
+            format!("{}+", total_bytes)
+        };
+        format!(
+            "files {}/{} ({:.1}%), bytes {} / {} ({:.1}%), copied={}, skipped={}, failed={}, retried={}, elapsed={}s, throughput={:.2} MiB/s, eta={}",
+            self.processed,
+            listing.objects_label(),
+            file_pct,
+            self.bytes_processed,
+            bytes_label,
+            byte_pct,
+            self.copied - self.skipped,
+            self.skipped,
+            self.failed,
+            self.retried,
+            elapsed.as_secs(),
+            throughput_mib,
+            eta
+        )
+    }
 }
 
 #[derive(Clone)]
@@ -96,6 +415,20 @@ fn build_globset(patterns: &[String]) -> Result<Option<GlobSet>> {
     Ok(Some(set))
 }
 
+/// Send one key's buffered versions (listed newest first) in chronological order.
+/// Returns false once the consumer has stopped.
+async fn send_oldest_first(
+    pending: &mut Vec<ListedObject>,
+    tx: &mpsc::Sender<Result<ListedObject>>,
+) -> bool {
+    for listed in pending.drain(..).rev() {
+        if tx.send(Ok(listed)).await.is_err() {
+            return false;
+        }
+    }
+    true
+}
+
 fn normalize_prefix(prefix: &str) -> String {
     if prefix.is_empty() {
         String::new()
@@ -106,11 +439,190 @@ fn normalize_prefix(prefix: &str) -> String {
     }
 }
 
+/// Refuse `--delete` when source and destination share a bucket and one prefix
+/// contains the other: the destination listing would then include source objects,
+/// and those would be deleted as extraneous.
+pub fn check_delete_scope(
+    source_bucket: &str,
+    source_prefix: &str,
+    dest_bucket: &str,
+    dest_prefix: &str,
+) -> Result<()> {
+    let source = normalize_prefix(source_prefix);
+    let dest = normalize_prefix(dest_prefix);
+    if source_bucket == dest_bucket && (source.starts_with(&dest) || dest.starts_with(&source)) {
+        anyhow::bail!(
+            "--delete cannot be used when s3://{}/{} and s3://{}/{} overlap",
+            source_bucket,
+            source,
+            dest_bucket,
+            dest
+        );
+    }
+    Ok(())
+}
+
 fn dest_key_from_prefix(normalized_source: &str, normalized_dest: &str, key: &str) -> String {
     let relative = key.strip_prefix(normalized_source).unwrap_or(key);
     format!("{}{}", normalized_dest, relative)
 }
 
+/// Destination keys under `normalized_dest` that have no source counterpart.
+/// Keys whose source equivalent is excluded by the filters are out of scope and kept,
+/// and so are source objects when both prefixes live in the same bucket.
+fn plan_deletions(
+    dest_keys: &[String],
+    expected: &HashSet<String>,
+    normalized_source: &str,
+    normalized_dest: &str,
+    same_bucket: bool,
+    filters: Option<&KeyFilter>,
+) -> Vec<String> {
+    dest_keys
+        .iter()
+        .filter(|key| !expected.contains(*key))
+        .filter(|key| !(same_bucket && key.starts_with(normalized_source)))
+        .filter(|key| {
+            let relative = key.strip_prefix(normalized_dest).unwrap_or(key);
+            let source_equivalent = format!("{}{}", normalized_source, relative);
+            filters.is_none_or(|f| f.matches(&source_equivalent))
+        })
+        .cloned()
+        .collect()
+}
+
+/// Length of an HTTP `bytes=start-end` range, or 0 if it cannot be parsed.
+fn range_len(range: &str) -> i64 {
+    range
+        .strip_prefix("bytes=")
+        .and_then(|r| r.split_once('-'))
+        .and_then(|(start, end)| Some(end.parse::<i64>().ok()? - start.parse::<i64>().ok()? + 1))
+        .unwrap_or(0)
+}
+
+/// Split `[start_byte, content_length)` into consecutive parts numbered from `first_part`.
+fn plan_parts(
+    first_part: i32,
+    start_byte: i64,
+    content_length: i64,
+    part_size: i64,
+) -> Vec<PlannedPart> {
+    let mut parts = Vec::new();
+    let mut part_number = first_part;
+    let mut start = start_byte;
+    while start < content_length {
+        let end = std::cmp::min(start + part_size, content_length) - 1;
+        parts.push(PlannedPart {
+            part_number,
+            start,
+            end,
+        });
+        part_number += 1;
+        start = end + 1;
+    }
+    parts
+}
+
+/// Plan parts with exactly the given sizes, in order.
+fn plan_from_layout(part_sizes: &[i64]) -> Vec<PlannedPart> {
+    let mut start = 0;
+    part_sizes
+        .iter()
+        .enumerate()
+        .map(|(i, size)| {
+            let planned = PlannedPart {
+                part_number: i as i32 + 1,
+                start,
+                end: start + size - 1,
+            };
+            start += size;
+            planned
+        })
+        .collect()
+}
+
+/// Per-part checksums as reported by S3. CompleteMultipartUpload requires them
+/// whenever the upload was created with a checksum algorithm.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct PartChecksums {
+    crc32: Option<String>,
+    crc32_c: Option<String>,
+    crc64_nvme: Option<String>,
+    sha1: Option<String>,
+    sha256: Option<String>,
+}
+
+/// Collect the `checksum_*` accessors shared by the SDK's part and object outputs.
+macro_rules! part_checksums {
+    ($source:expr) => {{
+        let source = $source;
+        PartChecksums {
+            crc32: source.checksum_crc32().map(str::to_string),
+            crc32_c: source.checksum_crc32_c().map(str::to_string),
+            crc64_nvme: source.checksum_crc64_nvme().map(str::to_string),
+            sha1: source.checksum_sha1().map(str::to_string),
+            sha256: source.checksum_sha256().map(str::to_string),
+        }
+    }};
+}
+
+impl PartChecksums {
+    /// Algorithm the checksums were computed with, if any.
+    fn algorithm(&self) -> Option<ChecksumAlgorithm> {
+        if self.crc32.is_some() {
+            Some(ChecksumAlgorithm::Crc32)
+        } else if self.crc32_c.is_some() {
+            Some(ChecksumAlgorithm::Crc32C)
+        } else if self.crc64_nvme.is_some() {
+            Some(ChecksumAlgorithm::Crc64Nvme)
+        } else if self.sha1.is_some() {
+            Some(ChecksumAlgorithm::Sha1)
+        } else if self.sha256.is_some() {
+            Some(ChecksumAlgorithm::Sha256)
+        } else {
+            None
+        }
+    }
+
+    fn apply(self, builder: CompletedPartBuilder) -> CompletedPartBuilder {
+        builder
+            .set_checksum_crc32(self.crc32)
+            .set_checksum_crc32_c(self.crc32_c)
+            .set_checksum_crc64_nvme(self.crc64_nvme)
+            .set_checksum_sha1(self.sha1)
+            .set_checksum_sha256(self.sha256)
+    }
+}
+
+/// Part boundaries of a multipart source and the checksum it was uploaded with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct SourceLayout {
+    part_sizes: Vec<i64>,
+    checksum_algorithm: Option<ChecksumAlgorithm>,
+    checksum_type: Option<ChecksumType>,
+}
+
+/// Multipart upload recovered from a resume journal.
+struct ResumePoint {
+    upload_id: String,
+    journal: UploadJournal,
+    completed: Vec<CompletedPart>,
+    pending: Vec<PlannedPart>,
+}
+
+/// Whether a failed upload is kept, with its journal, instead of aborted: an interrupt
+/// follows `--on-interrupt`, any other error needs `--resume`. Parts of a changed
+/// source, or for a destination that may not be written, are useless and never kept.
+fn keeps_failed_upload(err: &anyhow::Error, resume: bool, on_interrupt: OnInterrupt) -> bool {
+    if err.is::<SourceChanged>() || err.is::<DestinationExists>() {
+        false
+    } else if err.is::<Interrupted>() {
+        on_interrupt == OnInterrupt::Keep
+    } else {
+        resume
+    }
+}
+
 fn is_retryable_prefix_error(err: &anyhow::Error) -> bool {
     let msg = err.to_string().to_ascii_lowercase();
     msg.contains("slowdown")
@@ -187,16 +699,15 @@ impl ChecksumProvider for HeadObjectChecksumProvider {
 }
 
 impl S3CopyApp {
-    /// Create a new S3CopyApp instance
+    /// Create a new S3CopyApp instance (see `CopyRequest` for the public builder)
     #[allow(clippy::too_many_arguments)]
-    pub async fn new(
+    pub(crate) async fn new(
         source_bucket: String,
         source_key: String,
         dest_bucket: String,
         dest_key: String,
-        region: Option<String>,
-        source_region: Option<String>,
-        profile: Option<String>,
+        dest_config: ClientConfig,
+        source_config: ClientConfig,
         part_size: i64,
         concurrency: usize,
         storage_class: Option<String>,
@@ -216,6 +727,23 @@ impl S3CopyApp {
         sse_kms_key_id: Option<String>,
         include_patterns: Vec<String>,
         exclude_patterns: Vec<String>,
+        resume: bool,
+        journal_dir: Option<String>,
+        object_concurrency: usize,
+        delete_extraneous: bool,
+        max_delete: Option<usize>,
+        data_path: DataPath,
+        max_buffer_mb: usize,
+        events: Option<EventSink>,
+        report: Option<RunReport>,
+        source_version_id: Option<String>,
+        on_source_change: OnSourceChange,
+        dest_guard: Option<DestinationGuard>,
+        match_source_parts: bool,
+        throttle: Throttle,
+        control: TransferControl,
+        on_interrupt: OnInterrupt,
+        clients: ClientPool,
     ) -> Result<Self> {
         // Convert storage class string to StorageClass enum
         let storage_class = storage_class.map(|s| StorageClass::from(s.as_str()));
@@ -229,19 +757,6 @@ impl S3CopyApp {
         // Concurrency is a hard cap; auto mode derives dynamic runtime target within this cap.
         let final_concurrency = concurrency.max(1);
 
-        // Configure a custom Hyper client with increased connection pool limits
-        let mut hyper_builder = hyper::Client::builder();
-        hyper_builder.pool_max_idle_per_host(final_concurrency);
-        hyper_builder.retry_canceled_requests(true);
-        hyper_builder.http2_only(false); // Allow fallback to HTTP/1.1
-
-        // Match max connections to concurrency to avoid pool bottlenecks
-        hyper_builder.pool_idle_timeout(std::time::Duration::from_secs(90));
-
-        let http_client = HyperClientBuilder::new()
-            .hyper_builder(hyper_builder)
-            .build_https();
-
         // Tune retries: More aggressive for large transfers
         let max_attempts = if auto {
             match auto_profile {
@@ -253,35 +768,20 @@ impl S3CopyApp {
         } else {
             5
         };
-        let mut config_loader = aws_config::defaults(aws_config::BehaviorVersion::latest())
-            .http_client(http_client.clone())
-            .retry_config(RetryConfig::standard().with_max_attempts(max_attempts));
-
-        if let Some(r) = region {
-            config_loader = config_loader.region(Region::new(r));
-        }
-
-        if let Some(p) = profile.clone() {
-            config_loader = config_loader.profile_name(p);
-        }
-
-        let config = config_loader.load().await;
-        let client = Client::new(&config);
-
-        let mut source_config_loader = aws_config::defaults(aws_config::BehaviorVersion::latest())
-            .http_client(http_client.clone())
-            .retry_config(RetryConfig::standard().with_max_attempts(max_attempts));
-
-        if let Some(r) = source_region {
-            source_config_loader = source_config_loader.region(Region::new(r));
-        }
-
-        if let Some(p) = profile {
-            source_config_loader = source_config_loader.profile_name(p);
-        }
-
-        let source_config = source_config_loader.load().await;
-        let source_client = Client::new(&source_config);
+        let client = clients
+            .transfer_client(&dest_config, max_attempts, final_concurrency)
+            .await;
+        let source_client = clients
+            .transfer_client(&source_config, max_attempts, final_concurrency)
+            .await;
+        let separate_identities = source_config.profile != dest_config.profile
+            || source_config.role_arn != dest_config.role_arn;
+        let streaming = match data_path {
+            DataPath::Auto => requires_streaming(&source_config, &dest_config),
+            DataPath::ServerSide => false,
+            DataPath::Streaming => true,
+        };
+        let buffer_budget_mb = max_buffer_mb.clamp(1, u32::MAX as usize) as u32;
         let filters = KeyFilter::from_patterns(include_patterns, exclude_patterns)?;
 
         Ok(Self {
@@ -309,6 +809,30 @@ impl S3CopyApp {
             sse,
             sse_kms_key_id,
             filters,
+            resume,
+            journal_dir: PathBuf::from(journal_dir.as_deref().unwrap_or(DEFAULT_JOURNAL_DIR)),
+            object_concurrency: object_concurrency.max(1),
+            part_slots: None,
+            delete_extraneous,
+            max_delete,
+            separate_identities,
+            data_path,
+            streaming: Arc::new(AtomicBool::new(streaming)),
+            buffer_budget: Arc::new(Semaphore::new(buffer_budget_mb as usize)),
+            buffer_budget_mb,
+            events,
+            report,
+            source_version_id,
+            source_etag: None,
+            source_layout: None,
+            checksum_type: None,
+            listed_source: None,
+            on_source_change,
+            dest_guard,
+            match_source_parts,
+            throttle,
+            control,
+            on_interrupt,
         })
     }
 
@@ -320,6 +844,265 @@ impl S3CopyApp {
         cloned
     }
 
+    fn emit(&self, event: ProgressEvent) {
+        if let Some(events) = &self.events {
+            events.emit(&event);
+        }
+    }
+
+    /// Narrate a step for a console renderer; quiet apps narrate nothing.
+    fn info(&self, message: impl Into<String>) {
+        if !self.quiet {
+            self.emit(ProgressEvent::Info {
+                destination: self.dest_uri(),
+                message: message.into(),
+            });
+        }
+    }
+
+    /// Report a problem the copy survives.
+    fn warn(&self, message: impl Into<String>) {
+        self.emit(ProgressEvent::Warning {
+            destination: self.dest_uri(),
+            message: message.into(),
+        });
+    }
+
+    /// Version to read when `bucket`/`key` is the source object.
+    fn source_version_for(&self, bucket: &str, key: &str) -> Option<String> {
+        if bucket == self.source_bucket && key == self.source_key {
+            self.source_version_id.clone()
+        } else {
+            None
+        }
+    }
+
+    /// `x-amz-copy-source` value, pinned to the source version when known. Objects
+    /// behind an access point are named `<access point ARN>/object/<key>`.
+    fn copy_source(&self) -> String {
+        let object = if uri::is_access_point(&self.source_bucket) {
+            format!("{}/object/{}", self.source_bucket, self.source_key)
+        } else {
+            format!("{}/{}", self.source_bucket, self.source_key)
+        };
+        match &self.source_version_id {
+            Some(version_id) => format!("{}?versionId={}", object, version_id),
+            None => object,
+        }
+    }
+
+    fn source_changed(&self) -> SourceChanged {
+        SourceChanged(format!(
+            "{} no longer matches ETag {}",
+            self.source_uri(),
+            self.source_etag.as_deref().unwrap_or_default()
+        ))
+    }
+
+    fn destination_exists(&self) -> DestinationExists {
+        DestinationExists(match &self.dest_guard {
+            Some(DestinationGuard::IfETag(etag)) => {
+                format!("{} no longer matches ETag {}", self.dest_uri(), etag)
+            }
+            _ => format!("{} was created by another writer", self.dest_uri()),
+        })
+    }
+
+    /// CopyObject carries conditions on both the source and the destination and S3
+    /// reports either as 412, so re-read the source to tell them apart.
+    async fn copy_object_precondition_error(&self) -> anyhow::Error {
+        if self.dest_guard.is_some()
+            && let Ok(Some(current)) = self
+                .get_object_metadata(&self.source_bucket, &self.source_key)
+                .await
+            && current.e_tag() == self.source_etag.as_deref()
+        {
+            return self.destination_exists().into();
+        }
+        self.source_changed().into()
+    }
+
+    fn dest_if_none_match(&self) -> Option<String> {
+        matches!(self.dest_guard, Some(DestinationGuard::NoClobber)).then(|| "*".to_string())
+    }
+
+    fn dest_if_match(&self) -> Option<String> {
+        match &self.dest_guard {
+            Some(DestinationGuard::IfETag(etag)) => Some(etag.clone()),
+            _ => None,
+        }
+    }
+
+    fn source_uri(&self) -> String {
+        format!("s3://{}/{}", self.source_bucket, self.source_key)
+    }
+
+    fn dest_uri(&self) -> String {
+        format!("s3://{}/{}", self.dest_bucket, self.dest_key)
+    }
+
+    /// Report a finished object to the progress sink and the run report.
+    fn report_object_result(&self, result: &ObjectResult) {
+        if let Some(report) = &self.report {
+            report.record(&ReportEntry {
+                source_bucket: self.source_bucket.clone(),
+                source_key: result.source_key.clone(),
+                dest_bucket: self.dest_bucket.clone(),
+                dest_key: result.dest_key.clone(),
+                action: result.action,
+                bytes: result.size,
+                duration_ms: result.duration.as_millis() as u64,
+                retries: result.retries,
+                verification: result.verification,
+                error: result.error.clone(),
+            });
+        }
+        let source = format!("s3://{}/{}", self.source_bucket, result.source_key);
+        let destination = format!("s3://{}/{}", self.dest_bucket, result.dest_key);
+        let event = match (&result.error, result.action) {
+            (Some(error), _) => ProgressEvent::ObjectFailed {
+                source,
+                destination,
+                size: result.size,
+                retries: result.retries,
+                error: error.clone(),
+            },
+            (None, Some(CopyAction::Skipped)) => ProgressEvent::ObjectSkipped {
+                source,
+                destination,
+                size: result.size,
+            },
+            (None, action) => ProgressEvent::ObjectCopied {
+                source,
+                destination,
+                size: result.size,
+                action: action.unwrap_or(CopyAction::Multipart),
+                retries: result.retries,
+                duration_ms: result.duration.as_millis() as u64,
+            },
+        };
+        self.emit(event);
+    }
+
+    /// Publish run totals and close the run report.
+    fn finish_run(&self, summary: RunSummary) -> Result<()> {
+        self.emit(ProgressEvent::Summary(summary.clone()));
+        if let Some(report) = &self.report {
+            report.finish(&summary)?;
+        }
+        Ok(())
+    }
+
+    /// Copy the configured single object and report the outcome to the progress sink.
+    pub async fn copy_single(&self) -> Result<CopyOutcome> {
+        let started = Instant::now();
+        let copied = self.copy_file().await;
+        let result = ObjectResult::from_attempt(
+            self.source_key.clone(),
+            self.dest_key.clone(),
+            copied.as_ref().map(|o| o.size).unwrap_or_default(),
+            0,
+            started.elapsed(),
+            &copied,
+        );
+        self.report_object_result(&result);
+        if let Some(report) = &self.report {
+            let mut stats = PrefixStats::default();
+            stats.record(&result);
+            report.finish(&stats.summary(1, 0, started.elapsed()))?;
+        }
+        copied
+    }
+
+    /// Check an existing destination object against the source per
+    /// `--verify-integrity`, without copying (the `verify` subcommand).
+    pub async fn verify_existing(&self) -> Result<()> {
+        let content_length = self.get_source_size().await?;
+        self.verify_copy(content_length, None).await?;
+        Ok(())
+    }
+
+    /// Wait until the transfer control lets new parts start, reporting window waits.
+    async fn wait_until_runnable(&self) -> Result<()> {
+        self.control
+            .wait_until_runnable(|event| self.emit(event))
+            .await
+    }
+
+    /// Hold one slot of the part-level budget shared across prefix-mode workers.
+    async fn acquire_part_slot(&self) -> Result<Option<OwnedSemaphorePermit>> {
+        match &self.part_slots {
+            Some(slots) => Ok(Some(
+                slots
+                    .clone()
+                    .acquire_owned()
+                    .await
+                    .context("Part slot limiter closed")?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// Whether part data is moved through this process instead of server-side copies.
+    fn streaming_active(&self) -> bool {
+        self.streaming.load(Ordering::SeqCst)
+    }
+
+    /// Route the object data took, or is taking.
+    fn data_route(&self) -> DataRoute {
+        if self.streaming_active() {
+            DataRoute::Streaming
+        } else {
+            DataRoute::ServerSide
+        }
+    }
+
+    /// Switch every clone of this app to the streaming data path.
+    fn activate_streaming(&self, denied_operation: &str) {
+        if !self.streaming.swap(true, Ordering::SeqCst) {
+            self.warn(format!(
+                "{} was denied with separate source/destination credentials. Falling back to streaming GetObject -> UploadPart.",
+                denied_operation
+            ));
+        }
+    }
+
+    /// Server-side copies run as the destination identity; with separate credentials an
+    /// AccessDenied usually means it cannot read the source, which streaming works around.
+    fn can_fall_back_to_streaming(&self, err: &anyhow::Error) -> bool {
+        self.data_path == DataPath::Auto && self.separate_identities && is_copy_access_denied(err)
+    }
+
+    /// Cap the part size at the streaming buffer budget whenever parts may be streamed,
+    /// so that every streamed part can be held in memory within `--max-buffer-mb`.
+    fn fit_part_size_to_buffer(&self, part_size: i64) -> i64 {
+        let may_stream = self.streaming_active()
+            || (self.data_path == DataPath::Auto && self.separate_identities);
+        if may_stream {
+            part_size.min(i64::from(self.buffer_budget_mb) * 1024 * 1024)
+        } else {
+            part_size
+        }
+    }
+
+    /// Reserve buffer memory for one streamed part (1 permit per MiB).
+    /// Parts larger than the whole budget are refused rather than over-allocated.
+    async fn reserve_buffer(&self, bytes: i64) -> Result<OwnedSemaphorePermit> {
+        let mib = ((bytes.max(1) + 1024 * 1024 - 1) / (1024 * 1024)) as u64;
+        if mib > u64::from(self.buffer_budget_mb) {
+            anyhow::bail!(
+                "A {} MiB part does not fit in the {} MiB streaming buffer; raise --max-buffer-mb or lower --part-size",
+                mib,
+                self.buffer_budget_mb
+            );
+        }
+        self.buffer_budget
+            .clone()
+            .acquire_many_owned(mib as u32)
+            .await
+            .context("Buffer budget closed")
+    }
+
     /// Get the source object's size in bytes.
     /// Used by the cost estimation flow.
     pub async fn get_source_size(&self) -> Result<i64> {
@@ -336,12 +1119,43 @@ impl S3CopyApp {
         Ok(metadata.content_length.unwrap_or(0))
     }
 
-    /// Get total size of all objects under a prefix (directory mode).
-    /// Lists all objects and sums their sizes.
-    pub async fn get_total_size_from_prefix(&self, prefix: &str) -> Result<i64> {
-        let objects = self.list_objects_with_prefix(prefix).await?;
-        let total_size: i64 = objects.iter().map(|o| o.size).sum();
-        Ok(total_size)
+    /// Count and size the objects under a prefix (directory mode) in one pass: from
+    /// the S3 Inventory when `inventory_manifest` is set, by listing otherwise.
+    pub async fn get_prefix_totals(
+        &self,
+        prefix: &str,
+        inventory_manifest: Option<&str>,
+    ) -> Result<(usize, i64)> {
+        let Some(manifest_uri) = inventory_manifest else {
+            let objects = self.list_objects_with_prefix(prefix).await?;
+            return Ok((objects.len(), objects.iter().map(|o| o.size).sum()));
+        };
+
+        let (tx, mut rx) = mpsc::channel(LISTING_CHANNEL_CAPACITY);
+        let listing = ListingProgress::default();
+        let listing_prefix = normalize_prefix(prefix);
+        let read = async move {
+            self.inventory_rows_into(manifest_uri, &listing_prefix, &tx, &listing)
+                .await
+        };
+        let count = async {
+            let (mut objects, mut bytes) = (0usize, 0i64);
+            while let Some(item) = rx.recv().await {
+                objects += 1;
+                bytes += item?.size;
+            }
+            Ok::<_, anyhow::Error>((objects, bytes))
+        };
+        let ((), totals) = tokio::try_join!(read, count)?;
+        Ok(totals)
+    }
+
+    /// Send one S3 request once it fits under the `--max-bandwidth` and
+    /// `--max-requests-per-second` caps. Every request the app makes goes through here,
+    /// so the caps hold for metadata, listing and multipart calls as well as part copies.
+    async fn send<T>(&self, bytes: u64, request: impl Future<Output = T>) -> T {
+        self.throttle.acquire(1, bytes).await;
+        request.await
     }
 
     /// Get object metadata
@@ -355,11 +1169,16 @@ impl S3CopyApp {
         } else {
             &self.client
         };
-        match client_to_use
-            .head_object()
-            .bucket(bucket)
-            .key(key)
-            .send()
+        match self
+            .send(
+                0,
+                client_to_use
+                    .head_object()
+                    .bucket(bucket)
+                    .key(key)
+                    .set_version_id(self.source_version_for(bucket, key))
+                    .send(),
+            )
             .await
         {
             Ok(output) => Ok(Some(output)),
@@ -377,24 +1196,19 @@ impl S3CopyApp {
     }
 
     async fn get_bucket_region(&self, bucket: &str) -> Result<String> {
+        let client_to_use = if bucket == self.source_bucket {
+            &self.source_client
+        } else {
+            &self.client
+        };
         let out = self
-            .client
-            .get_bucket_location()
-            .bucket(bucket)
-            .send()
+            .send(0, client_to_use.get_bucket_location().bucket(bucket).send())
             .await
             .with_context(|| format!("Failed to get region for bucket {}", bucket))?;
 
-        let region = out
-            .location_constraint()
-            .map(|v| v.as_str().to_string())
-            .unwrap_or_else(|| "us-east-1".to_string());
-
-        if region.is_empty() {
-            Ok("us-east-1".to_string())
-        } else {
-            Ok(region)
-        }
+        Ok(region_from_location(
+            out.location_constraint().map(|v| v.as_str()),
+        ))
     }
 
     /// Get object tagging
@@ -404,11 +1218,16 @@ impl S3CopyApp {
         } else {
             &self.client
         };
-        match client_to_use
-            .get_object_tagging()
-            .bucket(bucket)
-            .key(key)
-            .send()
+        match self
+            .send(
+                0,
+                client_to_use
+                    .get_object_tagging()
+                    .bucket(bucket)
+                    .key(key)
+                    .set_version_id(self.source_version_for(bucket, key))
+                    .send(),
+            )
             .await
         {
             Ok(output) => Ok(Some(output.tag_set)),
@@ -506,6 +1325,9 @@ impl S3CopyApp {
         if let Some(algo) = &self.checksum_algorithm {
             builder = builder.checksum_algorithm(algo.clone());
         }
+        if let Some(checksum_type) = &self.checksum_type {
+            builder = builder.checksum_type(checksum_type.clone());
+        }
 
         // Set Encryption
         if let Some(sse) = &self.sse {
@@ -516,16 +1338,14 @@ impl S3CopyApp {
         }
 
         if self.dry_run {
-            if !self.quiet {
-                println!(
-                    "   [Dry Run] Would initiate multipart upload (dest: s3://{}/{})",
-                    self.dest_bucket, self.dest_key
-                );
-            }
+            self.info(format!(
+                "   [Dry Run] Would initiate multipart upload (dest: s3://{}/{})",
+                self.dest_bucket, self.dest_key
+            ));
             return Ok("DRY-RUN-UPLOAD-ID".to_string());
         }
 
-        let response = builder.send().await.with_context(|| {
+        let response = self.send(0, builder.send()).await.with_context(|| {
             format!(
                 "Failed to initiate multipart upload to s3://{}/{}",
                 self.dest_bucket, self.dest_key
@@ -535,46 +1355,156 @@ impl S3CopyApp {
         Ok(response.upload_id.unwrap_or_default())
     }
 
-    /// Upload a single part using copy
-    async fn upload_part_copy(
+    /// Copy one part, server-side when possible, streaming otherwise.
+    async fn copy_part(
         &self,
         upload_id: &str,
         part_number: i32,
         source_range: &str,
     ) -> Result<CompletedPart> {
+        if self.streaming_active() {
+            return self
+                .upload_part_streamed(upload_id, part_number, source_range)
+                .await;
+        }
+        match self
+            .upload_part_copy(upload_id, part_number, source_range)
+            .await
+        {
+            Err(e) if self.can_fall_back_to_streaming(&e) => {
+                self.activate_streaming("UploadPartCopy");
+                self.upload_part_streamed(upload_id, part_number, source_range)
+                    .await
+            }
+            result => result,
+        }
+    }
+
+    /// Upload a single part by reading the range from the source with the source
+    /// credentials and writing it with the destination credentials. Each part is held
+    /// in memory once, within the shared `--max-buffer-mb` budget.
+    async fn upload_part_streamed(
+        &self,
+        upload_id: &str,
+        part_number: i32,
+        source_range: &str,
+    ) -> Result<CompletedPart> {
+        let _slot = self.acquire_part_slot().await?;
+        let _buffer = self.reserve_buffer(range_len(source_range)).await?;
         if self.dry_run {
-            // Emulate delay for dry run
             tokio::time::sleep(std::time::Duration::from_millis(10)).await;
             return Ok(CompletedPart::builder()
                 .part_number(part_number)
                 .e_tag("dry-run-etag")
                 .build());
         }
+        // GetObject + UploadPart
+        let object = self
+            .send(
+                range_len(source_range) as u64,
+                self.source_client
+                    .get_object()
+                    .bucket(&self.source_bucket)
+                    .key(&self.source_key)
+                    .set_version_id(self.source_version_id.clone())
+                    .set_if_match(self.source_etag.clone())
+                    .range(source_range)
+                    .send(),
+            )
+            .await;
+        let object = match object {
+            Err(e) if is_precondition_failed(&e) => return Err(self.source_changed().into()),
+            result => result.with_context(|| {
+                format!(
+                    "Failed to read part {} (range: {}) from source",
+                    part_number, source_range
+                )
+            })?,
+        };
+        let data = object
+            .body
+            .collect()
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to stream part {} (range: {}) from source",
+                    part_number, source_range
+                )
+            })?
+            .into_bytes();
 
-        let response = self
+        let mut req = self
             .client
-            .upload_part_copy()
+            .upload_part()
             .bucket(&self.dest_bucket)
             .key(&self.dest_key)
             .upload_id(upload_id)
             .part_number(part_number)
-            .copy_source(format!("{}/{}", self.source_bucket, self.source_key))
-            .copy_source_range(source_range.to_string())
-            .send()
-            .await
-            .with_context(|| {
+            .content_length(data.len() as i64)
+            .body(ByteStream::from(data));
+        if let Some(algo) = &self.checksum_algorithm {
+            req = req.checksum_algorithm(algo.clone());
+        }
+        let response = self.send(0, req.send()).await.with_context(|| {
+            format!(
+                "Failed to upload part {} (range: {})",
+                part_number, source_range
+            )
+        })?;
+
+        let builder = CompletedPart::builder()
+            .part_number(part_number)
+            .e_tag(response.e_tag().unwrap_or_default());
+        Ok(part_checksums!(&response).apply(builder).build())
+    }
+
+    /// Upload a single part using copy
+    async fn upload_part_copy(
+        &self,
+        upload_id: &str,
+        part_number: i32,
+        source_range: &str,
+    ) -> Result<CompletedPart> {
+        let _slot = self.acquire_part_slot().await?;
+        if self.dry_run {
+            // Emulate delay for dry run
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            return Ok(CompletedPart::builder()
+                .part_number(part_number)
+                .e_tag("dry-run-etag")
+                .build());
+        }
+
+        let response = self
+            .send(
+                range_len(source_range) as u64,
+                self.client
+                    .upload_part_copy()
+                    .bucket(&self.dest_bucket)
+                    .key(&self.dest_key)
+                    .upload_id(upload_id)
+                    .part_number(part_number)
+                    .copy_source(self.copy_source())
+                    .set_copy_source_if_match(self.source_etag.clone())
+                    .copy_source_range(source_range.to_string())
+                    .send(),
+            )
+            .await;
+        let response = match response {
+            Err(e) if is_precondition_failed(&e) => return Err(self.source_changed().into()),
+            result => result.with_context(|| {
                 format!(
                     "Failed to upload part {} (range: {})",
                     part_number, source_range
                 )
-            })?;
-
-        let etag = response.copy_part_result.unwrap().e_tag.unwrap_or_default();
+            })?,
+        };
 
-        Ok(CompletedPart::builder()
+        let result = response.copy_part_result.unwrap();
+        let builder = CompletedPart::builder()
             .part_number(part_number)
-            .e_tag(etag)
-            .build())
+            .e_tag(result.e_tag().unwrap_or_default());
+        Ok(part_checksums!(&result).apply(builder).build())
     }
 
     /// Complete the multipart upload
@@ -584,33 +1514,42 @@ impl S3CopyApp {
         parts: Vec<CompletedPart>,
     ) -> Result<()> {
         if self.dry_run {
-            if !self.quiet {
-                println!(
-                    "   [Dry Run] Would complete multipart upload (upload_id: {})",
-                    upload_id
-                );
-            }
+            self.info(format!(
+                "   [Dry Run] Would complete multipart upload (upload_id: {})",
+                upload_id
+            ));
             return Ok(());
         }
 
-        self.client
-            .complete_multipart_upload()
-            .bucket(&self.dest_bucket)
-            .key(&self.dest_key)
-            .upload_id(upload_id)
-            .multipart_upload(
-                aws_sdk_s3::types::CompletedMultipartUpload::builder()
-                    .set_parts(Some(parts))
-                    .build(),
+        let response = self
+            .send(
+                0,
+                self.client
+                    .complete_multipart_upload()
+                    .bucket(&self.dest_bucket)
+                    .key(&self.dest_key)
+                    .upload_id(upload_id)
+                    .set_if_none_match(self.dest_if_none_match())
+                    .set_if_match(self.dest_if_match())
+                    .multipart_upload(
+                        aws_sdk_s3::types::CompletedMultipartUpload::builder()
+                            .set_parts(Some(parts))
+                            .build(),
+                    )
+                    .send(),
             )
-            .send()
-            .await
-            .with_context(|| {
-                format!(
-                    "Failed to complete multipart upload for s3://{}/{}",
-                    self.dest_bucket, self.dest_key
-                )
-            })?;
+            .await;
+        match response {
+            Err(e) if is_precondition_failed(&e) => return Err(self.destination_exists().into()),
+            result => {
+                result.with_context(|| {
+                    format!(
+                        "Failed to complete multipart upload for s3://{}/{}",
+                        self.dest_bucket, self.dest_key
+                    )
+                })?;
+            }
+        }
 
         Ok(())
     }
@@ -618,40 +1557,188 @@ impl S3CopyApp {
     /// Abort multipart upload on failure
     async fn abort_multipart_upload(&self, upload_id: &str) -> Result<()> {
         if self.dry_run {
-            if !self.quiet {
-                println!(
-                    "   [Dry Run] Would abort multipart upload (upload_id: {})",
-                    upload_id
-                );
-            }
+            self.info(format!(
+                "   [Dry Run] Would abort multipart upload (upload_id: {})",
+                upload_id
+            ));
             return Ok(());
         }
 
-        self.client
-            .abort_multipart_upload()
-            .bucket(&self.dest_bucket)
-            .key(&self.dest_key)
-            .upload_id(upload_id)
-            .send()
-            .await
-            .with_context(|| {
-                format!(
-                    "Failed to abort multipart upload for s3://{}/{}",
-                    self.dest_bucket, self.dest_key
-                )
-            })?;
+        self.send(
+            0,
+            self.client
+                .abort_multipart_upload()
+                .bucket(&self.dest_bucket)
+                .key(&self.dest_key)
+                .upload_id(upload_id)
+                .send(),
+        )
+        .await
+        .with_context(|| {
+            format!(
+                "Failed to abort multipart upload for s3://{}/{}",
+                self.dest_bucket, self.dest_key
+            )
+        })?;
 
         Ok(())
     }
 
+    fn journal_key(&self) -> JournalKey {
+        JournalKey {
+            source_bucket: self.source_bucket.clone(),
+            source_key: self.source_key.clone(),
+            dest_bucket: self.dest_bucket.clone(),
+            dest_key: self.dest_key.clone(),
+        }
+    }
+
+    /// List the parts already uploaded for `upload_id`, keyed by part number.
+    /// Returns `None` when the upload no longer exists.
+    async fn list_uploaded_parts(
+        &self,
+        upload_id: &str,
+    ) -> Result<Option<HashMap<i32, (CompletedPart, i64)>>> {
+        let mut parts = HashMap::new();
+        let mut marker: Option<String> = None;
+
+        loop {
+            let mut req = self
+                .client
+                .list_parts()
+                .bucket(&self.dest_bucket)
+                .key(&self.dest_key)
+                .upload_id(upload_id);
+            if let Some(m) = marker {
+                req = req.part_number_marker(m);
+            }
+
+            let response = match self.send(0, req.send()).await {
+                Ok(r) => r,
+                Err(e) => {
+                    let service_error = e.into_service_error();
+                    if service_error.code() == Some("NoSuchUpload") {
+                        return Ok(None);
+                    }
+                    return Err(anyhow::anyhow!(service_error).context(format!(
+                        "Failed to list parts of upload {} for s3://{}/{}",
+                        upload_id, self.dest_bucket, self.dest_key
+                    )));
+                }
+            };
+
+            for part in response.parts() {
+                if let (Some(number), Some(etag)) = (part.part_number(), part.e_tag()) {
+                    let builder = CompletedPart::builder().part_number(number).e_tag(etag);
+                    let completed = part_checksums!(part).apply(builder).build();
+                    parts.insert(number, (completed, part.size().unwrap_or(0)));
+                }
+            }
+
+            if response.is_truncated().unwrap_or(false) {
+                marker = response.next_part_number_marker().map(|s| s.to_string());
+                if marker.is_none() {
+                    break;
+                }
+            } else {
+                break;
+            }
+        }
+
+        Ok(Some(parts))
+    }
+
+    /// Marker recording that this tool initiated `upload_id`, so `cleanup` can find it.
+    fn upload_marker(&self, upload_id: &str) -> UploadMarker {
+        UploadMarker {
+            dest_bucket: self.dest_bucket.clone(),
+            dest_key: self.dest_key.clone(),
+            upload_id: upload_id.to_string(),
+        }
+    }
+
+    /// Drop the upload marker once the upload has been completed or aborted.
+    fn remove_upload_marker(&self, upload_id: &str) {
+        if let Err(e) = self.upload_marker(upload_id).remove(&self.journal_dir) {
+            self.warn(format!("Failed to remove upload marker: {}", e));
+        }
+    }
+
+    /// Load the resume journal for this copy and reconcile it with the parts S3 already holds.
+    async fn load_resume_point(
+        &self,
+        source_etag: &str,
+        content_length: i64,
+        part_size: i64,
+    ) -> Result<Option<ResumePoint>> {
+        let key = self.journal_key();
+        let path = key.path_in(&self.journal_dir);
+        let Some(state) = load_journal(&path, &key)? else {
+            return Ok(None);
+        };
+        let journal = UploadJournal::open(&path)?;
+
+        if state.source_etag != source_etag || state.content_length != content_length {
+            self.warn(format!(
+                "Source changed since the resume journal was written. Discarding upload {} and starting over.",
+                state.upload_id
+            ));
+            match self.abort_multipart_upload(&state.upload_id).await {
+                Ok(()) => self.remove_upload_marker(&state.upload_id),
+                Err(e) => self.warn(format!("Failed to abort stale multipart upload: {}", e)),
+            }
+            journal.remove()?;
+            return Ok(None);
+        }
+
+        let Some(uploaded) = self.list_uploaded_parts(&state.upload_id).await? else {
+            self.warn(format!(
+                "Upload {} from the resume journal no longer exists. Starting over.",
+                state.upload_id
+            ));
+            self.remove_upload_marker(&state.upload_id);
+            journal.remove()?;
+            return Ok(None);
+        };
+
+        let mut completed = Vec::new();
+        let mut pending = Vec::new();
+        for planned in state.plan.values() {
+            match uploaded.get(&planned.part_number) {
+                Some((part, size)) if *size == planned.len() => completed.push(part.clone()),
+                _ => pending.push(*planned),
+            }
+        }
+
+        // The run may have stopped before the remainder of the object was planned.
+        let planned_end = state.plan.values().map(|p| p.end + 1).max().unwrap_or(0);
+        if planned_end < content_length {
+            let next_part = state.plan.keys().max().copied().unwrap_or(0) + 1;
+            let remaining_slots = (10000 - (next_part - 1) as i64).max(1);
+            let tail_part_size =
+                clamp_part_size_for_limit(content_length - planned_end, part_size, remaining_slots);
+            let tail = plan_parts(next_part, planned_end, content_length, tail_part_size);
+            journal.record_plan(&tail)?;
+            pending.extend(tail);
+        }
+
+        Ok(Some(ResumePoint {
+            upload_id: state.upload_id,
+            journal,
+            completed,
+            pending,
+        }))
+    }
+
     async fn run_copy_window(
         &self,
         upload_id: &str,
         batch: Vec<(i32, String, u64)>,
         progress: &CopyProgress,
-        progress_bar: &ProgressBar,
+        journal: Option<&UploadJournal>,
     ) -> Result<(Vec<CompletedPart>, WindowMetrics)> {
         let started = Instant::now();
+        let throttled_before = self.throttle.waited();
         let window_bytes: u64 = batch.iter().map(|(_, _, bytes)| *bytes).sum();
         let semaphore = Arc::new(Semaphore::new(batch.len()));
         let mut handles = Vec::with_capacity(batch.len());
@@ -662,42 +1749,59 @@ impl S3CopyApp {
             let upload_id = upload_id.to_string();
             let semaphore = semaphore.clone();
             let progress = progress.clone();
-            let progress_bar = progress_bar.clone();
+            let journal = journal.cloned();
 
             let handle = task::spawn(async move {
                 let _permit = semaphore.acquire().await.unwrap();
+                app.wait_until_runnable().await?;
                 let part_started = Instant::now();
-                let completed_part = app
-                    .upload_part_copy(&upload_id, part_number, &range)
-                    .await?;
+                let completed_part = app.copy_part(&upload_id, part_number, &range).await?;
                 let elapsed = part_started.elapsed().as_secs_f64();
+                if let Some(journal) = &journal {
+                    journal.record_part(part_number, completed_part.e_tag().unwrap_or_default())?;
+                }
 
                 progress.add_completed(part_size_bytes);
-                progress_bar.set_position(progress.copied_bytes.load(Ordering::SeqCst));
+                let copied_bytes = progress.copied_bytes.load(Ordering::SeqCst);
                 let completed = progress.completed_parts.load(Ordering::SeqCst);
                 let total = progress.total_parts;
-                progress_bar.set_message(format!("{}/{} parts completed", completed, total));
+                app.emit(ProgressEvent::PartCompleted {
+                    destination: app.dest_uri(),
+                    part_number,
+                    bytes: part_size_bytes,
+                    latency_ms: (elapsed * 1000.0) as u64,
+                    completed_parts: completed,
+                    total_parts: total,
+                    copied_bytes,
+                });
 
                 Ok::<_, anyhow::Error>((completed_part, elapsed))
             });
             handles.push(handle);
         }
 
+        // Wait for every part in flight, even after a failure, so none is still
+        // running when the upload is aborted or kept for --resume.
         let mut completed_parts = Vec::with_capacity(handles.len());
+        let mut first_error: Option<anyhow::Error> = None;
         for handle in handles {
-            match handle.await {
+            let failure = match handle.await {
                 Ok(Ok((part, elapsed))) => {
                     total_part_seconds += elapsed;
                     completed_parts.push(part);
+                    continue;
                 }
-                Ok(Err(e)) => {
-                    return Err(e);
-                }
-                Err(join_err) => {
-                    return Err(anyhow::anyhow!(join_err).context("Part task join error"));
-                }
+                Ok(Err(e)) => e,
+                Err(join_err) => anyhow::anyhow!(join_err).context("Part task join error"),
+            };
+            // A real failure is more useful than the parts refused after an interrupt.
+            if first_error.as_ref().is_none_or(|e| e.is::<Interrupted>()) {
+                first_error = Some(failure);
             }
         }
+        if let Some(e) = first_error {
+            return Err(e);
+        }
 
         let elapsed = started.elapsed().as_secs_f64().max(0.001);
         let bytes = window_bytes as f64;
@@ -710,6 +1814,7 @@ impl S3CopyApp {
                 avg_part_seconds,
                 throughput_mib_s,
                 had_retryable_pressure: false,
+                at_rate_cap: self.throttle.waited() > throttled_before,
             },
         ))
     }
@@ -750,253 +1855,1514 @@ impl S3CopyApp {
         }
     }
 
-    /// Copy objects from a source prefix (directory path)
-    pub async fn copy_from_prefix(&self, source_prefix: &str, dest_prefix: &str) -> Result<()> {
+    /// Copy objects from a source prefix (directory path).
+    /// Objects are copied as listing pages arrive; totals grow while the listing runs.
+    pub async fn copy_from_prefix(
+        &self,
+        source_prefix: &str,
+        dest_prefix: &str,
+    ) -> Result<RunSummary> {
         let normalized_source = normalize_prefix(source_prefix);
         let normalized_dest = normalize_prefix(dest_prefix);
-        let objects = self.list_objects_with_prefix(&normalized_source).await?;
-
-        if objects.is_empty() {
-            println!(
-                "No objects found at s3://{}/{}",
-                self.source_bucket, normalized_source
-            );
-            return Ok(());
-        }
-
-        let total_bytes: i64 = objects.iter().map(|o| o.size).sum();
-        let total_objects = objects.len();
-        let pace_delay = object_pace_delay(total_objects);
-        let retry_limit: usize = if total_objects >= 10_000 {
-            7
-        } else if total_objects >= 2_000 {
-            6
-        } else {
-            4
-        };
-        let report_interval = progress_report_interval(total_objects);
-        let started = Instant::now();
-
-        if !self.quiet {
-            println!(
-                "Found {} objects ({} bytes) to copy",
-                total_objects, total_bytes
-            );
-            println!(
-                "Prefix copy tuning: retries={}, pacing={}ms/report-every={} objects",
-                retry_limit,
-                pace_delay.as_millis(),
-                report_interval
-            );
-        }
-
-        let mut processed = 0usize;
-        let mut copied = 0usize;
-        let mut failed = 0usize;
-        let mut retried = 0usize;
-        let mut bytes_copied: i64 = 0;
-        let mut bytes_processed: i64 = 0;
-        let mut error_samples: Vec<String> = Vec::new();
-
-        for (idx, obj) in objects.iter().enumerate() {
-            let dest_key = dest_key_from_prefix(&normalized_source, &normalized_dest, &obj.key);
-            let quiet_for_object = self.quiet || total_objects > 1;
-
-            if !self.quiet {
-                println!(
-                    "\n[{:>5}/{:>5}] {} -> {} ({} bytes)",
-                    idx + 1,
-                    total_objects,
-                    obj.key,
-                    dest_key,
-                    obj.size
-                );
-            }
-
-            let per_file_app = self.with_keys(obj.key.clone(), dest_key.clone(), quiet_for_object);
-            let mut attempt = 1usize;
-            let mut succeeded = false;
-
-            loop {
-                match per_file_app.copy_file().await {
-                    Ok(_) => {
-                        copied += 1;
-                        bytes_copied += obj.size;
-                        succeeded = true;
-                        break;
-                    }
-                    Err(e) => {
-                        let retryable = is_retryable_prefix_error(&e);
-                        if retryable && attempt < retry_limit {
-                            retried += 1;
-                            let delay = retry_backoff_delay(attempt, total_objects);
-                            if !self.quiet {
-                                eprintln!(
-                                    "  ⚠️ Retryable error (attempt {}/{}): {}. Backing off {}ms",
-                                    attempt,
-                                    retry_limit,
-                                    e,
-                                    delay.as_millis()
-                                );
-                            }
-                            tokio::time::sleep(delay).await;
-                            attempt += 1;
-                            continue;
-                        }
-                        failed += 1;
-                        if error_samples.len() < 5 {
-                            error_samples.push(format!("{} -> {}: {}", obj.key, dest_key, e));
-                        }
-                        eprintln!("  ❌ Failed: {}", e);
-                        break;
-                    }
-                }
-            }
-
-            processed += 1;
-            bytes_processed += obj.size;
-
-            if !self.quiet {
-                let should_report = !succeeded
-                    || processed.is_multiple_of(report_interval)
-                    || processed == total_objects;
-                if should_report {
-                    let elapsed = started.elapsed();
-                    let file_pct = (processed as f64 / total_objects as f64) * 100.0;
-                    let byte_pct = if total_bytes <= 0 {
-                        100.0
-                    } else {
-                        (bytes_processed as f64 / total_bytes as f64) * 100.0
-                    };
-                    let throughput_mib = if elapsed.as_secs_f64() > 0.0 {
-                        (bytes_processed as f64 / (1024.0 * 1024.0)) / elapsed.as_secs_f64()
-                    } else {
-                        0.0
-                    };
-                    let eta = eta_from_progress(elapsed, bytes_processed, total_bytes)
-                        .map(|d| format!("{}s", d.as_secs()))
-                        .unwrap_or_else(|| "n/a".to_string());
-                    println!(
-                        "  Progress: files {}/{} ({:.1}%), bytes {} / {} ({:.1}%), copied={}, failed={}, retried={}, elapsed={}s, throughput={:.2} MiB/s, eta={}",
-                        processed,
-                        total_objects,
-                        file_pct,
-                        bytes_processed,
-                        total_bytes,
-                        byte_pct,
-                        copied,
-                        failed,
-                        retried,
-                        elapsed.as_secs(),
-                        throughput_mib,
-                        eta
-                    );
-                }
-            }
+        let (listed, listing) = self.stream_objects_with_prefix(&normalized_source);
+        self.copy_listed_objects(listed, listing, &normalized_source, &normalized_dest)
+            .await
+    }
 
-            if pace_delay > Duration::from_millis(0) && idx + 1 < total_objects {
-                tokio::time::sleep(pace_delay).await;
+    /// Copy every version under a source prefix, oldest first per key, so a versioned
+    /// destination ends up with the same history. Delete markers are not replayed.
+    pub async fn copy_all_versions_from_prefix(
+        &self,
+        source_prefix: &str,
+        dest_prefix: &str,
+    ) -> Result<RunSummary> {
+        let normalized_source = normalize_prefix(source_prefix);
+        let normalized_dest = normalize_prefix(dest_prefix);
+        let (tx, rx) = mpsc::channel(LISTING_CHANNEL_CAPACITY);
+        let listing = ListingProgress::default();
+        let producer_listing = listing.clone();
+        let app = self.clone();
+        let listing_prefix = normalized_source.clone();
+
+        tokio::spawn(async move {
+            if let Err(e) = app
+                .list_versions_into(&listing_prefix, &tx, &producer_listing)
+                .await
+            {
+                let _ = tx.send(Err(e)).await;
             }
-        }
+            producer_listing.mark_complete();
+        });
 
-        if !self.quiet {
-            let elapsed = started.elapsed();
-            let avg_mib_s = if elapsed.as_secs_f64() > 0.0 {
-                (bytes_processed as f64 / (1024.0 * 1024.0)) / elapsed.as_secs_f64()
-            } else {
-                0.0
-            };
-            println!("\n=== Summary ===");
-            println!("Total:   {}", total_objects);
-            println!("Done:    {}", processed);
-            println!("Copied:  {}", copied);
-            println!("Failed:  {}", failed);
-            println!("Retried: {}", retried);
-            println!("Bytes copied:    {} / {}", bytes_copied, total_bytes);
-            println!("Bytes processed: {} / {}", bytes_processed, total_bytes);
-            println!("Elapsed: {}s", elapsed.as_secs());
-            println!("Avg throughput: {:.2} MiB/s", avg_mib_s);
-            for sample in &error_samples {
-                println!("Error sample: {}", sample);
-            }
-        }
-
-        if failed > 0 {
-            Err(anyhow::anyhow!("{} objects failed to copy", failed))
-        } else {
-            Ok(())
-        }
+        self.copy_listed_objects(rx, listing, &normalized_source, &normalized_dest)
+            .await
     }
 
-    /// List all objects under a given prefix (public for estimation use)
-    pub async fn list_objects_with_prefix(&self, prefix: &str) -> Result<Vec<ListedObject>> {
+    /// Page through ListObjectVersions. The API returns each key's versions newest
+    /// first (possibly split across pages), so versions are buffered per key and sent
+    /// in reverse once the next key starts.
+    async fn list_versions_into(
+        &self,
+        listing_prefix: &str,
+        tx: &mpsc::Sender<Result<ListedObject>>,
+        listing: &ListingProgress,
+    ) -> Result<()> {
         let filters = self.filters.as_ref();
-        let mut objects = Vec::new();
-        let listing_prefix = normalize_prefix(prefix);
-        let mut continuation_token: Option<String> = None;
+        let mut key_marker: Option<String> = None;
+        let mut version_id_marker: Option<String> = None;
+        let mut pending: Vec<ListedObject> = Vec::new();
 
         loop {
-            let mut req = self
-                .source_client
-                .list_objects_v2()
-                .bucket(&self.source_bucket)
-                .prefix(&listing_prefix);
-
-            if let Some(token) = continuation_token {
-                req = req.continuation_token(token);
-            }
-
-            let response = req.send().await?;
+            let response = self
+                .send(
+                    0,
+                    self.source_client
+                        .list_object_versions()
+                        .bucket(&self.source_bucket)
+                        .prefix(listing_prefix)
+                        .set_key_marker(key_marker.take())
+                        .set_version_id_marker(version_id_marker.take())
+                        .send(),
+                )
+                .await
+                .with_context(|| {
+                    format!(
+                        "Failed to list object versions at s3://{}/{}",
+                        self.source_bucket, listing_prefix
+                    )
+                })?;
 
-            for obj in response.contents() {
-                if let Some(key) = obj.key() {
-                    let key_string = key.to_string();
-                    if let Some(f) = filters
-                        && !f.matches(&key_string)
-                    {
-                        continue;
-                    }
-                    let size = obj.size().unwrap_or(0);
-                    objects.push(ListedObject {
-                        key: key_string,
-                        size,
-                    });
+            for version in response.versions() {
+                let (Some(key), Some(version_id)) = (version.key(), version.version_id()) else {
+                    continue;
+                };
+                if let Some(f) = filters
+                    && !f.matches(key)
+                {
+                    continue;
                 }
+                if pending.first().is_some_and(|p| p.key != key)
+                    && !send_oldest_first(&mut pending, tx).await
+                {
+                    return Ok(());
+                }
+                let size = version.size().unwrap_or(0);
+                listing.add_object(size);
+                pending.push(ListedObject {
+                    key: key.to_string(),
+                    size,
+                    dest_key: None,
+                    version_id: Some(version_id.to_string()),
+                    e_tag: None,
+                    storage_class: None,
+                });
             }
 
-            let is_truncated = response.is_truncated();
-            if is_truncated.unwrap_or(false) {
-                continuation_token = response
-                    .clone()
-                    .next_continuation_token()
-                    .map(|s| s.to_string());
+            if response.is_truncated().unwrap_or(false) {
+                key_marker = response.next_key_marker().map(str::to_string);
+                version_id_marker = response.next_version_id_marker().map(str::to_string);
             } else {
                 break;
             }
         }
 
-        Ok(objects)
+        send_oldest_first(&mut pending, tx).await;
+        Ok(())
     }
 
-    /// Copy the file using multipart upload
-    pub async fn copy_file(&self) -> Result<()> {
-        if !self.quiet {
-            println!("\n=== S3 Large File Copy ===");
-            println!(
-                "Source:      s3://{}/{}",
-                self.source_bucket, self.source_key
-            );
-            println!("Destination: s3://{}/{}", self.dest_bucket, self.dest_key);
-            println!("Part size:   {} MB", self.part_size / 1024 / 1024);
-            println!("Concurrency: {} parts", self.concurrency);
-            println!("=========================\n");
-        }
+    /// Copy a prefix enumerated from an S3 Inventory report instead of ListObjectsV2.
+    pub async fn copy_from_inventory(
+        &self,
+        manifest_uri: &str,
+        source_prefix: &str,
+        dest_prefix: &str,
+    ) -> Result<RunSummary> {
+        let normalized_source = normalize_prefix(source_prefix);
+        let normalized_dest = normalize_prefix(dest_prefix);
+        let (tx, rx) = mpsc::channel(LISTING_CHANNEL_CAPACITY);
+        let listing = ListingProgress::default();
+        let producer_listing = listing.clone();
+        let app = self.clone();
+        let manifest_uri = manifest_uri.to_string();
+        let listing_prefix = normalized_source.clone();
+
+        tokio::spawn(async move {
+            if let Err(e) = app
+                .inventory_rows_into(&manifest_uri, &listing_prefix, &tx, &producer_listing)
+                .await
+            {
+                let _ = tx.send(Err(e)).await;
+            }
+            producer_listing.mark_complete();
+        });
 
-        if self.dry_run && !self.quiet {
-            println!("🚨 DRY RUN MODE: No data will be modified.");
-        }
+        self.copy_listed_objects(rx, listing, &normalized_source, &normalized_dest)
+            .await
+    }
 
-        // Get source object metadata
+    /// Read an inventory manifest and its CSV, ORC or Parquet data files, sending
+    /// current objects under `listing_prefix` that pass the filters.
+    async fn inventory_rows_into(
+        &self,
+        manifest_uri: &str,
+        listing_prefix: &str,
+        tx: &mpsc::Sender<Result<ListedObject>>,
+        listing: &ListingProgress,
+    ) -> Result<()> {
+        let (manifest_bucket, manifest_key) = parse_s3_uri(manifest_uri)?;
+        let manifest_bytes = self
+            .download_object(&manifest_bucket, &manifest_key)
+            .await
+            .with_context(|| format!("Failed to read inventory manifest {}", manifest_uri))?;
+        let manifest = InventoryManifest::parse(&manifest_bytes)?;
+        if manifest.source_bucket != self.source_bucket {
+            anyhow::bail!(
+                "Inventory {} describes bucket {}, not {}",
+                manifest_uri,
+                manifest.source_bucket,
+                self.source_bucket
+            );
+        }
+
+        for file in &manifest.files {
+            let read = match manifest.format {
+                InventoryFormat::Csv => {
+                    self.inventory_csv_rows_into(&manifest, file, listing_prefix, tx, listing)
+                        .await
+                }
+                InventoryFormat::Orc | InventoryFormat::Parquet => {
+                    self.inventory_columnar_rows_into(&manifest, file, listing_prefix, tx, listing)
+                        .await
+                }
+            };
+            let sent_all = read.with_context(|| {
+                format!(
+                    "Failed to read inventory file s3://{}/{}",
+                    manifest.files_bucket, file.key
+                )
+            })?;
+            if !sent_all {
+                return Ok(());
+            }
+        }
+        Ok(())
+    }
+
+    /// Stream and decompress one gzipped CSV data file line by line.
+    /// Returns `false` once the consumer has gone away.
+    async fn inventory_csv_rows_into(
+        &self,
+        manifest: &InventoryManifest,
+        file: &InventoryFile,
+        listing_prefix: &str,
+        tx: &mpsc::Sender<Result<ListedObject>>,
+        listing: &ListingProgress,
+    ) -> Result<bool> {
+        let body = self.open_object(&manifest.files_bucket, &file.key).await?;
+        let mut decoder = GzipDecoder::new(body.into_async_read());
+        decoder.multiple_members(true);
+        let mut lines = tokio::io::BufReader::new(decoder).lines();
+        while let Some(line) = lines.next_line().await.context("Failed to decompress")? {
+            if let Some(row) = manifest.columns.parse_row(&line)
+                && !self
+                    .send_inventory_row(row, listing_prefix, tx, listing)
+                    .await
+            {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Spool one ORC or Parquet data file to a temporary file, since both formats
+    /// keep their metadata in a footer, and decode it on a blocking thread.
+    /// Returns `false` once the consumer has gone away.
+    async fn inventory_columnar_rows_into(
+        &self,
+        manifest: &InventoryManifest,
+        file: &InventoryFile,
+        listing_prefix: &str,
+        tx: &mpsc::Sender<Result<ListedObject>>,
+        listing: &ListingProgress,
+    ) -> Result<bool> {
+        let mut body = self.open_object(&manifest.files_bucket, &file.key).await?;
+        let spool = tempfile::tempfile().context("Failed to create a temporary file")?;
+        let mut writer = tokio::fs::File::from_std(spool.try_clone()?);
+        while let Some(chunk) = body.try_next().await.context("Failed to download")? {
+            writer.write_all(&chunk).await?;
+        }
+        writer.flush().await?;
+
+        let format = manifest.format;
+        let (batch_tx, mut batches) = mpsc::channel(2);
+        let decoder = task::spawn_blocking(move || {
+            inventory::read_columnar_file(format, spool, |rows| {
+                batch_tx.blocking_send(rows).is_ok()
+            })
+        });
+        while let Some(rows) = batches.recv().await {
+            for row in rows {
+                if !self
+                    .send_inventory_row(row, listing_prefix, tx, listing)
+                    .await
+                {
+                    return Ok(false);
+                }
+            }
+        }
+        decoder.await.context("Inventory decoder failed")??;
+        Ok(true)
+    }
+
+    /// Send one inventory row if it is under the prefix and passes the filters.
+    /// Returns `false` once the consumer has gone away.
+    async fn send_inventory_row(
+        &self,
+        row: InventoryRow,
+        listing_prefix: &str,
+        tx: &mpsc::Sender<Result<ListedObject>>,
+        listing: &ListingProgress,
+    ) -> bool {
+        if !row.key.starts_with(listing_prefix)
+            || self.filters.as_ref().is_some_and(|f| !f.matches(&row.key))
+        {
+            return true;
+        }
+        listing.add_object(row.size);
+        let listed = ListedObject {
+            key: row.key,
+            size: row.size,
+            dest_key: None,
+            version_id: None,
+            e_tag: row.e_tag,
+            storage_class: row.storage_class,
+        };
+        tx.send(Ok(listed)).await.is_ok()
+    }
+
+    /// Open a source-credential GetObject body for streaming.
+    async fn open_object(&self, bucket: &str, key: &str) -> Result<ByteStream> {
+        let response = self
+            .send(
+                0,
+                self.source_client
+                    .get_object()
+                    .bucket(bucket)
+                    .key(key)
+                    .send(),
+            )
+            .await?;
+        Ok(response.body)
+    }
+
+    /// Fetch a whole (small) object with the source credentials.
+    async fn download_object(&self, bucket: &str, key: &str) -> Result<Vec<u8>> {
+        let response = self
+            .send(
+                0,
+                self.source_client
+                    .get_object()
+                    .bucket(bucket)
+                    .key(key)
+                    .send(),
+            )
+            .await?;
+        let body = response.body.collect().await?;
+        Ok(body.into_bytes().to_vec())
+    }
+
+    /// Copy exactly the keys of a manifest, without listing the source.
+    /// Entries without a destination key are mapped through the prefixes (if any).
+    pub async fn copy_from_manifest(
+        &self,
+        entries: Vec<ManifestEntry>,
+        source_prefix: &str,
+        dest_prefix: &str,
+    ) -> Result<RunSummary> {
+        let normalized_source = normalize_prefix(source_prefix);
+        let normalized_dest = normalize_prefix(dest_prefix);
+        let (tx, rx) = mpsc::channel(LISTING_CHANNEL_CAPACITY);
+        let listing = ListingProgress::default();
+        let producer_listing = listing.clone();
+
+        tokio::spawn(async move {
+            for entry in entries {
+                // Sizes unknown here are added once each object's HeadObject runs.
+                let size = entry.size.unwrap_or(0);
+                producer_listing.add_object(size);
+                let listed = ListedObject {
+                    key: entry.source_key,
+                    size,
+                    dest_key: entry.dest_key,
+                    version_id: None,
+                    e_tag: None,
+                    storage_class: None,
+                };
+                if tx.send(Ok(listed)).await.is_err() {
+                    break;
+                }
+            }
+            producer_listing.mark_complete();
+        });
+
+        self.copy_listed_objects(rx, listing, &normalized_source, &normalized_dest)
+            .await
+    }
+
+    /// Run the object worker pool over a stream of listed objects.
+    async fn copy_listed_objects(
+        &self,
+        mut listed: mpsc::Receiver<Result<ListedObject>>,
+        listing: ListingProgress,
+        normalized_source: &str,
+        normalized_dest: &str,
+    ) -> Result<RunSummary> {
+        let object_concurrency = self.object_concurrency.max(1);
+        let started = Instant::now();
+
+        self.info(format!(
+            "Listing and copying objects (object-concurrency={}, part-concurrency={})",
+            object_concurrency, self.concurrency
+        ));
+
+        // One part-level budget shared by every object worker.
+        let part_slots = Arc::new(Semaphore::new(self.concurrency.max(1)));
+        let mut workers: JoinSet<ObjectResult> = JoinSet::new();
+        let mut stats = PrefixStats::default();
+        let mut listing_error: Option<anyhow::Error> = None;
+        let mut launched = 0usize;
+        let mut expected_dest_keys: HashSet<String> = HashSet::new();
+        let mut versions_in_flight: HashSet<String> = HashSet::new();
+
+        while let Some(item) = listed.recv().await {
+            if self.control.is_interrupted() {
+                break;
+            }
+            let obj = match item {
+                Ok(obj) => obj,
+                Err(e) => {
+                    listing_error = Some(e);
+                    break;
+                }
+            };
+
+            // Versions of one key are copied one after another, oldest first.
+            while workers.len() >= object_concurrency
+                || (obj.version_id.is_some() && versions_in_flight.contains(&obj.key))
+            {
+                match workers.join_next().await {
+                    Some(joined) => {
+                        if let Some(done) =
+                            self.record_prefix_result(joined, &mut stats, &listing, started)
+                        {
+                            versions_in_flight.remove(&done);
+                        }
+                    }
+                    None => {
+                        versions_in_flight.clear();
+                        break;
+                    }
+                }
+            }
+            if obj.version_id.is_some() {
+                versions_in_flight.insert(obj.key.clone());
+            }
+
+            // Tuning follows the number of objects discovered so far.
+            let discovered = listing.objects();
+            let retry_limit: usize = if discovered >= 10_000 {
+                7
+            } else if discovered >= 2_000 {
+                6
+            } else {
+                4
+            };
+            let quiet_for_object = self.quiet || !(listing.is_complete() && discovered == 1);
+
+            launched += 1;
+            let dest_key = obj.dest_key.clone().unwrap_or_else(|| {
+                dest_key_from_prefix(normalized_source, normalized_dest, &obj.key)
+            });
+            if self.delete_extraneous {
+                expected_dest_keys.insert(dest_key.clone());
+            }
+            self.info(format!(
+                "\n[{:>5}/{:>5}] {} -> {} ({} bytes)",
+                launched,
+                listing.objects_label(),
+                obj.key,
+                dest_key,
+                obj.size
+            ));
+
+            let mut per_file_app = self.with_keys(obj.key.clone(), dest_key, quiet_for_object);
+            per_file_app.part_slots = Some(part_slots.clone());
+            if obj.e_tag.is_some() && obj.version_id.is_none() {
+                per_file_app.listed_source = Some(obj.clone());
+            }
+            if let Some(version_id) = &obj.version_id {
+                // Every version becomes a new destination version, even if its data
+                // matches the current destination object.
+                per_file_app.source_version_id = Some(version_id.clone());
+                per_file_app.force_copy = true;
+            }
+            let verbose = !self.quiet;
+            workers.spawn(async move {
+                per_file_app
+                    .copy_with_retries(obj, retry_limit, discovered, verbose)
+                    .await
+            });
+
+            let pace_delay = object_pace_delay(discovered);
+            if pace_delay > Duration::from_millis(0)
+                && (!listing.is_complete() || launched < listing.objects())
+            {
+                tokio::time::sleep(pace_delay).await;
+            }
+        }
+
+        while let Some(joined) = workers.join_next().await {
+            self.record_prefix_result(joined, &mut stats, &listing, started);
+        }
+
+        if self.control.is_interrupted() {
+            self.info(format!(
+                "\n🛑 Interrupted after {} objects: {} copied, {} skipped, {} failed or stopped ({} bytes copied).",
+                stats.processed,
+                stats.copied - stats.skipped,
+                stats.skipped,
+                stats.failed,
+                stats.bytes_copied
+            ));
+            if let Err(report_err) =
+                self.finish_run(stats.summary(listing.objects(), 0, started.elapsed()))
+            {
+                self.warn(report_err.to_string());
+            }
+            return Err(Interrupted.into());
+        }
+
+        if let Some(e) = listing_error {
+            if stats.processed > 0 {
+                self.info(format!(
+                    "\nListing stopped after {} objects; {} copied, {} failed.",
+                    stats.processed, stats.copied, stats.failed
+                ));
+            }
+            if let Err(report_err) =
+                self.finish_run(stats.summary(listing.objects(), 0, started.elapsed()))
+            {
+                self.warn(report_err.to_string());
+            }
+            return Err(e);
+        }
+
+        // Deletions only run against a complete source listing.
+        let deleted = if self.delete_extraneous {
+            match self
+                .delete_extraneous_objects(normalized_source, normalized_dest, &expected_dest_keys)
+                .await
+            {
+                Ok(deleted) => deleted,
+                Err(e) => {
+                    if let Err(report_err) =
+                        self.finish_run(stats.summary(listing.objects(), 0, started.elapsed()))
+                    {
+                        self.warn(report_err.to_string());
+                    }
+                    return Err(e);
+                }
+            }
+        } else {
+            0
+        };
+
+        let total_objects = listing.objects();
+        let total_bytes = listing.bytes();
+        let summary = stats.summary(total_objects, deleted, started.elapsed());
+        self.finish_run(summary.clone())?;
+        if total_objects == 0 {
+            self.info(format!(
+                "No objects found at s3://{}/{}",
+                self.source_bucket, normalized_source
+            ));
+            return Ok(summary);
+        }
+
+        if !self.quiet {
+            let elapsed = started.elapsed();
+            let avg_mib_s = if elapsed.as_secs_f64() > 0.0 {
+                (stats.bytes_processed as f64 / (1024.0 * 1024.0)) / elapsed.as_secs_f64()
+            } else {
+                0.0
+            };
+            let mut lines = vec![
+                "\n=== Summary ===".to_string(),
+                format!("Total:   {}", total_objects),
+                format!("Done:    {}", stats.processed),
+                format!("Copied:  {}", stats.copied - stats.skipped),
+                format!("Skipped: {}", stats.skipped),
+                format!("Failed:  {}", stats.failed),
+                format!("Retried: {}", stats.retried),
+            ];
+            if self.delete_extraneous {
+                lines.push(format!("Deleted: {}", deleted));
+            }
+            lines.push(format!(
+                "Bytes copied:    {} / {}",
+                stats.bytes_copied, total_bytes
+            ));
+            lines.push(format!(
+                "Bytes processed: {} / {}",
+                stats.bytes_processed, total_bytes
+            ));
+            lines.push(format!("Elapsed: {}s", elapsed.as_secs()));
+            lines.push(format!("Avg throughput: {:.2} MiB/s", avg_mib_s));
+            for sample in &stats.error_samples {
+                lines.push(format!("Error sample: {}", sample));
+            }
+            self.info(lines.join("\n"));
+        }
+
+        if stats.failed > 0 {
+            Err(ObjectsFailed(summary).into())
+        } else {
+            Ok(summary)
+        }
+    }
+
+    /// List every key under a destination prefix.
+    async fn list_dest_keys(&self, normalized_dest: &str) -> Result<Vec<String>> {
+        let mut keys = Vec::new();
+        let mut continuation_token: Option<String> = None;
+
+        loop {
+            let mut req = self
+                .client
+                .list_objects_v2()
+                .bucket(&self.dest_bucket)
+                .prefix(normalized_dest);
+            if let Some(token) = continuation_token {
+                req = req.continuation_token(token);
+            }
+
+            let response = self.send(0, req.send()).await.with_context(|| {
+                format!(
+                    "Failed to list objects at s3://{}/{}",
+                    self.dest_bucket, normalized_dest
+                )
+            })?;
+            keys.extend(
+                response
+                    .contents()
+                    .iter()
+                    .filter_map(|o| o.key().map(|k| k.to_string())),
+            );
+
+            if response.is_truncated().unwrap_or(false) {
+                continuation_token = response.next_continuation_token().map(|s| s.to_string());
+            } else {
+                break;
+            }
+        }
+
+        Ok(keys)
+    }
+
+    /// Delete destination objects that have no counterpart under the source prefix.
+    /// Returns the number of deleted (or, in dry-run mode, planned) objects.
+    async fn delete_extraneous_objects(
+        &self,
+        normalized_source: &str,
+        normalized_dest: &str,
+        expected_dest_keys: &HashSet<String>,
+    ) -> Result<usize> {
+        let dest_keys = self.list_dest_keys(normalized_dest).await?;
+        let doomed = plan_deletions(
+            &dest_keys,
+            expected_dest_keys,
+            normalized_source,
+            normalized_dest,
+            self.source_bucket == self.dest_bucket,
+            self.filters.as_ref(),
+        );
+
+        if doomed.is_empty() {
+            self.info(format!(
+                "\n🧹 No extraneous objects at s3://{}/{}",
+                self.dest_bucket, normalized_dest
+            ));
+            return Ok(0);
+        }
+
+        if let Some(limit) = self.max_delete
+            && doomed.len() > limit
+        {
+            anyhow::bail!(
+                "Refusing to delete {} objects from s3://{}/{}: exceeds --max-delete {}",
+                doomed.len(),
+                self.dest_bucket,
+                normalized_dest,
+                limit
+            );
+        }
+
+        if self.dry_run {
+            self.info(format!(
+                "\n🧹 [Dry Run] Would delete {} extraneous objects:",
+                doomed.len()
+            ));
+            for key in &doomed {
+                self.emit(ProgressEvent::ObjectDeleted {
+                    destination: format!("s3://{}/{}", self.dest_bucket, key),
+                    dry_run: true,
+                });
+            }
+            return Ok(doomed.len());
+        }
+
+        self.info(format!(
+            "\n🧹 Deleting {} extraneous objects...",
+            doomed.len()
+        ));
+
+        let mut failures = Vec::new();
+        for batch in doomed.chunks(DELETE_BATCH_SIZE) {
+            let identifiers = batch
+                .iter()
+                .map(|key| ObjectIdentifier::builder().key(key).build())
+                .collect::<std::result::Result<Vec<_>, _>>()
+                .context("Failed to build delete request")?;
+            let delete = Delete::builder()
+                .set_objects(Some(identifiers))
+                .quiet(true)
+                .build()
+                .context("Failed to build delete request")?;
+
+            let response = self
+                .send(
+                    0,
+                    self.client
+                        .delete_objects()
+                        .bucket(&self.dest_bucket)
+                        .delete(delete)
+                        .send(),
+                )
+                .await
+                .with_context(|| {
+                    format!(
+                        "Failed to delete objects from s3://{}/{}",
+                        self.dest_bucket, normalized_dest
+                    )
+                })?;
+
+            let mut failed_keys = HashSet::new();
+            for err in response.errors() {
+                failed_keys.insert(err.key().unwrap_or_default());
+                failures.push(format!(
+                    "{}: {}",
+                    err.key().unwrap_or_default(),
+                    err.message().or(err.code()).unwrap_or("unknown error")
+                ));
+            }
+            for key in batch.iter().filter(|k| !failed_keys.contains(k.as_str())) {
+                self.emit(ProgressEvent::ObjectDeleted {
+                    destination: format!("s3://{}/{}", self.dest_bucket, key),
+                    dry_run: false,
+                });
+            }
+        }
+
+        if !failures.is_empty() {
+            for sample in failures.iter().take(5) {
+                self.warn(format!("Delete failed: {}", sample));
+            }
+            anyhow::bail!("{} objects failed to delete", failures.len());
+        }
+
+        Ok(doomed.len())
+    }
+
+    /// Copy one prefix object, retrying throttling errors with backoff.
+    async fn copy_with_retries(
+        &self,
+        obj: ListedObject,
+        retry_limit: usize,
+        total_objects: usize,
+        verbose: bool,
+    ) -> ObjectResult {
+        let started = Instant::now();
+        let mut attempt = 1usize;
+        loop {
+            let copied = self.copy_file().await;
+            match &copied {
+                Ok(_) => {}
+                Err(e) => {
+                    let retryable = is_retryable_prefix_error(e);
+                    if retryable && attempt < retry_limit {
+                        let delay = retry_backoff_delay(attempt, total_objects);
+                        if verbose {
+                            self.warn(format!(
+                                "Retryable error on {} (attempt {}/{}): {}. Backing off {}ms",
+                                obj.key,
+                                attempt,
+                                retry_limit,
+                                e,
+                                delay.as_millis()
+                            ));
+                        }
+                        tokio::time::sleep(delay).await;
+                        attempt += 1;
+                        continue;
+                    }
+                }
+            }
+            return ObjectResult::from_attempt(
+                obj.key,
+                self.dest_key.clone(),
+                obj.size,
+                attempt - 1,
+                started.elapsed(),
+                &copied,
+            );
+        }
+    }
+
+    /// Account for a finished object worker; returns the source key it copied.
+    fn record_prefix_result(
+        &self,
+        joined: std::result::Result<ObjectResult, task::JoinError>,
+        stats: &mut PrefixStats,
+        listing: &ListingProgress,
+        started: Instant,
+    ) -> Option<String> {
+        let result = match joined {
+            Ok(r) => r,
+            Err(join_err) => {
+                stats.processed += 1;
+                stats.failed += 1;
+                if stats.error_samples.len() < 5 {
+                    stats
+                        .error_samples
+                        .push(format!("object worker failed: {}", join_err));
+                }
+                self.warn(format!("object worker failed: {}", join_err));
+                return None;
+            }
+        };
+
+        let succeeded = result.error.is_none();
+        // Manifest entries are listed without a size, and inventories can be stale:
+        // the copy's HeadObject has the authoritative size.
+        if result.size != result.listed_size {
+            listing.add_bytes(result.size - result.listed_size);
+        }
+        stats.record(&result);
+        self.report_object_result(&result);
+
+        let report_interval = progress_report_interval(listing.objects());
+        let should_report = !succeeded
+            || stats.processed.is_multiple_of(report_interval)
+            || (listing.is_complete() && stats.processed == listing.objects());
+        if should_report {
+            self.info(format!(
+                "  Progress: {}",
+                stats.progress_line(listing, started.elapsed())
+            ));
+        }
+        Some(result.source_key)
+    }
+
+    /// List all objects under a given prefix (public for estimation use)
+    pub async fn list_objects_with_prefix(&self, prefix: &str) -> Result<Vec<ListedObject>> {
+        let (mut listed, _) = self.stream_objects_with_prefix(prefix);
+        let mut objects = Vec::new();
+        while let Some(item) = listed.recv().await {
+            objects.push(item?);
+        }
+        Ok(objects)
+    }
+
+    /// Stream objects under a prefix as listing pages arrive.
+    /// The listing runs ahead of the consumer by at most `LISTING_CHANNEL_CAPACITY` objects.
+    pub fn stream_objects_with_prefix(
+        &self,
+        prefix: &str,
+    ) -> (mpsc::Receiver<Result<ListedObject>>, ListingProgress) {
+        let (tx, rx) = mpsc::channel(LISTING_CHANNEL_CAPACITY);
+        let listing = ListingProgress::default();
+        let app = self.clone();
+        let listing_prefix = normalize_prefix(prefix);
+        let producer_listing = listing.clone();
+
+        tokio::spawn(async move {
+            if let Err(e) = app
+                .list_pages_into(&listing_prefix, &tx, &producer_listing)
+                .await
+            {
+                let _ = tx.send(Err(e)).await;
+            }
+            producer_listing.mark_complete();
+        });
+
+        (rx, listing)
+    }
+
+    async fn list_pages_into(
+        &self,
+        listing_prefix: &str,
+        tx: &mpsc::Sender<Result<ListedObject>>,
+        listing: &ListingProgress,
+    ) -> Result<()> {
+        let filters = self.filters.as_ref();
+        let mut continuation_token: Option<String> = None;
+
+        loop {
+            let mut req = self
+                .source_client
+                .list_objects_v2()
+                .bucket(&self.source_bucket)
+                .prefix(listing_prefix);
+
+            if let Some(token) = continuation_token {
+                req = req.continuation_token(token);
+            }
+
+            let response = self.send(0, req.send()).await.with_context(|| {
+                format!(
+                    "Failed to list objects at s3://{}/{}",
+                    self.source_bucket, listing_prefix
+                )
+            })?;
+
+            for obj in response.contents() {
+                if let Some(key) = obj.key() {
+                    let key_string = key.to_string();
+                    if let Some(f) = filters
+                        && !f.matches(&key_string)
+                    {
+                        continue;
+                    }
+                    let size = obj.size().unwrap_or(0);
+                    listing.add_object(size);
+                    let listed = ListedObject {
+                        key: key_string,
+                        size,
+                        dest_key: None,
+                        version_id: None,
+                        e_tag: None,
+                        storage_class: None,
+                    };
+                    if tx.send(Ok(listed)).await.is_err() {
+                        // Consumer stopped; no point in listing further.
+                        return Ok(());
+                    }
+                }
+            }
+
+            if response.is_truncated().unwrap_or(false) {
+                continuation_token = response.next_continuation_token().map(|s| s.to_string());
+            } else {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Property-only sync: rewrite the destination in place via CopyObject with
+    /// MetadataDirective=REPLACE (objects up to 5 GiB).
+    async fn sync_properties(
+        &self,
+        metadata: &HeadObjectOutput,
+        source_tags: &Option<Vec<Tag>>,
+        tags_match: bool,
+    ) -> Result<()> {
+        let src_etag = metadata.e_tag.as_deref().unwrap_or_default();
+        // Property-only sync: Use CopyObject with MetadataDirective=REPLACE
+        let mut builder = self
+            .client
+            .copy_object()
+            .bucket(&self.dest_bucket)
+            .key(&self.dest_key)
+            .copy_source(self.copy_source())
+            .set_copy_source_if_match(self.source_etag.clone())
+            .set_if_none_match(self.dest_if_none_match())
+            .set_if_match(self.dest_if_match())
+            .metadata_directive(aws_sdk_s3::types::MetadataDirective::Replace);
+
+        // Apply ACL unless disabled
+        if self.full_control && !self.no_acl {
+            builder = builder.acl(ObjectCannedAcl::BucketOwnerFullControl);
+        }
+
+        // Set checksum algorithm if provided
+        if let Some(algo) = &self.checksum_algorithm {
+            builder = builder.checksum_algorithm(algo.clone());
+        }
+
+        // Set Encryption
+        if let Some(sse) = &self.sse {
+            builder = builder.server_side_encryption(sse.clone());
+        }
+        if let Some(key_id) = &self.sse_kms_key_id {
+            builder = builder.ssekms_key_id(key_id);
+        }
+
+        // Re-apply metadata unless disabled
+        if !self.no_metadata {
+            if let Some(ct) = metadata.content_type() {
+                builder = builder.content_type(ct);
+            }
+            if let Some(cc) = metadata.cache_control() {
+                builder = builder.cache_control(cc);
+            }
+            if let Some(cd) = metadata.content_disposition() {
+                builder = builder.content_disposition(cd);
+            }
+            if let Some(ce) = metadata.content_encoding() {
+                builder = builder.content_encoding(ce);
+            }
+            if let Some(cl) = metadata.content_language() {
+                builder = builder.content_language(cl);
+            }
+            if let Some(wr) = metadata.website_redirect_location() {
+                builder = builder.website_redirect_location(wr);
+            }
+            if let Some(ex) = metadata.expires_string()
+                && let Ok(dt) = aws_smithy_types::date_time::DateTime::from_str(
+                    ex,
+                    aws_smithy_types::date_time::Format::HttpDate,
+                )
+            {
+                builder = builder.set_expires(Some(dt));
+            }
+        }
+
+        // Re-apply custom metadata unless disabled (preserving our source-etag)
+        if !self.no_metadata
+            && let Some(m) = metadata.metadata()
+        {
+            for (k, v) in m {
+                if k != "source-etag" {
+                    builder = builder.metadata(k, v);
+                }
+            }
+        }
+        // Always maintain our source-etag tracking metadata
+        builder = builder.metadata("source-etag", src_etag);
+
+        // Re-apply storage class unless disabled
+        if let Some(sc) = &self.storage_class {
+            builder = builder.storage_class(sc.clone());
+        } else if !self.no_storage_class
+            && let Some(sc) = metadata.storage_class()
+        {
+            builder = builder.storage_class(sc.clone());
+        }
+
+        // Sync tags if needed and not disabled
+        if !self.no_tags
+            && !tags_match
+            && let Some(tags) = &source_tags
+        {
+            let tagging = tags
+                .iter()
+                .map(|t| format!("{}={}", t.key(), t.value()))
+                .collect::<Vec<_>>()
+                .join("&");
+            builder = builder.tagging(tagging);
+            builder = builder.tagging_directive(aws_sdk_s3::types::TaggingDirective::Replace);
+        }
+
+        if self.dry_run {
+            self.info("   [Dry Run] Would sync properties via CopyObject (REPLACE directive)");
+        } else {
+            let _slot = self.acquire_part_slot().await?;
+            match self
+                .send(
+                    metadata.content_length().unwrap_or(0) as u64,
+                    builder.send(),
+                )
+                .await
+            {
+                Err(e) if is_precondition_failed(&e) => {
+                    return Err(self.copy_object_precondition_error().await);
+                }
+                result => {
+                    result.with_context(|| "Failed to sync properties via CopyObject")?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Single-request CopyObject for objects below 5 GiB (auto mode).
+    async fn instant_copy(
+        &self,
+        metadata: &HeadObjectOutput,
+        source_tags: &Option<Vec<Tag>>,
+    ) -> Result<()> {
+        let src_etag = metadata.e_tag.as_deref().unwrap_or_default();
+        let mut builder = self
+            .client
+            .copy_object()
+            .bucket(&self.dest_bucket)
+            .key(&self.dest_key)
+            .copy_source(self.copy_source())
+            .set_copy_source_if_match(self.source_etag.clone())
+            .set_if_none_match(self.dest_if_none_match())
+            .set_if_match(self.dest_if_match())
+            .metadata_directive(aws_sdk_s3::types::MetadataDirective::Replace);
+
+        // Apply ACL
+        if self.full_control && !self.no_acl {
+            builder = builder.acl(ObjectCannedAcl::BucketOwnerFullControl);
+        }
+
+        // Set checksum algorithm if provided
+        if let Some(algo) = &self.checksum_algorithm {
+            builder = builder.checksum_algorithm(algo.clone());
+        }
+
+        // Set Encryption
+        if let Some(sse) = &self.sse {
+            builder = builder.server_side_encryption(sse.clone());
+        }
+        if let Some(key_id) = &self.sse_kms_key_id {
+            builder = builder.ssekms_key_id(key_id);
+        }
+
+        // Apply metadata
+        if !self.no_metadata {
+            if let Some(ct) = metadata.content_type() {
+                builder = builder.content_type(ct);
+            }
+            if let Some(cc) = metadata.cache_control() {
+                builder = builder.cache_control(cc);
+            }
+            if let Some(cd) = metadata.content_disposition() {
+                builder = builder.content_disposition(cd);
+            }
+            if let Some(ce) = metadata.content_encoding() {
+                builder = builder.content_encoding(ce);
+            }
+            if let Some(cl) = metadata.content_language() {
+                builder = builder.content_language(cl);
+            }
+            if let Some(wr) = metadata.website_redirect_location() {
+                builder = builder.website_redirect_location(wr);
+            }
+            if let Some(ex) = metadata.expires_string()
+                && let Ok(dt) = aws_smithy_types::date_time::DateTime::from_str(
+                    ex,
+                    aws_smithy_types::date_time::Format::HttpDate,
+                )
+            {
+                builder = builder.set_expires(Some(dt));
+            }
+
+            // Re-apply custom metadata (preserving our source-etag)
+            if let Some(m) = metadata.metadata() {
+                for (k, v) in m {
+                    if k != "source-etag" {
+                        builder = builder.metadata(k, v);
+                    }
+                }
+            }
+        }
+        // Always maintain our source-etag tracking metadata
+        builder = builder.metadata("source-etag", src_etag);
+
+        // Apply storage class
+        if let Some(sc) = &self.storage_class {
+            builder = builder.storage_class(sc.clone());
+        } else if !self.no_storage_class
+            && let Some(sc) = metadata.storage_class()
+        {
+            builder = builder.storage_class(sc.clone());
+        }
+
+        // Apply tags
+        if !self.no_tags
+            && let Some(tags) = &source_tags
+            && !tags.is_empty()
+        {
+            let tagging = tags
+                .iter()
+                .map(|t| format!("{}={}", t.key(), t.value()))
+                .collect::<Vec<_>>()
+                .join("&");
+            builder = builder.tagging(tagging);
+            builder = builder.tagging_directive(aws_sdk_s3::types::TaggingDirective::Replace);
+        }
+
+        if self.dry_run {
+            self.info("   [Dry Run] Would perform Instant Copy (CopyObject)");
+        } else {
+            let _slot = self.acquire_part_slot().await?;
+            match self
+                .send(
+                    metadata.content_length().unwrap_or(0) as u64,
+                    builder.send(),
+                )
+                .await
+            {
+                Err(e) if is_precondition_failed(&e) => {
+                    return Err(self.copy_object_precondition_error().await);
+                }
+                result => {
+                    result.with_context(|| "Failed to perform Instant Copy")?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Part layout of a multipart source. GetObjectAttributes only lists the parts of
+    /// objects uploaded with checksums, so other sources are sized part by part with
+    /// `HeadObject ?partNumber=N`. `None` when the source is not a multipart object.
+    async fn source_part_layout(&self, content_length: i64) -> Result<Option<SourceLayout>> {
+        let layout = match self.part_layout_from_attributes().await {
+            Ok(Some(layout)) => Some(layout),
+            Ok(None) => self.part_layout_from_head().await?,
+            Err(e) => {
+                self.warn(format!(
+                    "Could not list source parts ({}); sizing them with HeadObject",
+                    e
+                ));
+                self.part_layout_from_head().await?
+            }
+        };
+        Ok(layout.filter(|l| l.part_sizes.iter().sum::<i64>() == content_length))
+    }
+
+    async fn part_layout_from_attributes(&self) -> Result<Option<SourceLayout>> {
+        let mut sizes = Vec::new();
+        let mut checksums = PartChecksums::default();
+        let mut checksum_type = None;
+        let mut marker: Option<String> = None;
+        loop {
+            let response = self
+                .send(
+                    0,
+                    self.source_client
+                        .get_object_attributes()
+                        .bucket(&self.source_bucket)
+                        .key(&self.source_key)
+                        .set_version_id(self.source_version_id.clone())
+                        .object_attributes(ObjectAttributes::ObjectParts)
+                        .object_attributes(ObjectAttributes::Checksum)
+                        .max_parts(1000)
+                        .set_part_number_marker(marker.take())
+                        .send(),
+                )
+                .await
+                .with_context(|| {
+                    format!("Failed to read the part layout of {}", self.source_uri())
+                })?;
+            if let Some(checksum) = response.checksum() {
+                checksums = part_checksums!(checksum);
+                checksum_type = checksum.checksum_type().cloned();
+            }
+            let Some(parts) = response.object_parts() else {
+                return Ok(None);
+            };
+            sizes.extend(parts.parts().iter().filter_map(|p| p.size()));
+            if parts.is_truncated().unwrap_or(false) {
+                marker = parts.next_part_number_marker().map(str::to_string);
+                if marker.is_none() {
+                    break;
+                }
+            } else {
+                let total = parts.total_parts_count().unwrap_or(0) as usize;
+                if sizes.is_empty() || sizes.len() != total {
+                    return Ok(None);
+                }
+                break;
+            }
+        }
+        Ok(Some(SourceLayout {
+            part_sizes: sizes,
+            checksum_algorithm: checksums.algorithm(),
+            checksum_type,
+        }))
+    }
+
+    async fn part_layout_from_head(&self) -> Result<Option<SourceLayout>> {
+        let first = self.head_source_part(1).await?;
+        let Some(count) = first.parts_count().filter(|count| *count > 0) else {
+            return Ok(None);
+        };
+
+        let slots = Arc::new(Semaphore::new(self.concurrency.max(1)));
+        let mut tasks = JoinSet::new();
+        for part_number in 2..=count {
+            let app = self.clone();
+            let slots = slots.clone();
+            tasks.spawn(async move {
+                let _permit = slots
+                    .acquire_owned()
+                    .await
+                    .context("Part layout discovery aborted")?;
+                let part = app.head_source_part(part_number).await?;
+                Ok::<_, anyhow::Error>((part_number, part.content_length().unwrap_or(0)))
+            });
+        }
+
+        let mut sizes = vec![0; count as usize];
+        sizes[0] = first.content_length().unwrap_or(0);
+        while let Some(joined) = tasks.join_next().await {
+            let (part_number, size) = joined.context("Part layout task join error")??;
+            sizes[part_number as usize - 1] = size;
+        }
+        Ok(Some(SourceLayout {
+            part_sizes: sizes,
+            checksum_algorithm: part_checksums!(&first).algorithm(),
+            checksum_type: first.checksum_type().cloned(),
+        }))
+    }
+
+    /// HeadObject for one part of the pinned source version.
+    async fn head_source_part(&self, part_number: i32) -> Result<HeadObjectOutput> {
+        let response = self
+            .send(
+                0,
+                self.source_client
+                    .head_object()
+                    .bucket(&self.source_bucket)
+                    .key(&self.source_key)
+                    .set_version_id(self.source_version_id.clone())
+                    .set_if_match(self.source_etag.clone())
+                    .part_number(part_number)
+                    .checksum_mode(ChecksumMode::Enabled)
+                    .send(),
+            )
+            .await;
+        match response {
+            Err(e) if is_precondition_failed(&e) => Err(self.source_changed().into()),
+            result => result.with_context(|| {
+                format!(
+                    "Failed to read part {} of {}",
+                    part_number,
+                    self.source_uri()
+                )
+            }),
+        }
+    }
+
+    /// Compare the finished destination with the source per `--verify-integrity`.
+    /// Mismatches are returned as `VerificationFailed`.
+    /// `expected_etag` is the multipart ETag computed from the copied parts, if known.
+    async fn verify_copy(
+        &self,
+        content_length: i64,
+        expected_etag: Option<&str>,
+    ) -> Result<Verification> {
+        if !self.dry_run && self.verify_integrity != VerifyIntegrity::Off {
+            let source_metadata = self
+                .send(
+                    0,
+                    self.source_client
+                        .head_object()
+                        .bucket(&self.source_bucket)
+                        .key(&self.source_key)
+                        .set_version_id(self.source_version_id.clone())
+                        .send(),
+                )
+                .await
+                .with_context(|| "Failed to load source metadata for verification")?;
+            let dest_metadata = self
+                .send(
+                    0,
+                    self.client
+                        .head_object()
+                        .bucket(&self.dest_bucket)
+                        .key(&self.dest_key)
+                        .send(),
+                )
+                .await
+                .with_context(|| "Failed to verify destination object")?;
+
+            if dest_metadata.content_length != Some(content_length) {
+                return Err(VerificationFailed(format!(
+                    "source/destination size mismatch ({} != {})",
+                    content_length,
+                    dest_metadata.content_length.unwrap_or(0)
+                ))
+                .into());
+            }
+
+            match self.verify_integrity {
+                VerifyIntegrity::Off => {}
+                VerifyIntegrity::Etag => {
+                    let src_etag = source_metadata.e_tag().unwrap_or_default();
+                    let dst_etag = dest_metadata.e_tag().unwrap_or_default();
+                    if let Some(expected) = expected_etag
+                        && src_etag != dst_etag
+                    {
+                        // Layouts differ, so the ETags can't match; check that S3 assembled
+                        // exactly the parts that were copied instead.
+                        if dst_etag != expected {
+                            return Err(VerificationFailed(format!(
+                                "destination ETag {} does not match {} computed from the copied parts",
+                                dst_etag, expected
+                            ))
+                            .into());
+                        }
+                    } else if !src_etag.is_empty() && !dst_etag.is_empty() && src_etag != dst_etag {
+                        let tracked_src = dest_metadata
+                            .metadata()
+                            .and_then(|m| m.get("source-etag"))
+                            .map(|v| format!("\"{}\"", v.trim_matches('"')))
+                            .unwrap_or_default();
+                        let normalized_src = format!("\"{}\"", src_etag.trim_matches('"'));
+                        if tracked_src != normalized_src {
+                            return Err(VerificationFailed(
+                                "ETag mismatch and source-etag metadata mismatch".to_string(),
+                            )
+                            .into());
+                        }
+                    }
+                }
+                VerifyIntegrity::Checksum => {
+                    let provider = HeadObjectChecksumProvider;
+                    Self::verify_checksum_with_provider(
+                        &provider,
+                        &source_metadata,
+                        &dest_metadata,
+                    )
+                    .map_err(|e| VerificationFailed(e.to_string()))?;
+                }
+                VerifyIntegrity::Deep => self.deep_verify(content_length).await?,
+            }
+
+            self.info(format!(
+                "\n✅ Copy verification successful!\n   Source size:      {} bytes\n   Destination size: {} bytes\n   Mode:             {:?}",
+                content_length,
+                dest_metadata.content_length.unwrap_or(0),
+                self.verify_integrity
+            ));
+            Ok(Verification::Passed)
+        } else {
+            self.info("\n[Dry Run/Config] Copy verification skipped.");
+            Ok(Verification::NotRun)
+        }
+    }
+
+    /// Re-read both objects with parallel ranged GETs and compare local digests of every
+    /// range. Works regardless of how either object was uploaded.
+    async fn deep_verify(&self, content_length: i64) -> Result<()> {
+        let algorithm = DigestAlgorithm::for_checksum(self.checksum_algorithm.as_ref());
+        let ranges = plan_parts(1, 0, content_length, DEEP_VERIFY_RANGE_SIZE);
+        self.info(format!(
+            "\n🔍 Deep verification: comparing {} ranges ({:?}, {} GETs per object)...",
+            ranges.len(),
+            algorithm,
+            ranges.len()
+        ));
+
+        let slots = Arc::new(Semaphore::new(self.concurrency.max(1)));
+        let mut tasks = JoinSet::new();
+        for range in ranges {
+            let app = self.clone();
+            let slots = slots.clone();
+            tasks.spawn(async move {
+                let _permit = slots
+                    .acquire_owned()
+                    .await
+                    .context("Verification aborted")?;
+                // In prefix mode, verification reads share the part-level budget.
+                let _slot = app.acquire_part_slot().await?;
+                let source = digest_range(
+                    &app.source_client,
+                    &app.source_bucket,
+                    &app.source_key,
+                    app.source_version_id.clone(),
+                    app.source_etag.clone(),
+                    range.start,
+                    range.end,
+                    algorithm,
+                );
+                let dest = digest_range(
+                    &app.client,
+                    &app.dest_bucket,
+                    &app.dest_key,
+                    None,
+                    None,
+                    range.start,
+                    range.end,
+                    algorithm,
+                );
+                let bytes = range.len() as u64;
+                let (source, dest) =
+                    tokio::try_join!(app.send(bytes, source), app.send(bytes, dest))?;
+                Ok::<_, anyhow::Error>(RangeDigest {
+                    start: range.start,
+                    end: range.end,
+                    source,
+                    dest,
+                })
+            });
+        }
+
+        let mut digests = Vec::with_capacity(tasks.len());
+        while let Some(joined) = tasks.join_next().await {
+            digests.push(joined.context("Verification task join error")??);
+        }
+        if let Some((start, end)) = first_mismatch(&digests) {
+            return Err(VerificationFailed(format!(
+                "data mismatch in bytes {}-{} ({:?})",
+                start, end, algorithm
+            ))
+            .into());
+        }
+        Ok(())
+    }
+
+    /// Copy the file using multipart upload
+    pub async fn copy_file(&self) -> Result<CopyOutcome> {
+        let mut restarts = 0;
+        loop {
+            match self.copy_current_source().await {
+                Err(e)
+                    if e.is::<SourceChanged>()
+                        && self.on_source_change == OnSourceChange::Restart
+                        && restarts < MAX_SOURCE_CHANGE_RESTARTS =>
+                {
+                    restarts += 1;
+                    self.warn(format!(
+                        "{}. Restarting copy ({}/{})",
+                        e, restarts, MAX_SOURCE_CHANGE_RESTARTS
+                    ));
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Copy whatever the source currently holds, guarded against later changes.
+    async fn copy_current_source(&self) -> Result<CopyOutcome> {
+        self.wait_until_runnable().await?;
+        if !self.quiet {
+            self.emit(ProgressEvent::CopyStarted {
+                source: self.source_uri(),
+                destination: self.dest_uri(),
+                version_id: self.source_version_id.clone(),
+                part_size: self.part_size,
+                concurrency: self.concurrency,
+                streaming_buffer_mb: self.streaming_active().then_some(self.buffer_budget_mb),
+                dry_run: self.dry_run,
+            });
+        }
+
+        if let Some(skipped) = self.skip_from_listing().await? {
+            return Ok(skipped);
+        }
+
+        // Get source object metadata
         let metadata = self
             .get_object_metadata(&self.source_bucket, &self.source_key)
             .await?
@@ -1007,15 +3373,149 @@ impl S3CopyApp {
                     self.source_key
                 )
             })?;
+
+        // Pin every later read of the source to the version just inspected, so a
+        // concurrent overwrite cannot mix bytes from two versions, and make each read
+        // conditional on its ETag, which also covers unversioned buckets.
+        let mut pinned = self.clone();
+        if pinned.source_version_id.is_none() {
+            pinned.source_version_id = metadata.version_id().map(str::to_string);
+        }
+        pinned.source_etag = metadata.e_tag().map(str::to_string);
+
+        // Mirror a multipart source's part boundaries, and its checksum algorithm
+        // unless one was requested, so the destination ETag and composite checksum
+        // equal the source's.
+        if self.match_source_parts && metadata.e_tag().is_some_and(|e| e.contains('-')) {
+            let content_length = metadata.content_length().unwrap_or(0);
+            if let Some(layout) = pinned.source_part_layout(content_length).await? {
+                if pinned.checksum_algorithm.is_none() {
+                    pinned.checksum_algorithm = layout.checksum_algorithm.clone();
+                    pinned.checksum_type = layout.checksum_type.clone();
+                }
+                pinned.source_layout = Some(layout);
+            }
+        }
+        pinned.copy_with_metadata(metadata).await
+    }
+
+    /// Skip an object whose inventory entry already matches the destination in size,
+    /// ETag and storage class (and tags, unless `--no-tags`), without a source
+    /// HeadObject. Anything else falls through to the full comparison.
+    async fn skip_from_listing(&self) -> Result<Option<CopyOutcome>> {
+        let Some(listed) = &self.listed_source else {
+            return Ok(None);
+        };
+        let Some(src_etag) = listed.e_tag.as_deref().map(|e| e.trim_matches('"')) else {
+            return Ok(None);
+        };
+        if self.force_copy || self.dest_guard.is_some() {
+            return Ok(None);
+        }
+        let Some(dest) = self
+            .get_object_metadata(&self.dest_bucket, &self.dest_key)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let dest_etag = dest.e_tag().unwrap_or_default().trim_matches('"');
+        let dest_stored_src_etag = dest
+            .metadata()
+            .and_then(|m| m.get("source-etag"))
+            .map(|e| e.trim_matches('"'));
+        let data_match = dest.content_length().unwrap_or(0) == listed.size
+            && (dest_etag == src_etag || dest_stored_src_etag == Some(src_etag));
+        // HeadObject omits the storage class of STANDARD objects.
+        let expected_class = self
+            .storage_class
+            .clone()
+            .or_else(|| listed.storage_class.as_deref().map(StorageClass::from))
+            .filter(|c| *c != StorageClass::Standard);
+        let storage_class_match = self.no_storage_class
+            || dest
+                .storage_class()
+                .filter(|c| **c != StorageClass::Standard)
+                == expected_class.as_ref();
+        if !data_match || !storage_class_match {
+            return Ok(None);
+        }
+        if !self.no_tags {
+            let source_tags = self
+                .get_object_tagging(&self.source_bucket, &self.source_key)
+                .await?;
+            let dest_tags = self
+                .get_object_tagging(&self.dest_bucket, &self.dest_key)
+                .await?;
+            if source_tags != dest_tags {
+                return Ok(None);
+            }
+        }
+
+        self.info(format!(
+            "⏭️  Skipping copy: Destination {} matches the inventory entry (size, ETag, storage class).",
+            self.dest_uri()
+        ));
+        Ok(Some(CopyOutcome {
+            action: CopyAction::Skipped,
+            size: listed.size,
+            parts: 0,
+            data_path: None,
+            verification: Verification::Passed,
+        }))
+    }
+
+    /// Copy the source object version described by `metadata`.
+    async fn copy_with_metadata(&self, metadata: HeadObjectOutput) -> Result<CopyOutcome> {
         let content_length = metadata.content_length.unwrap_or(0);
 
+        // Conditional-write guards are checked up front so a doomed copy moves no data;
+        // the conditional requests themselves still catch concurrent writers.
+        if self.dest_guard == Some(DestinationGuard::NoClobber)
+            && self
+                .get_object_metadata(&self.dest_bucket, &self.dest_key)
+                .await?
+                .is_some()
+        {
+            self.info(format!(
+                "⏭️  Skipping copy: Destination {} already exists (--no-clobber).",
+                self.dest_uri()
+            ));
+            return Ok(CopyOutcome {
+                action: CopyAction::Skipped,
+                size: content_length,
+                parts: 0,
+                data_path: None,
+                verification: Verification::NotRun,
+            });
+        }
+        if let Some(DestinationGuard::IfETag(expected)) = &self.dest_guard {
+            let current = self
+                .get_object_metadata(&self.dest_bucket, &self.dest_key)
+                .await?
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "Destination {} does not exist (--if-dest-etag requires an existing object)",
+                        self.dest_uri()
+                    )
+                })?;
+            let current_etag = current.e_tag().unwrap_or_default();
+            if current_etag.trim_matches('"') != expected.trim_matches('"') {
+                return Err(DestinationExists(format!(
+                    "{} has ETag {}, expected {}",
+                    self.dest_uri(),
+                    current_etag,
+                    expected
+                ))
+                .into());
+            }
+        }
+
         // Check if destination exists and is identical unless forced.
         if self.force_copy {
-            if !self.quiet {
-                println!(
-                    "⚠️  Force copy enabled: destination pre-check optimizations are disabled. Object will be overwritten."
-                );
-            }
+            self.info(
+                "⚠️  Force copy enabled: destination pre-check optimizations are disabled. Object will be overwritten.",
+            );
         } else if let Some(dest_metadata) = self
             .get_object_metadata(&self.dest_bucket, &self.dest_key)
             .await?
@@ -1037,9 +3537,7 @@ impl S3CopyApp {
                 && (dest_etag == src_etag || dest_stored_src_etag == standardized_src_etag)
             {
                 // Data matches. Now check if properties need syncing.
-                if !self.quiet {
-                    println!("✅ Data identity verified (Size & ETag). Checking properties...");
-                }
+                self.info("✅ Data identity verified (Size & ETag). Checking properties...");
 
                 let source_tags = if self.no_tags {
                     None
@@ -1070,302 +3568,130 @@ impl S3CopyApp {
                         && dest_metadata.expires_string() == metadata.expires_string());
 
                 if tags_match && storage_class_match && metadata_match {
-                    if !self.quiet {
-                        println!(
-                            "⏭️  Skipping copy: Destination s3://{}/{} is already identical in data and properties.",
-                            self.dest_bucket, self.dest_key
-                        );
-                    }
-                    return Ok(());
-                } else if content_length <= 5 * 1024 * 1024 * 1024 {
-                    if !self.quiet {
-                        println!(
-                            "🔄 Data matches but properties differ. Performing property-only sync via CopyObject..."
-                        );
-                    }
-                    // Property-only sync: Use CopyObject with MetadataDirective=REPLACE
-                    let mut builder = self
-                        .client
-                        .copy_object()
-                        .bucket(&self.dest_bucket)
-                        .key(&self.dest_key)
-                        .copy_source(format!("{}/{}", self.source_bucket, self.source_key))
-                        .metadata_directive(aws_sdk_s3::types::MetadataDirective::Replace);
-
-                    // Apply ACL unless disabled
-                    if self.full_control && !self.no_acl {
-                        builder = builder.acl(ObjectCannedAcl::BucketOwnerFullControl);
-                    }
-
-                    // Set checksum algorithm if provided
-                    if let Some(algo) = &self.checksum_algorithm {
-                        builder = builder.checksum_algorithm(algo.clone());
-                    }
-
-                    // Set Encryption
-                    if let Some(sse) = &self.sse {
-                        builder = builder.server_side_encryption(sse.clone());
-                    }
-                    if let Some(key_id) = &self.sse_kms_key_id {
-                        builder = builder.ssekms_key_id(key_id);
-                    }
-
-                    // Re-apply metadata unless disabled
-                    if !self.no_metadata {
-                        if let Some(ct) = metadata.content_type() {
-                            builder = builder.content_type(ct);
-                        }
-                        if let Some(cc) = metadata.cache_control() {
-                            builder = builder.cache_control(cc);
-                        }
-                        if let Some(cd) = metadata.content_disposition() {
-                            builder = builder.content_disposition(cd);
-                        }
-                        if let Some(ce) = metadata.content_encoding() {
-                            builder = builder.content_encoding(ce);
-                        }
-                        if let Some(cl) = metadata.content_language() {
-                            builder = builder.content_language(cl);
-                        }
-                        if let Some(wr) = metadata.website_redirect_location() {
-                            builder = builder.website_redirect_location(wr);
-                        }
-                        if let Some(ex) = metadata.expires_string()
-                            && let Ok(dt) = aws_smithy_types::date_time::DateTime::from_str(
-                                ex,
-                                aws_smithy_types::date_time::Format::HttpDate,
-                            )
-                        {
-                            builder = builder.set_expires(Some(dt));
-                        }
-                    }
-
-                    // Re-apply custom metadata unless disabled (preserving our source-etag)
-                    if !self.no_metadata
-                        && let Some(m) = metadata.metadata()
+                    self.info(format!(
+                        "⏭️  Skipping copy: Destination s3://{}/{} is already identical in data and properties.",
+                        self.dest_bucket, self.dest_key
+                    ));
+                    return Ok(CopyOutcome {
+                        action: CopyAction::Skipped,
+                        size: content_length,
+                        parts: 0,
+                        data_path: None,
+                        verification: Verification::Passed,
+                    });
+                } else if content_length <= 5 * 1024 * 1024 * 1024 && !self.streaming_active() {
+                    self.info("🔄 Data matches but properties differ. Performing property-only sync via CopyObject...");
+                    match self
+                        .sync_properties(&metadata, &source_tags, tags_match)
+                        .await
                     {
-                        for (k, v) in m {
-                            if k != "source-etag" {
-                                builder = builder.metadata(k, v);
-                            }
+                        Ok(()) => {
+                            self.info("✨ Property sync completed successfully.");
+                            return Ok(CopyOutcome {
+                                action: CopyAction::PropertySync,
+                                size: content_length,
+                                parts: 0,
+                                data_path: Some(DataRoute::ServerSide),
+                                verification: Verification::Passed,
+                            });
                         }
-                    }
-                    // Always maintain our source-etag tracking metadata
-                    builder = builder.metadata("source-etag", src_etag);
-
-                    // Re-apply storage class unless disabled
-                    if let Some(sc) = &self.storage_class {
-                        builder = builder.storage_class(sc.clone());
-                    } else if !self.no_storage_class
-                        && let Some(sc) = metadata.storage_class()
-                    {
-                        builder = builder.storage_class(sc.clone());
-                    }
-
-                    // Sync tags if needed and not disabled
-                    if !self.no_tags
-                        && !tags_match
-                        && let Some(tags) = &source_tags
-                    {
-                        let tagging = tags
-                            .iter()
-                            .map(|t| format!("{}={}", t.key(), t.value()))
-                            .collect::<Vec<_>>()
-                            .join("&");
-                        builder = builder.tagging(tagging);
-                        builder =
-                            builder.tagging_directive(aws_sdk_s3::types::TaggingDirective::Replace);
-                    }
-
-                    if self.dry_run {
-                        if !self.quiet {
-                            println!(
-                                "   [Dry Run] Would sync properties via CopyObject (REPLACE directive)"
-                            );
+                        Err(e) if self.can_fall_back_to_streaming(&e) => {
+                            self.activate_streaming("CopyObject");
                         }
-                    } else {
-                        builder
-                            .send()
-                            .await
-                            .with_context(|| "Failed to sync properties via CopyObject")?;
-                    }
-
-                    if !self.quiet {
-                        println!("✨ Property sync completed successfully.");
+                        Err(e) => return Err(e),
                     }
-                    return Ok(());
                 } else if !tags_match && storage_class_match && metadata_match {
                     // Object > 5GB, but only tags changed. We can use PutObjectTagging.
-                    if !self.quiet {
-                        println!(
-                            "🔄 Data matches, object > 5GB, but ONLY tags differ. Syncing tags..."
-                        );
-                    }
+                    self.info(
+                        "🔄 Data matches, object > 5GB, but ONLY tags differ. Syncing tags...",
+                    );
                     if let Some(tags) = source_tags {
                         let tagging = Tagging::builder()
                             .set_tag_set(Some(tags))
                             .build()
                             .context("Failed to build tagging")?;
                         if self.dry_run {
-                            if !self.quiet {
-                                println!("   [Dry Run] Would update object tags");
-                            }
+                            self.info("   [Dry Run] Would update object tags");
                         } else {
-                            self.client
-                                .put_object_tagging()
-                                .bucket(&self.dest_bucket)
-                                .key(&self.dest_key)
-                                .tagging(tagging)
-                                .send()
-                                .await
-                                .with_context(|| "Failed to sync tags")?;
-                        }
-                        if !self.quiet {
-                            println!("✨ Tags updated successfully.");
+                            self.send(
+                                0,
+                                self.client
+                                    .put_object_tagging()
+                                    .bucket(&self.dest_bucket)
+                                    .key(&self.dest_key)
+                                    .tagging(tagging)
+                                    .send(),
+                            )
+                            .await
+                            .with_context(|| "Failed to sync tags")?;
                         }
-                        return Ok(());
+                        self.info("✨ Tags updated successfully.");
+                        return Ok(CopyOutcome {
+                            action: CopyAction::TagSync,
+                            size: content_length,
+                            parts: 0,
+                            data_path: None,
+                            verification: Verification::Passed,
+                        });
                     }
                 } else {
-                    if !self.quiet {
-                        println!(
-                            "🔄 Data matches, but object > 5GB and metadata/storage-class differ."
-                        );
-                        println!(
-                            "   S3 requires a full copy for metadata updates > 5GB. Proceeding with Multipart Copy..."
-                        );
-                    }
+                    self.info(
+                        "🔄 Data matches, but object > 5GB and metadata/storage-class differ.\n   S3 requires a full copy for metadata updates > 5GB. Proceeding with Multipart Copy...",
+                    );
                     // Fall through to regular multipart copy loop
                 }
             }
         }
 
         if content_length == 0 {
-            return Err(anyhow::anyhow!("Source object is empty"));
-        }
-
-        // Fetch source tags (needed for both Instant Copy and Multipart Initiate)
-        let source_tags = if self.no_tags {
-            None
-        } else {
-            self.get_object_tagging(&self.source_bucket, &self.source_key)
-                .await?
-        };
-
-        // Instant copy path for small objects when auto mode is enabled.
-        if is_instant_copy(self.auto, content_length) {
-            if !self.quiet {
-                println!(
-                    "🤖 Auto Mode: Small file detected ({:.2} MB). Using Instant Copy (CopyObject)...",
-                    content_length as f64 / (1024.0 * 1024.0)
-                );
-            }
-
-            let src_etag = metadata.e_tag.as_deref().unwrap_or_default();
-            let mut builder = self
-                .client
-                .copy_object()
-                .bucket(&self.dest_bucket)
-                .key(&self.dest_key)
-                .copy_source(format!("{}/{}", self.source_bucket, self.source_key))
-                .metadata_directive(aws_sdk_s3::types::MetadataDirective::Replace);
-
-            // Apply ACL
-            if self.full_control && !self.no_acl {
-                builder = builder.acl(ObjectCannedAcl::BucketOwnerFullControl);
-            }
-
-            // Set checksum algorithm if provided
-            if let Some(algo) = &self.checksum_algorithm {
-                builder = builder.checksum_algorithm(algo.clone());
-            }
-
-            // Set Encryption
-            if let Some(sse) = &self.sse {
-                builder = builder.server_side_encryption(sse.clone());
-            }
-            if let Some(key_id) = &self.sse_kms_key_id {
-                builder = builder.ssekms_key_id(key_id);
-            }
-
-            // Apply metadata
-            if !self.no_metadata {
-                if let Some(ct) = metadata.content_type() {
-                    builder = builder.content_type(ct);
-                }
-                if let Some(cc) = metadata.cache_control() {
-                    builder = builder.cache_control(cc);
-                }
-                if let Some(cd) = metadata.content_disposition() {
-                    builder = builder.content_disposition(cd);
-                }
-                if let Some(ce) = metadata.content_encoding() {
-                    builder = builder.content_encoding(ce);
-                }
-                if let Some(cl) = metadata.content_language() {
-                    builder = builder.content_language(cl);
-                }
-                if let Some(wr) = metadata.website_redirect_location() {
-                    builder = builder.website_redirect_location(wr);
-                }
-                if let Some(ex) = metadata.expires_string()
-                    && let Ok(dt) = aws_smithy_types::date_time::DateTime::from_str(
-                        ex,
-                        aws_smithy_types::date_time::Format::HttpDate,
-                    )
-                {
-                    builder = builder.set_expires(Some(dt));
-                }
-
-                // Re-apply custom metadata (preserving our source-etag)
-                if let Some(m) = metadata.metadata() {
-                    for (k, v) in m {
-                        if k != "source-etag" {
-                            builder = builder.metadata(k, v);
-                        }
-                    }
-                }
-            }
-            // Always maintain our source-etag tracking metadata
-            builder = builder.metadata("source-etag", src_etag);
-
-            // Apply storage class
-            if let Some(sc) = &self.storage_class {
-                builder = builder.storage_class(sc.clone());
-            } else if !self.no_storage_class
-                && let Some(sc) = metadata.storage_class()
-            {
-                builder = builder.storage_class(sc.clone());
-            }
+            return Err(anyhow::anyhow!("Source object is empty"));
+        }
 
-            // Apply tags
-            if !self.no_tags
-                && let Some(tags) = &source_tags
-                && !tags.is_empty()
-            {
-                let tagging = tags
-                    .iter()
-                    .map(|t| format!("{}={}", t.key(), t.value()))
-                    .collect::<Vec<_>>()
-                    .join("&");
-                builder = builder.tagging(tagging);
-                builder = builder.tagging_directive(aws_sdk_s3::types::TaggingDirective::Replace);
-            }
+        // Fetch source tags (needed for both Instant Copy and Multipart Initiate)
+        let source_tags = if self.no_tags {
+            None
+        } else {
+            self.get_object_tagging(&self.source_bucket, &self.source_key)
+                .await?
+        };
 
-            if self.dry_run {
-                if !self.quiet {
-                    println!("   [Dry Run] Would perform Instant Copy (CopyObject)");
-                }
+        // Instant copy path for small objects when auto mode is enabled. A single
+        // CopyObject cannot reproduce a multipart source's layout, but is the only
+        // way to keep a single-part source's plain MD5 ETag.
+        let multipart_source = metadata.e_tag().is_some_and(|e| e.contains('-'));
+        let single_part_match = self.match_source_parts && !multipart_source;
+        if is_instant_copy(self.auto || single_part_match, content_length)
+            && !self.streaming_active()
+            && !(self.match_source_parts && multipart_source)
+        {
+            if self.auto {
+                self.info(format!(
+                    "🤖 Auto Mode: Small file detected ({:.2} MB). Using Instant Copy (CopyObject)...",
+                    content_length as f64 / (1024.0 * 1024.0)
+                ));
             } else {
-                builder
-                    .send()
-                    .await
-                    .with_context(|| "Failed to perform Instant Copy")?;
+                self.info(
+                    "🧩 Source is a single-part object. Using Instant Copy (CopyObject) to keep its ETag...",
+                );
             }
 
-            if !self.quiet {
-                println!("✨ Instant Copy completed successfully.");
+            match self.instant_copy(&metadata, &source_tags).await {
+                Ok(()) => {
+                    self.info("✨ Instant Copy completed successfully.");
+                    let verification = self.verify_copy(content_length, None).await?;
+                    return Ok(CopyOutcome {
+                        action: CopyAction::InstantCopy,
+                        size: content_length,
+                        parts: 0,
+                        data_path: Some(DataRoute::ServerSide),
+                        verification,
+                    });
+                }
+                Err(e) if self.can_fall_back_to_streaming(&e) => {
+                    self.activate_streaming("CopyObject");
+                }
+                Err(e) => return Err(e),
             }
-            return Ok(());
         }
 
         let mut part_size = self.part_size;
@@ -1381,11 +3707,7 @@ impl S3CopyApp {
             ) {
                 (Ok(src), Ok(dst)) => src == dst,
                 _ => {
-                    if !self.quiet {
-                        println!(
-                            "⚠️  Auto Mode: Could not determine both bucket regions. Assuming cross-region defaults."
-                        );
-                    }
+                    self.warn("Auto Mode: Could not determine both bucket regions. Assuming cross-region defaults.");
                     false
                 }
             };
@@ -1400,38 +3722,111 @@ impl S3CopyApp {
             target_concurrency = auto_plan.initial_concurrency;
             max_auto_concurrency = auto_plan.max_concurrency;
             probe_parts = auto_plan.probe_parts;
-            if !self.quiet {
-                println!(
-                    "🤖 Auto Mode: profile={:?}, initial part size={} MB, concurrency start={} (max {})",
-                    self.auto_profile,
-                    part_size / 1024 / 1024,
-                    target_concurrency,
-                    max_auto_concurrency
-                );
-            }
+            self.info(format!(
+                "🤖 Auto Mode: profile={:?}, initial part size={} MB, concurrency start={} (max {})",
+                self.auto_profile,
+                part_size / 1024 / 1024,
+                target_concurrency,
+                max_auto_concurrency
+            ));
         }
 
-        part_size = clamp_part_size_for_limit(content_length, part_size, 10000);
+        part_size = clamp_part_size_for_limit(
+            content_length,
+            self.fit_part_size_to_buffer(part_size),
+            10000,
+        );
 
-        // Initiate multipart upload
-        if !self.quiet {
-            println!("\n📤 Initiating multipart upload...");
+        // Mirror the source part boundaries so the destination ETag equals the source's.
+        let source_layout = self.source_layout.as_ref().map(|l| l.part_sizes.as_slice());
+        if self.match_source_parts {
+            match (source_layout, &self.checksum_algorithm) {
+                (Some(sizes), Some(algo)) => self.info(format!(
+                    "🧩 Matching source part layout ({} parts, {} checksums)",
+                    sizes.len(),
+                    algo.as_str()
+                )),
+                (Some(sizes), None) => self.info(format!(
+                    "🧩 Matching source part layout ({} parts)",
+                    sizes.len()
+                )),
+                (None, _) => self.warn(format!(
+                    "Source part layout is not available; using {} MB parts",
+                    part_size / 1024 / 1024
+                )),
+            }
         }
-        let src_etag = metadata.e_tag.as_deref().unwrap_or_default();
-        let upload_id = self
-            .initiate_multipart_upload(src_etag, &metadata, source_tags)
-            .await?;
-        if !self.quiet {
-            println!("   Upload ID: {}", upload_id);
+        if source_layout.is_some() {
+            probe_parts = 0;
         }
 
+        let src_etag = metadata.e_tag.as_deref().unwrap_or_default();
+        // --on-interrupt keep journals even without --resume, so a kept upload can be resumed.
+        let journaling = (self.resume || self.on_interrupt == OnInterrupt::Keep) && !self.dry_run;
+        let resume_point = if self.resume && journaling {
+            self.load_resume_point(src_etag, content_length, part_size)
+                .await?
+        } else {
+            None
+        };
+        let resuming = resume_point.is_some();
+
+        let (upload_id, journal, resumed_parts, resumed_pending) = match resume_point {
+            Some(point) => {
+                self.info(format!(
+                    "\n♻️  Resuming multipart upload {} ({} parts already copied, {} remaining)",
+                    point.upload_id,
+                    point.completed.len(),
+                    point.pending.len()
+                ));
+                (
+                    point.upload_id,
+                    Some(point.journal),
+                    point.completed,
+                    point.pending,
+                )
+            }
+            None => {
+                // Initiate multipart upload
+                self.info("\n📤 Initiating multipart upload...");
+                let upload_id = self
+                    .initiate_multipart_upload(src_etag, &metadata, source_tags)
+                    .await?;
+                self.info(format!("   Upload ID: {}", upload_id));
+                // Written with or without --resume, so `cleanup` can attribute the upload
+                // if this process dies before completing or aborting it.
+                if !self.dry_run
+                    && let Err(e) = self.upload_marker(&upload_id).write(&self.journal_dir)
+                {
+                    self.warn(format!("Failed to write upload marker: {}", e));
+                }
+                let journal = if journaling {
+                    let key = self.journal_key();
+                    Some(UploadJournal::create(
+                        &key.path_in(&self.journal_dir),
+                        &key,
+                        src_etag,
+                        content_length,
+                        &upload_id,
+                    )?)
+                } else {
+                    None
+                };
+                (upload_id, journal, Vec::new(), Vec::new())
+            }
+        };
+
+        // Parts and bytes copied by this run, reported if it is interrupted.
+        let mut copied_parts = 0usize;
+        let mut copied_bytes = 0u64;
+
         // Wrap the upload logic to ensure cleanup on failure
-        let upload_result: Result<()> = async {
-            let mut completed_parts: Vec<CompletedPart> = Vec::new();
+        let upload_result: Result<(Option<String>, usize)> = async {
+            let mut completed_parts: Vec<CompletedPart> = resumed_parts;
             let mut next_part_number: i32 = 1;
             let mut next_start_byte: i64 = 0;
 
-            if self.auto && probe_parts > 0 {
+            if !resuming && self.auto && probe_parts > 0 {
                 let probe_start = Instant::now();
                 let mut probe_measured_mib_s = 0.0_f64;
                 let mut probe_done = 0usize;
@@ -1440,24 +3835,38 @@ impl S3CopyApp {
                     ((content_length + part_size - 1) / part_size) as usize,
                 );
 
-                if !self.quiet {
-                    println!("🧪 Auto Mode: running warm-up probe ({} parts)...", max_probe);
-                }
+                self.info(format!(
+                    "🧪 Auto Mode: running warm-up probe ({} parts)...",
+                    max_probe
+                ));
 
                 for _ in 0..max_probe {
                     if next_start_byte >= content_length {
                         break;
                     }
                     let end_byte = std::cmp::min(next_start_byte + part_size, content_length) - 1;
-                    let range = format!("bytes={}-{}", next_start_byte, end_byte);
-                    let part_bytes = (end_byte - next_start_byte + 1) as u64;
+                    let planned = PlannedPart {
+                        part_number: next_part_number,
+                        start: next_start_byte,
+                        end: end_byte,
+                    };
+                    if let Some(journal) = &journal {
+                        journal.record_plan(&[planned])?;
+                    }
+                    let part_bytes = planned.len() as u64;
+                    self.wait_until_runnable().await?;
                     let started = Instant::now();
                     let part = self
-                        .upload_part_copy(&upload_id, next_part_number, &range)
+                        .copy_part(&upload_id, next_part_number, &planned.range())
                         .await?;
+                    if let Some(journal) = &journal {
+                        journal.record_part(next_part_number, part.e_tag().unwrap_or_default())?;
+                    }
                     let secs = started.elapsed().as_secs_f64().max(0.001);
                     probe_measured_mib_s += (part_bytes as f64 / (1024.0 * 1024.0)) / secs;
                     completed_parts.push(part);
+                    copied_parts += 1;
+                    copied_bytes += part_bytes;
                     next_part_number += 1;
                     next_start_byte = end_byte + 1;
                     probe_done += 1;
@@ -1482,73 +3891,67 @@ impl S3CopyApp {
                         let remaining_slots = (10000 - (next_part_number - 1) as usize).max(1);
                         part_size = clamp_part_size_for_limit(
                             remaining,
-                            cost_optimized,
+                            self.fit_part_size_to_buffer(cost_optimized),
                             remaining_slots as i64,
                         );
                     }
-                    if !self.quiet {
-                        println!(
-                            "🧪 Probe completed in {:.2}s at {:.1} MiB/s. Tuned part size={} MB",
-                            probe_start.elapsed().as_secs_f64(),
-                            avg_probe_mib_s,
-                            part_size / 1024 / 1024
-                        );
-                    }
+                    self.info(format!(
+                        "🧪 Probe completed in {:.2}s at {:.1} MiB/s. Tuned part size={} MB",
+                        probe_start.elapsed().as_secs_f64(),
+                        avg_probe_mib_s,
+                        part_size / 1024 / 1024
+                    ));
                 }
             }
 
-            let remaining_bytes = content_length - next_start_byte;
-            let remaining_parts = if remaining_bytes > 0 {
-                ((remaining_bytes + part_size - 1) / part_size) as usize
+            let pending_parts = if resuming {
+                resumed_pending
             } else {
-                0
+                let planned = match source_layout {
+                    Some(sizes) => plan_from_layout(sizes),
+                    None => {
+                        plan_parts(next_part_number, next_start_byte, content_length, part_size)
+                    }
+                };
+                if let Some(journal) = &journal {
+                    journal.record_plan(&planned)?;
+                }
+                planned
             };
+
+            let remaining_bytes: i64 = pending_parts.iter().map(|p| p.len()).sum();
+            let remaining_parts = pending_parts.len();
             let num_parts = completed_parts.len() + remaining_parts;
 
-            if !self.quiet {
-                println!(
-                    "File size: {:.2} GB",
-                    content_length as f64 / (1024.0 * 1024.0 * 1024.0)
-                );
-                println!("Number of parts: {}", num_parts);
-                println!("Final part size: {} MB", part_size / 1024 / 1024);
-            }
+            self.emit(ProgressEvent::UploadStarted {
+                source: self.source_uri(),
+                destination: self.dest_uri(),
+                upload_id: upload_id.clone(),
+                size: content_length,
+                part_size,
+                parts: num_parts,
+                resumed_parts: completed_parts.len(),
+                resumed_bytes: content_length - remaining_bytes,
+            });
 
             let progress = CopyProgress::new(remaining_parts);
-            let progress_bar = if self.quiet {
-                ProgressBar::hidden()
-            } else {
-                let pb = ProgressBar::new(remaining_bytes.max(0) as u64);
-                pb.set_style(
-                    ProgressStyle::default_bar()
-                        .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {bytes}/{total_bytes} ({percent}%) {binary_bytes_per_sec} ETA: {eta} {msg}")
-                        .unwrap()
-                        .progress_chars("=>-"),
-                );
-                pb
-            };
-
-            if !self.quiet && remaining_parts > 0 {
-                println!("\n📥 Copying parts...\n");
-            }
-
-            while next_start_byte < content_length {
-                let mut batch = Vec::with_capacity(target_concurrency);
-                for _ in 0..target_concurrency {
-                    if next_start_byte >= content_length {
-                        break;
-                    }
-                    let end_byte = std::cmp::min(next_start_byte + part_size, content_length) - 1;
-                    let range = format!("bytes={}-{}", next_start_byte, end_byte);
-                    let part_bytes = (end_byte - next_start_byte + 1) as u64;
-                    batch.push((next_part_number, range, part_bytes));
-                    next_part_number += 1;
-                    next_start_byte = end_byte + 1;
-                }
 
-                let (mut window_parts, metrics) = self
-                    .run_copy_window(&upload_id, batch, &progress, &progress_bar)
-                    .await?;
+            let (probed_parts, probed_bytes) = (copied_parts, copied_bytes);
+            let mut cursor = 0usize;
+            while cursor < pending_parts.len() {
+                let window_end = std::cmp::min(cursor + target_concurrency, pending_parts.len());
+                let batch = pending_parts[cursor..window_end]
+                    .iter()
+                    .map(|p| (p.part_number, p.range(), p.len() as u64))
+                    .collect::<Vec<_>>();
+                cursor = window_end;
+
+                let window = self
+                    .run_copy_window(&upload_id, batch, &progress, journal.as_ref())
+                    .await;
+                copied_parts = probed_parts + progress.completed_parts.load(Ordering::SeqCst);
+                copied_bytes = probed_bytes + progress.copied_bytes.load(Ordering::SeqCst);
+                let (mut window_parts, metrics) = window?;
                 completed_parts.append(&mut window_parts);
 
                 if self.auto {
@@ -1559,118 +3962,109 @@ impl S3CopyApp {
                         max_auto_concurrency,
                         metrics,
                     );
-                    if next != target_concurrency && !self.quiet {
-                        println!(
-                            "🤖 Auto Mode: concurrency {} -> {} (avg part {:.1}s, throughput {:.1} MiB/s)",
-                            target_concurrency, next, metrics.avg_part_seconds, metrics.throughput_mib_s
-                        );
+                    if next != target_concurrency {
+                        self.emit(ProgressEvent::ConcurrencyChanged {
+                            destination: self.dest_uri(),
+                            from: target_concurrency,
+                            to: next,
+                            avg_part_seconds: metrics.avg_part_seconds,
+                            throughput_mib_s: metrics.throughput_mib_s,
+                        });
                     }
                     target_concurrency = next;
                 }
             }
 
-            if remaining_parts > 0 {
-                progress_bar.finish_with_message("All parts copied!");
-            }
-            if !self.quiet {
-                println!("\n✅ All parts copied successfully");
-            }
+            self.info("\n✅ All parts copied successfully");
             // Sort parts by part number
-            completed_parts.sort_by(|a, b| a.part_number.cmp(&b.part_number));
+            completed_parts.sort_by_key(|p| p.part_number);
+            let part_etags: Vec<&str> = completed_parts
+                .iter()
+                .map(|p| p.e_tag().unwrap_or_default())
+                .collect();
+            let expected_etag = multipart_etag(&part_etags);
+            let parts = completed_parts.len();
 
             // Complete multipart upload
-            if !self.quiet {
-                println!("\n📦 Completing multipart upload...");
-            }
+            self.info("\n📦 Completing multipart upload...");
             self.complete_multipart_upload(&upload_id, completed_parts)
                 .await?;
-            if !self.quiet {
-                println!("   ✅ Multipart upload completed successfully!");
-            }
+            self.emit(ProgressEvent::UploadCompleted {
+                destination: self.dest_uri(),
+                upload_id: upload_id.clone(),
+                parts,
+            });
 
-            Ok(())
+            Ok((expected_etag, parts))
         }
         .await;
 
         // Cleanup if error occurred during upload
-        if let Err(e) = upload_result {
-            eprintln!("\n⚠️  Error occurred during upload: {}. Cleaning up...", e);
-            if let Err(abort_err) = self.abort_multipart_upload(&upload_id).await {
-                eprintln!("   Failed to abort multipart upload: {}", abort_err);
-            }
-            return Err(e);
-        }
-
-        // Verify the copy
-        if !self.dry_run && self.verify_integrity != VerifyIntegrity::Off {
-            let source_metadata = self
-                .source_client
-                .head_object()
-                .bucket(&self.source_bucket)
-                .key(&self.source_key)
-                .send()
-                .await
-                .with_context(|| "Failed to load source metadata for verification")?;
-            let dest_metadata = self
-                .client
-                .head_object()
-                .bucket(&self.dest_bucket)
-                .key(&self.dest_key)
-                .send()
-                .await
-                .with_context(|| "Failed to verify destination object")?;
-
-            if dest_metadata.content_length != Some(content_length) {
-                return Err(anyhow::anyhow!(
-                    "Verification failed: source/destination size mismatch ({} != {})",
-                    content_length,
-                    dest_metadata.content_length.unwrap_or(0)
-                ));
-            }
-
-            match self.verify_integrity {
-                VerifyIntegrity::Off => {}
-                VerifyIntegrity::Etag => {
-                    let src_etag = source_metadata.e_tag().unwrap_or_default();
-                    let dst_etag = dest_metadata.e_tag().unwrap_or_default();
-                    if !src_etag.is_empty() && !dst_etag.is_empty() && src_etag != dst_etag {
-                        let tracked_src = dest_metadata
-                            .metadata()
-                            .and_then(|m| m.get("source-etag"))
-                            .map(|v| format!("\"{}\"", v.trim_matches('"')))
-                            .unwrap_or_default();
-                        let normalized_src = format!("\"{}\"", src_etag.trim_matches('"'));
-                        if tracked_src != normalized_src {
-                            return Err(anyhow::anyhow!(
-                                "Verification failed: ETag mismatch and source-etag metadata mismatch"
-                            ));
-                        }
-                    }
+        let (expected_etag, parts) = match upload_result {
+            Ok(uploaded) => uploaded,
+            Err(e) => {
+                let keep =
+                    journal.is_some() && keeps_failed_upload(&e, self.resume, self.on_interrupt);
+                if e.is::<Interrupted>() {
+                    self.emit(ProgressEvent::UploadInterrupted {
+                        destination: self.dest_uri(),
+                        upload_id: upload_id.clone(),
+                        copied_parts,
+                        copied_bytes,
+                        kept: keep,
+                    });
                 }
-                VerifyIntegrity::Checksum => {
-                    let provider = HeadObjectChecksumProvider;
-                    Self::verify_checksum_with_provider(
-                        &provider,
-                        &source_metadata,
-                        &dest_metadata,
-                    )?;
+                if let Some(journal) = &journal
+                    && keep
+                {
+                    self.emit(ProgressEvent::UploadKept {
+                        destination: self.dest_uri(),
+                        upload_id,
+                        journal: journal.path().display().to_string(),
+                        error: e.to_string(),
+                    });
+                    return Err(e);
+                }
+                let abort_error = match self.abort_multipart_upload(&upload_id).await {
+                    Ok(()) => {
+                        self.remove_upload_marker(&upload_id);
+                        None
+                    }
+                    Err(abort_err) => Some(abort_err.to_string()),
+                };
+                self.emit(ProgressEvent::UploadAborted {
+                    destination: self.dest_uri(),
+                    upload_id,
+                    error: e.to_string(),
+                    abort_error,
+                });
+                if let Some(journal) = &journal
+                    && let Err(remove_err) = journal.remove()
+                {
+                    self.warn(format!("Failed to remove resume journal: {}", remove_err));
                 }
+                return Err(e);
             }
+        };
 
-            if !self.quiet {
-                println!("\n✅ Copy verification successful!");
-                println!("   Source size:      {} bytes", content_length);
-                println!(
-                    "   Destination size: {} bytes",
-                    dest_metadata.content_length.unwrap_or(0)
-                );
-                println!("   Mode:             {:?}", self.verify_integrity);
-            }
-        } else if !self.quiet {
-            println!("\n[Dry Run/Config] Copy verification skipped.");
+        self.remove_upload_marker(&upload_id);
+        if let Some(journal) = &journal
+            && let Err(e) = journal.remove()
+        {
+            self.warn(format!("Failed to remove resume journal: {}", e));
         }
 
-        Ok(())
+        let verification = self
+            .verify_copy(content_length, expected_etag.as_deref())
+            .await?;
+
+        Ok(CopyOutcome {
+            action: CopyAction::Multipart,
+            size: content_length,
+            parts,
+            data_path: Some(self.data_route()),
+            verification,
+        })
     }
 }
 
@@ -1678,6 +4072,7 @@ impl S3CopyApp {
 mod tests {
     use super::*;
     use aws_sdk_s3::Config;
+    use aws_sdk_s3::config::Region;
     use mockall::Sequence;
 
     fn build_test_app(dry_run: bool) -> S3CopyApp {
@@ -1712,6 +4107,30 @@ mod tests {
             sse: None,
             sse_kms_key_id: None,
             filters: None,
+            resume: false,
+            journal_dir: PathBuf::from(DEFAULT_JOURNAL_DIR),
+            object_concurrency: 1,
+            part_slots: None,
+            delete_extraneous: false,
+            max_delete: None,
+            separate_identities: false,
+            data_path: DataPath::Auto,
+            streaming: Arc::new(AtomicBool::new(false)),
+            buffer_budget: Arc::new(Semaphore::new(64)),
+            buffer_budget_mb: 64,
+            events: None,
+            report: None,
+            source_version_id: None,
+            source_etag: None,
+            source_layout: None,
+            checksum_type: None,
+            listed_source: None,
+            on_source_change: OnSourceChange::Abort,
+            dest_guard: None,
+            match_source_parts: false,
+            throttle: Throttle::default(),
+            control: TransferControl::default(),
+            on_interrupt: OnInterrupt::Abort,
         }
     }
 
@@ -1728,6 +4147,17 @@ mod tests {
         assert_eq!(part.e_tag.as_deref(), Some("dry-run-etag"));
     }
 
+    /// Ensures every request sent through the app draws from the request-rate cap.
+    #[tokio::test]
+    async fn send_counts_against_request_rate_cap() {
+        let mut app = build_test_app(false);
+        app.throttle = Throttle::new(None, Some(100));
+        for _ in 0..101 {
+            app.send(0, async {}).await;
+        }
+        assert!(app.throttle.waited() > Duration::ZERO);
+    }
+
     /// Verifies dry-run multipart lifecycle methods succeed and return deterministic values.
     #[tokio::test]
     async fn multipart_lifecycle_dry_run_succeeds() {
@@ -1748,6 +4178,124 @@ mod tests {
             .expect("dry-run abort should succeed");
     }
 
+    /// Ensures AccessDenied only triggers the streaming fallback with separate credentials.
+    #[test]
+    fn streaming_fallback_requires_separate_identities() {
+        let service_error = |code: &str| {
+            let error = UploadPartCopyError::generic(
+                aws_sdk_s3::error::ErrorMetadata::builder()
+                    .code(code)
+                    .build(),
+            );
+            let response = HttpResponse::new(
+                403u16.try_into().unwrap(),
+                aws_smithy_types::body::SdkBody::empty(),
+            );
+            anyhow::Error::new(SdkError::service_error(error, response))
+                .context("Failed to upload part 1")
+        };
+        let mut app = build_test_app(true);
+        let denied = service_error("AccessDenied");
+        let throttled = service_error("SlowDown");
+        let mentions_denied = anyhow::anyhow!("Failed to copy reports/AccessDenied.csv");
+
+        assert!(!app.can_fall_back_to_streaming(&denied));
+        app.separate_identities = true;
+        assert!(app.can_fall_back_to_streaming(&denied));
+        assert!(!app.can_fall_back_to_streaming(&throttled));
+        assert!(!app.can_fall_back_to_streaming(&mentions_denied));
+    }
+
+    /// Ensures a quiet app narrates nothing but still reports warnings, and that the
+    /// streaming fallback is both a warning and the reported data route.
+    #[test]
+    fn quiet_apps_emit_warnings_but_no_narration() {
+        let received = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let seen = received.clone();
+        let mut app = build_test_app(true);
+        app.events = Some(EventSink::from_callback(Arc::new(
+            move |event: &ProgressEvent| seen.lock().unwrap().push(event.clone()),
+        )));
+
+        app.info("narration");
+        assert_eq!(app.data_route(), DataRoute::ServerSide);
+        app.activate_streaming("CopyObject");
+        assert_eq!(app.data_route(), DataRoute::Streaming);
+        app.quiet = false;
+        app.info("narration");
+
+        let received = received.lock().unwrap();
+        assert_eq!(received.len(), 2);
+        assert!(matches!(
+            &received[0],
+            ProgressEvent::Warning { message, .. } if message.starts_with("CopyObject was denied")
+        ));
+        assert!(matches!(
+            &received[1],
+            ProgressEvent::Info { destination, message }
+                if destination == "s3://dst-bucket/dst-key" && message == "narration"
+        ));
+    }
+
+    /// Verifies the streaming switch is shared by every clone of the app.
+    #[tokio::test]
+    async fn streaming_switch_is_shared_and_used_for_parts() {
+        let app = build_test_app(true);
+        let worker = app.with_keys("k2".to_string(), "d2".to_string(), true);
+        assert!(!worker.streaming_active());
+
+        app.activate_streaming("UploadPartCopy");
+        assert!(worker.streaming_active());
+
+        let part = worker
+            .copy_part("dry-upload", 3, "bytes=0-1023")
+            .await
+            .expect("dry-run streamed part should succeed");
+        assert_eq!(part.part_number, Some(3));
+    }
+
+    #[test]
+    fn range_len_parses_http_ranges() {
+        assert_eq!(range_len("bytes=0-1023"), 1024);
+        assert_eq!(range_len("bytes=100-100"), 1);
+        assert_eq!(range_len("garbage"), 0);
+    }
+
+    /// Ensures streamed parts never reserve more than the whole buffer budget.
+    #[tokio::test]
+    async fn buffer_reservation_is_capped_by_budget() {
+        let app = build_test_app(true);
+        let small = app.reserve_buffer(3 * 1024 * 1024 + 1).await.unwrap();
+        assert_eq!(small.num_permits(), 4);
+        drop(small);
+
+        let whole = app.reserve_buffer(64 * 1024 * 1024).await.unwrap();
+        assert_eq!(app.buffer_budget.available_permits(), 0);
+        drop(whole);
+
+        let huge = app
+            .reserve_buffer(5 * 1024 * 1024 * 1024)
+            .await
+            .unwrap_err();
+        assert!(huge.to_string().contains("--max-buffer-mb"));
+        assert_eq!(app.buffer_budget.available_permits(), 64);
+    }
+
+    /// Verifies part sizes are capped at the buffer budget only when parts may be streamed.
+    #[test]
+    fn part_size_fits_buffer_when_streaming() {
+        let mut app = build_test_app(true);
+        let five_gib = 5 * 1024 * 1024 * 1024;
+        assert_eq!(app.fit_part_size_to_buffer(five_gib), five_gib);
+
+        app.separate_identities = true;
+        assert_eq!(app.fit_part_size_to_buffer(five_gib), 64 * 1024 * 1024);
+        assert_eq!(
+            app.fit_part_size_to_buffer(8 * 1024 * 1024),
+            8 * 1024 * 1024
+        );
+    }
+
     /// Confirms checksum extraction prefers SHA256 over other checksum headers when available.
     #[test]
     fn extract_checksum_value_prefers_sha256() {
@@ -1852,6 +4400,154 @@ mod tests {
         assert_eq!(dest_key, "backup/2026/part-000.parquet");
     }
 
+    #[test]
+    fn plan_deletions_respects_mapping_and_filters() {
+        let normalized_source = normalize_prefix("raw");
+        let normalized_dest = normalize_prefix("backup");
+        let expected: HashSet<String> = ["backup/a.parquet".to_string()].into_iter().collect();
+        let dest_keys = vec![
+            "backup/a.parquet".to_string(),
+            "backup/stale.parquet".to_string(),
+            "backup/notes.txt".to_string(),
+        ];
+        let filter = KeyFilter::from_patterns(vec!["*.parquet".to_string()], Vec::new())
+            .unwrap()
+            .unwrap();
+
+        let filtered = plan_deletions(
+            &dest_keys,
+            &expected,
+            &normalized_source,
+            &normalized_dest,
+            false,
+            Some(&filter),
+        );
+        assert_eq!(filtered, vec!["backup/stale.parquet".to_string()]);
+
+        let unfiltered = plan_deletions(
+            &dest_keys,
+            &expected,
+            &normalized_source,
+            &normalized_dest,
+            false,
+            None,
+        );
+        assert_eq!(unfiltered.len(), 2);
+    }
+
+    /// Ensures a destination prefix that contains the source never plans source
+    /// objects for deletion, and that `--delete` is refused for such prefixes.
+    #[test]
+    fn plan_deletions_keeps_nested_source_objects() {
+        let normalized_source = normalize_prefix("data/in");
+        let normalized_dest = normalize_prefix("data");
+        let expected: HashSet<String> = ["data/a".to_string()].into_iter().collect();
+        let dest_keys = vec![
+            "data/a".to_string(),
+            "data/in/a".to_string(),
+            "data/stale".to_string(),
+        ];
+
+        let doomed = plan_deletions(
+            &dest_keys,
+            &expected,
+            &normalized_source,
+            &normalized_dest,
+            true,
+            None,
+        );
+        assert_eq!(doomed, vec!["data/stale".to_string()]);
+
+        assert!(check_delete_scope("b", "data/in/", "b", "data/").is_err());
+        assert!(check_delete_scope("b", "data/", "b", "data/out").is_err());
+        assert!(check_delete_scope("b", "", "b", "backup/").is_err());
+        assert!(check_delete_scope("b", "data/in/", "b", "data/out/").is_ok());
+        assert!(check_delete_scope("b", "data/in", "b", "data/inbox").is_ok());
+        assert!(check_delete_scope("a", "data/in/", "b", "data/").is_ok());
+    }
+
+    #[test]
+    fn plan_parts_covers_range_with_uneven_tail() {
+        let parts = plan_parts(3, 100, 1050, 400);
+        assert_eq!(parts.len(), 3);
+        assert_eq!(parts[0].part_number, 3);
+        assert_eq!(parts[0].start, 100);
+        assert_eq!(parts[2].end, 1049);
+        assert_eq!(parts[2].len(), 150);
+        assert_eq!(parts.iter().map(|p| p.len()).sum::<i64>(), 950);
+    }
+
+    /// Ensures a source layout is reproduced part for part, including odd sizes.
+    #[test]
+    fn plan_from_layout_reproduces_source_boundaries() {
+        let parts = plan_from_layout(&[400, 400, 150]);
+        assert_eq!(
+            parts
+                .iter()
+                .map(|p| (p.part_number, p.start, p.end))
+                .collect::<Vec<_>>(),
+            vec![(1, 0, 399), (2, 400, 799), (3, 800, 949)]
+        );
+        assert!(plan_from_layout(&[]).is_empty());
+    }
+
+    /// Ensures part checksums returned by S3 identify the algorithm and reach the completed part.
+    #[test]
+    fn part_checksums_carry_into_completed_parts() {
+        let result = aws_sdk_s3::types::CopyPartResult::builder()
+            .e_tag("\"abc\"")
+            .checksum_crc32_c("AAAAAA==")
+            .build();
+        let checksums = part_checksums!(&result);
+        assert_eq!(checksums.algorithm(), Some(ChecksumAlgorithm::Crc32C));
+
+        let part = checksums
+            .apply(CompletedPart::builder().part_number(1).e_tag("\"abc\""))
+            .build();
+        assert_eq!(part.checksum_crc32_c(), Some("AAAAAA=="));
+        assert_eq!(part.checksum_sha256(), None);
+        assert_eq!(PartChecksums::default().algorithm(), None);
+    }
+
+    /// Ensures only interrupts honour `--on-interrupt keep`; other errors keep the
+    /// upload only under `--resume`.
+    #[test]
+    fn failed_uploads_are_kept_per_resume_and_on_interrupt() {
+        let interrupted = || anyhow::Error::from(Interrupted);
+        let denied = || anyhow::anyhow!("AccessDenied");
+        let changed = || anyhow::Error::from(SourceChanged("etag".to_string()));
+
+        // --on-interrupt keep without --resume
+        assert!(keeps_failed_upload(
+            &interrupted(),
+            false,
+            OnInterrupt::Keep
+        ));
+        assert!(!keeps_failed_upload(&denied(), false, OnInterrupt::Keep));
+        assert!(!keeps_failed_upload(
+            &VerificationFailed("size".to_string()).into(),
+            false,
+            OnInterrupt::Keep
+        ));
+        // --resume with either interrupt policy
+        assert!(keeps_failed_upload(&denied(), true, OnInterrupt::Abort));
+        assert!(!keeps_failed_upload(
+            &interrupted(),
+            true,
+            OnInterrupt::Abort
+        ));
+        assert!(keeps_failed_upload(&interrupted(), true, OnInterrupt::Keep));
+        // neither
+        assert!(!keeps_failed_upload(&denied(), false, OnInterrupt::Abort));
+        assert!(!keeps_failed_upload(
+            &interrupted(),
+            false,
+            OnInterrupt::Abort
+        ));
+        // never worth keeping
+        assert!(!keeps_failed_upload(&changed(), true, OnInterrupt::Keep));
+    }
+
     #[test]
     fn retryable_error_classifier_handles_s3_pressure_signals() {
         let slowdown = anyhow::anyhow!("SlowDown: Please reduce your request rate.");
@@ -1877,6 +4573,194 @@ mod tests {
         assert!(big_pace.as_millis() > 0);
     }
 
+    #[test]
+    fn prefix_stats_aggregate_worker_results() {
+        let mut stats = PrefixStats::default();
+        stats.record(&ObjectResult {
+            source_key: "a".to_string(),
+            dest_key: "x/a".to_string(),
+            listed_size: 100,
+            size: 100,
+            retries: 2,
+            action: Some(CopyAction::Skipped),
+            duration: Duration::from_millis(20),
+            verification: Verification::Passed,
+            error: None,
+        });
+        stats.record(&ObjectResult {
+            source_key: "b".to_string(),
+            dest_key: "x/b".to_string(),
+            listed_size: 50,
+            size: 50,
+            retries: 0,
+            action: None,
+            duration: Duration::from_millis(5),
+            verification: Verification::NotRun,
+            error: Some("AccessDenied".to_string()),
+        });
+
+        assert_eq!(stats.processed, 2);
+        assert_eq!(stats.copied, 1);
+        assert_eq!(stats.skipped, 1);
+        assert_eq!(stats.failed, 1);
+        assert_eq!(stats.retried, 2);
+        assert_eq!(stats.bytes_copied, 100);
+        assert_eq!(stats.bytes_processed, 150);
+        assert_eq!(
+            stats.error_samples,
+            vec!["b -> x/b: AccessDenied".to_string()]
+        );
+        let listing = ListingProgress::default();
+        for size in [100, 50, 100, 50] {
+            listing.add_object(size);
+        }
+        let line = stats.progress_line(&listing, Duration::from_secs(1));
+        assert!(line.starts_with("files 2/4+ (50.0%)"));
+        assert!(line.ends_with("eta=n/a (listing)"));
+        assert!(line.contains("copied=0, skipped=1, failed=1"));
+
+        listing.mark_complete();
+        let line = stats.progress_line(&listing, Duration::from_secs(1));
+        assert!(line.starts_with("files 2/4 (50.0%), bytes 150 / 300"));
+    }
+
+    /// Confirms verification mismatches are reported distinctly from other failures.
+    #[test]
+    fn object_result_flags_verification_failures() {
+        let failed: Result<CopyOutcome> =
+            Err(VerificationFailed("ETag mismatch".to_string()).into());
+        let result =
+            ObjectResult::from_attempt("a".into(), "b".into(), 1, 0, Duration::ZERO, &failed);
+        assert_eq!(result.verification, Verification::Failed);
+        assert_eq!(
+            result.error.as_deref(),
+            Some("Verification failed: ETag mismatch")
+        );
+
+        let denied: Result<CopyOutcome> = Err(anyhow::anyhow!("AccessDenied"));
+        let result =
+            ObjectResult::from_attempt("a".into(), "b".into(), 1, 2, Duration::ZERO, &denied);
+        assert_eq!(result.verification, Verification::NotRun);
+        assert_eq!(result.retries, 2);
+        assert!(result.action.is_none());
+    }
+
+    /// Verifies the copy source is pinned to a version only when one is known, and only
+    /// for the source object itself.
+    #[test]
+    fn copy_source_pins_the_source_version() {
+        let mut app = build_test_app(true);
+        assert_eq!(app.copy_source(), "src-bucket/src-key");
+
+        app.source_version_id = Some("3HL4kqtJlcpXroDTDmJ".to_string());
+        assert_eq!(
+            app.copy_source(),
+            "src-bucket/src-key?versionId=3HL4kqtJlcpXroDTDmJ"
+        );
+        assert_eq!(
+            app.source_version_for("src-bucket", "src-key"),
+            app.source_version_id
+        );
+        assert_eq!(app.source_version_for("src-bucket", "other-key"), None);
+
+        app.source_bucket = "arn:aws:s3:us-west-2:123456789012:accesspoint/src-ap".to_string();
+        assert_eq!(
+            app.copy_source(),
+            "arn:aws:s3:us-west-2:123456789012:accesspoint/src-ap/object/src-key?versionId=3HL4kqtJlcpXroDTDmJ"
+        );
+    }
+
+    /// Ensures a rejected copy-source condition is recognized and surfaces as
+    /// `SourceChanged` even behind added context.
+    #[test]
+    fn precondition_failures_map_to_source_changed() {
+        use aws_sdk_s3::config::http::HttpResponse;
+        use aws_sdk_s3::error::ErrorMetadata;
+        use aws_sdk_s3::operation::upload_part_copy::UploadPartCopyError;
+        use aws_smithy_types::body::SdkBody;
+
+        let service_error = |code: &str, status: u16| {
+            SdkError::service_error(
+                UploadPartCopyError::generic(ErrorMetadata::builder().code(code).build()),
+                HttpResponse::new(status.try_into().unwrap(), SdkBody::empty()),
+            )
+        };
+        assert!(is_precondition_failed(&service_error(
+            "PreconditionFailed",
+            412
+        )));
+        assert!(!is_precondition_failed(&service_error("SlowDown", 503)));
+
+        let mut app = build_test_app(true);
+        app.source_etag = Some("\"abc\"".to_string());
+        let err = anyhow::Error::from(app.source_changed()).context("part 3 failed");
+        assert!(err.is::<SourceChanged>());
+        assert!(format!("{:#}", err).contains("s3://src-bucket/src-key no longer matches ETag"));
+    }
+
+    /// Verifies each destination guard maps to exactly one conditional header.
+    #[test]
+    fn destination_guards_map_to_conditional_headers() {
+        let mut app = build_test_app(true);
+        assert_eq!(app.dest_if_none_match(), None);
+        assert_eq!(app.dest_if_match(), None);
+
+        app.dest_guard = Some(DestinationGuard::NoClobber);
+        assert_eq!(app.dest_if_none_match().as_deref(), Some("*"));
+        assert_eq!(app.dest_if_match(), None);
+        assert!(
+            app.destination_exists()
+                .to_string()
+                .starts_with("Destination exists: s3://dst-bucket/dst-key")
+        );
+
+        app.dest_guard = Some(DestinationGuard::if_etag("abc123"));
+        assert_eq!(app.dest_if_none_match(), None);
+        assert_eq!(app.dest_if_match().as_deref(), Some("\"abc123\""));
+        assert_eq!(
+            DestinationGuard::if_etag("\"abc123\""),
+            DestinationGuard::if_etag("abc123")
+        );
+    }
+
+    /// Ensures buffered versions (listed newest first) are sent oldest first.
+    #[tokio::test]
+    async fn versions_are_sent_oldest_first() {
+        let version = |id: &str| ListedObject {
+            key: "k".to_string(),
+            size: 1,
+            dest_key: None,
+            version_id: Some(id.to_string()),
+            e_tag: None,
+            storage_class: None,
+        };
+        let mut pending = vec![version("v3"), version("v2"), version("v1")];
+        let (tx, mut rx) = mpsc::channel(4);
+
+        assert!(send_oldest_first(&mut pending, &tx).await);
+        assert!(pending.is_empty());
+        drop(tx);
+        let mut sent = Vec::new();
+        while let Some(item) = rx.recv().await {
+            sent.push(item.unwrap().version_id.unwrap());
+        }
+        assert_eq!(sent, vec!["v1", "v2", "v3"]);
+    }
+
+    /// Ensures prefix workers share the part-level budget instead of each getting their own.
+    #[tokio::test]
+    async fn part_slots_are_shared_between_clones() {
+        let mut app = build_test_app(true);
+        app.part_slots = Some(Arc::new(Semaphore::new(1)));
+        let other = app.with_keys("k2".to_string(), "d2".to_string(), true);
+
+        let held = app.acquire_part_slot().await.unwrap();
+        assert!(held.is_some());
+        assert_eq!(other.part_slots.as_ref().unwrap().available_permits(), 0);
+        drop(held);
+        assert_eq!(other.part_slots.as_ref().unwrap().available_permits(), 1);
+    }
+
     #[test]
     fn eta_is_none_without_meaningful_progress() {
         let elapsed = Duration::from_secs(10);
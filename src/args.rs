@@ -1,17 +1,54 @@
-use crate::auto::{AutoProfile, VerifyIntegrity};
-use clap::Parser;
+use anyhow::Result;
+use clap::builder::RangedU64ValueParser;
+use clap::{Parser, Subcommand};
+use s3_largecopy::app;
+use s3_largecopy::auto::{AutoProfile, DataPath, OnInterrupt, OnSourceChange, VerifyIntegrity};
+use s3_largecopy::cleanup::parse_age;
+use s3_largecopy::control::TransferWindow;
+use s3_largecopy::report::ReportFormat;
+use s3_largecopy::s3_utils::ClientConfig;
+use s3_largecopy::throttle::parse_bandwidth;
+use s3_largecopy::uri::{CopyScope, S3Uri};
 
-pub const MIN_PART_SIZE_MB: i64 = 5;
-pub const DEFAULT_PART_SIZE_MB: i64 = 256;
-pub const MAX_PART_SIZE_MB: i64 = 5 * 1024; // 5GB maximum in MB
-pub const DEFAULT_CONCURRENCY: usize = 50;
-pub const MAX_CONCURRENT_PARTS: usize = 1000;
+pub use s3_largecopy::request::{
+    DEFAULT_CONCURRENCY, DEFAULT_MAX_BUFFER_MB, DEFAULT_OBJECT_CONCURRENCY, DEFAULT_PART_SIZE_MB,
+    MAX_CONCURRENT_PARTS, MAX_OBJECT_CONCURRENCY, MAX_PART_SIZE_MB, MIN_PART_SIZE_MB,
+};
 
-/// CLI arguments for the S3 large file copy tool
+/// CLI arguments for the S3 large file copy tool.
+///
+/// Without a subcommand, positional `SOURCE DEST` URIs copy an object or a prefix.
+/// The bucket/key flags outside a subcommand are the deprecated flat interface, kept
+/// for one release as an alias of `copy`, `sync`, `estimate` and `price`.
 #[derive(Parser, Debug)]
 #[command(name = "s3_largecopy")]
 #[command(author, version = concat!(env!("CARGO_PKG_VERSION"), " (", env!("CARGO_PKG_AUTHORS"), ")"), about, long_about = None)]
+#[command(args_conflicts_with_subcommands = true, arg_required_else_help = true)]
+#[command(
+    after_help = "Positional SOURCE DEST URIs are also accepted by `copy` and `sync`. The bucket/key flags without a subcommand are deprecated; see `s3_largecopy <COMMAND> --help`."
+)]
 pub struct Args {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Source as s3://bucket/key, or s3://bucket/prefix/ to copy a prefix. Access point
+    /// ARNs and a trailing ?versionId=... are accepted
+    #[arg(
+        value_name = "SOURCE",
+        value_parser = S3Uri::parse,
+        requires = "dest",
+        conflicts_with_all = [
+            "source_bucket", "source_key", "source_version_id", "source_prefix",
+            "dest_bucket", "dest_key", "dest_prefix", "get_price",
+        ]
+    )]
+    pub source: Option<S3Uri>,
+
+    /// Destination as s3://bucket/key, or s3://bucket/prefix/ (an object copied into a
+    /// prefix keeps its file name)
+    #[arg(value_name = "DEST", value_parser = S3Uri::parse)]
+    pub dest: Option<S3Uri>,
+
     /// Source S3 bucket name
     #[arg(short, long)]
     pub source_bucket: Option<String>,
@@ -20,6 +57,10 @@ pub struct Args {
     #[arg(short = 'k', long)]
     pub source_key: Option<String>,
 
+    /// Copy this version of the source object instead of the current one (single object mode)
+    #[arg(long)]
+    pub source_version_id: Option<String>,
+
     /// Source object prefix (directory path to sync from)
     #[arg(long)]
     pub source_prefix: Option<String>,
@@ -36,33 +77,457 @@ pub struct Args {
     #[arg(long)]
     pub dest_prefix: Option<String>,
 
+    /// Number of objects copied in parallel in prefix mode (default: 1, max: 256)
+    #[arg(long, value_parser = object_concurrency_parser())]
+    pub object_concurrency: Option<usize>,
+
+    /// Only replace the destination object if it still has this ETag (If-Match);
+    /// single object mode
+    #[arg(long, value_name = "ETAG", conflicts_with = "no_clobber")]
+    pub if_dest_etag: Option<String>,
+
+    /// Estimate the cost of the copy operation without executing it
+    #[arg(long, default_value_t = false)]
+    pub estimate: bool,
+
+    /// Get price for a specified storage class and region
+    #[arg(long, default_value_t = false)]
+    pub get_price: bool,
+
+    /// Delete destination objects under --dest-prefix that have no source counterpart (sync mode)
+    #[arg(long, default_value_t = false)]
+    pub delete: bool,
+
+    /// Abort deletion if more than this many destination objects would be deleted
+    #[arg(long)]
+    pub max_delete: Option<usize>,
+
+    /// Include only keys that match these glob patterns (comma separated or repeat flag)
+    #[arg(long, value_delimiter = ',', num_args = 1..)]
+    pub include: Vec<String>,
+
+    /// Exclude keys that match these glob patterns (comma separated or repeat flag)
+    #[arg(long, value_delimiter = ',', num_args = 1..)]
+    pub exclude: Vec<String>,
+
+    /// Copy every version under the source prefix, oldest first, to rebuild the version
+    /// history in a versioned destination (delete markers are not replayed)
+    #[arg(long)]
+    pub all_versions: bool,
+
+    /// Enumerate the source prefix from an S3 Inventory report (s3://bucket/.../manifest.json)
+    /// instead of ListObjectsV2. CSV, ORC and Parquet inventories are supported
+    #[arg(long, value_name = "S3_URI")]
+    pub inventory_manifest: Option<String>,
+
+    /// Copy only the keys listed in a file (CSV or JSON lines of source key and optional
+    /// dest key, or a previous --report, of which only failed entries are retried)
+    #[arg(long, value_name = "FILE")]
+    pub from_manifest: Option<String>,
+
+    /// Run the copy tasks of a TOML job file (alias of `s3_largecopy job FILE`)
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = [
+            "source", "source_bucket", "source_key", "source_version_id", "source_prefix",
+            "dest_bucket", "dest_key", "dest_prefix", "object_concurrency", "if_dest_etag",
+            "get_price", "delete", "max_delete", "include", "exclude", "all_versions",
+            "inventory_manifest", "from_manifest",
+        ]
+    )]
+    pub job: Option<String>,
+
+    #[command(flatten)]
+    pub connection: ConnectionArgs,
+
+    #[command(flatten)]
+    pub object: ObjectArgs,
+
+    #[command(flatten)]
+    pub transfer: TransferArgs,
+
+    #[command(flatten)]
+    pub run: RunArgs,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Copy one object
+    Copy(CopyArgs),
+    /// Copy every object under a prefix, or the keys listed in a manifest, optionally
+    /// deleting destination objects the source no longer has
+    Sync(SyncArgs),
+    /// Estimate the cost of a copy or sync without running it
+    Estimate(EstimateArgs),
+    /// Show S3 pricing for a storage class and region
+    Price(PriceArgs),
+    /// Check an existing destination object against its source without copying
+    Verify(VerifyArgs),
+    /// Find incomplete multipart uploads left by failed or killed runs, report their
+    /// storage cost and abort the old ones
+    Cleanup(CleanupArgs),
+    /// Run the copy tasks of a TOML job file in one process, with shared clients and
+    /// one combined report
+    Job(JobArgs),
+}
+
+impl Command {
+    /// Subcommand name, as typed on the command line.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Command::Copy(_) => "copy",
+            Command::Sync(_) => "sync",
+            Command::Estimate(_) => "estimate",
+            Command::Price(_) => "price",
+            Command::Verify(_) => "verify",
+            Command::Cleanup(_) => "cleanup",
+            Command::Job(_) => "job",
+        }
+    }
+}
+
+#[derive(clap::Args, Debug)]
+pub struct CopyArgs {
+    /// Source object as s3://bucket/key, in place of --source-bucket/--source-key. Access
+    /// point ARNs and a trailing ?versionId=... are accepted
+    #[arg(
+        value_name = "SOURCE",
+        value_parser = S3Uri::parse,
+        requires = "dest",
+        conflicts_with_all = [
+            "source_bucket", "source_key", "source_version_id", "dest_bucket", "dest_key",
+        ]
+    )]
+    pub source: Option<S3Uri>,
+
+    /// Destination as s3://bucket/key, or s3://bucket/prefix/ to keep the source file name
+    #[arg(value_name = "DEST", value_parser = S3Uri::parse)]
+    pub dest: Option<S3Uri>,
+
+    /// Source S3 bucket name
+    #[arg(short, long, required_unless_present = "source")]
+    pub source_bucket: Option<String>,
+
+    /// Source object key
+    #[arg(short = 'k', long, required_unless_present = "source")]
+    pub source_key: Option<String>,
+
+    /// Copy this version of the source object instead of the current one
+    #[arg(long)]
+    pub source_version_id: Option<String>,
+
+    /// Destination S3 bucket name
+    #[arg(short = 'b', long, required_unless_present = "source")]
+    pub dest_bucket: Option<String>,
+
+    /// Destination object key
+    #[arg(short = 't', long, required_unless_present = "source")]
+    pub dest_key: Option<String>,
+
+    /// Only replace the destination object if it still has this ETag (If-Match)
+    #[arg(long, value_name = "ETAG", conflicts_with = "no_clobber")]
+    pub if_dest_etag: Option<String>,
+
+    #[command(flatten)]
+    pub connection: ConnectionArgs,
+
+    #[command(flatten)]
+    pub object: ObjectArgs,
+
+    #[command(flatten)]
+    pub transfer: TransferArgs,
+
+    #[command(flatten)]
+    pub run: RunArgs,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct SyncArgs {
+    /// Source prefix as s3://bucket/prefix/ (or s3://bucket), in place of
+    /// --source-bucket/--source-prefix
+    #[arg(
+        value_name = "SOURCE",
+        value_parser = S3Uri::parse,
+        requires = "dest",
+        conflicts_with_all = ["source_bucket", "source_prefix", "dest_bucket", "dest_prefix"]
+    )]
+    pub source: Option<S3Uri>,
+
+    /// Destination prefix as s3://bucket/prefix/ (or s3://bucket)
+    #[arg(value_name = "DEST", value_parser = S3Uri::parse)]
+    pub dest: Option<S3Uri>,
+
+    /// Source S3 bucket name
+    #[arg(short, long, required_unless_present = "source")]
+    pub source_bucket: Option<String>,
+
+    /// Source object prefix (directory path to sync from)
+    #[arg(long, required_unless_present_any = ["from_manifest", "source"])]
+    pub source_prefix: Option<String>,
+
+    /// Destination S3 bucket name
+    #[arg(short = 'b', long, required_unless_present = "source")]
+    pub dest_bucket: Option<String>,
+
+    /// Destination prefix (directory path to sync into)
+    #[arg(long, required_unless_present_any = ["from_manifest", "source"])]
+    pub dest_prefix: Option<String>,
+
+    /// Number of objects copied in parallel (default: 1, max: 256)
+    #[arg(long, value_parser = object_concurrency_parser())]
+    pub object_concurrency: Option<usize>,
+
+    /// Delete destination objects under --dest-prefix that have no source counterpart
+    #[arg(long, default_value_t = false, conflicts_with_all = ["from_manifest", "inventory_manifest"])]
+    pub delete: bool,
+
+    /// Abort deletion if more than this many destination objects would be deleted
+    #[arg(long, requires = "delete")]
+    pub max_delete: Option<usize>,
+
+    /// Include only keys that match these glob patterns (comma separated or repeat flag)
+    #[arg(long, value_delimiter = ',', num_args = 1..)]
+    pub include: Vec<String>,
+
+    /// Exclude keys that match these glob patterns (comma separated or repeat flag)
+    #[arg(long, value_delimiter = ',', num_args = 1..)]
+    pub exclude: Vec<String>,
+
+    /// Copy every version under the source prefix, oldest first, to rebuild the version
+    /// history in a versioned destination (delete markers are not replayed)
+    #[arg(
+        long,
+        conflicts_with_all = ["from_manifest", "inventory_manifest", "delete", "no_clobber"]
+    )]
+    pub all_versions: bool,
+
+    /// Enumerate the source prefix from an S3 Inventory report (s3://bucket/.../manifest.json)
+    /// instead of ListObjectsV2. CSV, ORC and Parquet inventories are supported
+    #[arg(long, value_name = "S3_URI", conflicts_with = "from_manifest")]
+    pub inventory_manifest: Option<String>,
+
+    /// Copy only the keys listed in a file (CSV or JSON lines of source key and optional
+    /// dest key, or a previous --report, of which only failed entries are retried)
+    #[arg(long, value_name = "FILE")]
+    pub from_manifest: Option<String>,
+
+    #[command(flatten)]
+    pub connection: ConnectionArgs,
+
+    #[command(flatten)]
+    pub object: ObjectArgs,
+
+    #[command(flatten)]
+    pub transfer: TransferArgs,
+
+    #[command(flatten)]
+    pub run: RunArgs,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct EstimateArgs {
+    /// Source S3 bucket name
+    #[arg(short, long)]
+    pub source_bucket: String,
+
+    /// Source object key
+    #[arg(
+        short = 'k',
+        long,
+        required_unless_present = "source_prefix",
+        conflicts_with = "source_prefix"
+    )]
+    pub source_key: Option<String>,
+
+    /// Source object prefix; estimates the copy of every object under it
+    #[arg(long)]
+    pub source_prefix: Option<String>,
+
+    /// Destination S3 bucket name
+    #[arg(short = 'b', long)]
+    pub dest_bucket: String,
+
+    /// Destination object key
+    #[arg(short = 't', long, conflicts_with = "source_prefix")]
+    pub dest_key: Option<String>,
+
+    /// Destination prefix
+    #[arg(long, requires = "source_prefix")]
+    pub dest_prefix: Option<String>,
+
+    /// Include only keys that match these glob patterns (comma separated or repeat flag)
+    #[arg(long, value_delimiter = ',', num_args = 1.., requires = "source_prefix")]
+    pub include: Vec<String>,
+
+    /// Exclude keys that match these glob patterns (comma separated or repeat flag)
+    #[arg(long, value_delimiter = ',', num_args = 1.., requires = "source_prefix")]
+    pub exclude: Vec<String>,
+
+    /// Estimate a sync listed from an S3 Inventory report instead of ListObjectsV2
+    #[arg(long, value_name = "S3_URI", requires = "source_prefix")]
+    pub inventory_manifest: Option<String>,
+
+    #[command(flatten)]
+    pub connection: ConnectionArgs,
+
+    #[command(flatten)]
+    pub object: ObjectArgs,
+
+    #[command(flatten)]
+    pub transfer: TransferArgs,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct PriceArgs {
+    /// AWS region to price
+    #[arg(short = 'r', long, default_value = "us-east-1")]
+    pub region: String,
+
+    /// Storage class to price
+    #[arg(long, default_value = "STANDARD")]
+    pub storage_class: String,
+
+    /// Destination region, to include inter-region transfer pricing
+    #[arg(long)]
+    pub dest_region: Option<String>,
+
+    /// AWS profile to use for credentials
+    #[arg(long)]
+    pub profile: Option<String>,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct VerifyArgs {
+    /// Source S3 bucket name
+    #[arg(short, long)]
+    pub source_bucket: String,
+
+    /// Source object key
+    #[arg(short = 'k', long)]
+    pub source_key: String,
+
+    /// Compare against this version of the source object instead of the current one
+    #[arg(long)]
+    pub source_version_id: Option<String>,
+
+    /// Destination S3 bucket name
+    #[arg(short = 'b', long)]
+    pub dest_bucket: String,
+
+    /// Destination object key
+    #[arg(short = 't', long)]
+    pub dest_key: String,
+
+    /// Comparison to run (etag, checksum, deep)
+    #[arg(long, value_enum, default_value_t = VerifyIntegrity::Etag)]
+    pub mode: VerifyIntegrity,
+
+    /// Number of concurrent ranged reads in deep mode (default: 50)
+    #[arg(long, value_parser = concurrency_parser())]
+    pub concurrency: Option<usize>,
+
+    /// Digest used in deep mode (CRC32, CRC32C, SHA1, SHA256)
+    #[arg(long, value_parser = ["CRC32", "CRC32C", "SHA1", "SHA256"])]
+    pub checksum_algorithm: Option<String>,
+
+    /// Only report failures
+    #[arg(short, long, default_value_t = false)]
+    pub quiet: bool,
+
+    #[command(flatten)]
+    pub connection: ConnectionArgs,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct JobArgs {
+    /// TOML job file: a [defaults] table and one [[task]] table per copy, each with a
+    /// source and dest URI and any destination object or transfer settings
+    #[arg(value_name = "FILE")]
+    pub file: String,
+
+    /// Print the cost of every task and the combined total without copying
+    #[arg(long, default_value_t = false)]
+    pub estimate: bool,
+
+    #[command(flatten)]
+    pub connection: ConnectionArgs,
+
+    /// Defaults for the settings a task leaves unset
+    #[command(flatten)]
+    pub object: ObjectArgs,
+
+    #[command(flatten)]
+    pub transfer: TransferArgs,
+
+    #[command(flatten)]
+    pub run: RunArgs,
+}
+
+/// Regions, credentials and endpoints of the source and destination buckets.
+#[derive(clap::Args, Clone, Debug, Default)]
+#[command(next_help_heading = "Connection")]
+pub struct ConnectionArgs {
     /// AWS region (optional, uses default region if not specified)
     #[arg(short = 'r', long)]
     pub region: Option<String>,
 
-    /// Part size in MB (default: 256, min: 5, max: 5120)
-    #[arg(short = 'p', long, value_parser = clap::value_parser!(i64).range(5..=5120))]
-    pub part_size: Option<i64>,
+    /// Destination region (for cross-region cost estimation; defaults to --region)
+    #[arg(long)]
+    pub dest_region: Option<String>,
 
-    /// Number of concurrent part uploads (default: 50)
+    /// AWS profile to use for credentials
     #[arg(long)]
-    pub concurrency: Option<usize>,
+    pub profile: Option<String>,
+
+    /// AWS profile for the source bucket (defaults to --profile)
+    #[arg(long)]
+    pub source_profile: Option<String>,
+
+    /// AWS profile for the destination bucket (defaults to --profile)
+    #[arg(long)]
+    pub dest_profile: Option<String>,
+
+    /// IAM role ARN to assume for reading the source bucket
+    #[arg(long)]
+    pub source_role_arn: Option<String>,
+
+    /// IAM role ARN to assume for writing the destination bucket
+    #[arg(long)]
+    pub dest_role_arn: Option<String>,
 
+    /// Custom endpoint URL for the source bucket (e.g. MinIO, Ceph)
+    #[arg(long)]
+    pub source_endpoint_url: Option<String>,
+
+    /// Custom endpoint URL for the destination bucket (e.g. MinIO, Ceph)
+    #[arg(long)]
+    pub dest_endpoint_url: Option<String>,
+
+    /// Use path-style addressing (https://host/bucket/key), required by most S3-compatible stores
+    #[arg(long, default_value_t = false)]
+    pub force_path_style: bool,
+}
+
+/// Properties of the destination object.
+#[derive(clap::Args, Debug, Default)]
+#[command(next_help_heading = "Destination object")]
+pub struct ObjectArgs {
     /// Target storage class (e.g. STANDARD, INTELLIGENT_TIERING, GLACIER_IR)
     #[arg(long)]
     pub storage_class: Option<String>,
 
+    /// Do not inherit storage class from source (use destination default unless --storage-class is provided)
+    #[arg(long, default_value_t = false)]
+    pub no_storage_class: bool,
+
     /// Set bucket-owner-full-control ACL (useful for cross-account copies)
     #[arg(long, default_value_t = false)]
     pub full_control: bool,
 
-    /// Automatically tune part size and concurrency based on object size
+    /// Disable applying bucket-owner-full-control ACL
     #[arg(long, default_value_t = false)]
-    pub auto: bool,
-
-    /// Auto mode profile (balanced, aggressive, conservative, cost-efficient)
-    #[arg(long, value_enum)]
-    pub auto_profile: Option<AutoProfile>,
+    pub no_acl: bool,
 
     /// Disable replication of standard and custom metadata
     #[arg(long, default_value_t = false)]
@@ -72,14 +537,84 @@ pub struct Args {
     #[arg(long, default_value_t = false)]
     pub no_tags: bool,
 
-    /// Do not inherit storage class from source (use destination default unless --storage-class is provided)
+    /// Checksum algorithm to use (CRC32, CRC32C, SHA1, SHA256)
+    #[arg(long, value_parser = ["CRC32", "CRC32C", "SHA1", "SHA256"])]
+    pub checksum_algorithm: Option<String>,
+
+    /// Server-side encryption algorithm (AES256, aws:kms)
+    #[arg(long, value_parser = ["AES256", "aws:kms"])]
+    pub sse: Option<String>,
+
+    /// KMS Key ID (ARN or Alias) to use with aws:kms encryption
+    #[arg(long)]
+    pub sse_kms_key_id: Option<String>,
+
+    /// Copy with the same part boundaries as the source upload so the destination ETag
+    /// matches the source's (layout read with GetObjectAttributes or per-part HeadObject)
     #[arg(long, default_value_t = false)]
-    pub no_storage_class: bool,
+    pub match_source_parts: bool,
+}
 
-    /// Disable applying bucket-owner-full-control ACL
+/// How parts are copied, checked and rate limited.
+#[derive(clap::Args, Debug, Default)]
+#[command(next_help_heading = "Transfer")]
+pub struct TransferArgs {
+    /// Part size in MB (default: 256, min: 5, max: 5120)
+    #[arg(short = 'p', long, value_parser = clap::value_parser!(i64).range(MIN_PART_SIZE_MB..=MAX_PART_SIZE_MB))]
+    pub part_size: Option<i64>,
+
+    /// Number of concurrent part uploads (default: 50, max: 1000)
+    #[arg(long, value_parser = concurrency_parser())]
+    pub concurrency: Option<usize>,
+
+    /// Automatically tune part size and concurrency based on object size
     #[arg(long, default_value_t = false)]
-    pub no_acl: bool,
+    pub auto: bool,
+
+    /// Auto mode profile (balanced, aggressive, conservative, cost-efficient)
+    #[arg(long, value_enum)]
+    pub auto_profile: Option<AutoProfile>,
+
+    /// Force full copy and overwrite destination even if object already matches
+    #[arg(long, default_value_t = false)]
+    pub force_copy: bool,
+
+    /// Never overwrite an existing destination object: existing keys are skipped and the
+    /// final write is conditional (If-None-Match), so a concurrent writer always wins
+    #[arg(long, default_value_t = false, conflicts_with = "force_copy")]
+    pub no_clobber: bool,
+
+    /// Post-copy integrity verification mode (off, etag, checksum, deep)
+    #[arg(long, value_enum)]
+    pub verify_integrity: Option<VerifyIntegrity>,
 
+    /// What to do when the source changes mid-copy (every source read is conditional on
+    /// the ETag seen at the start): abort the object, or restart it from the new source
+    #[arg(long, value_enum)]
+    pub on_source_change: Option<OnSourceChange>,
+
+    /// Data path (auto, server-side, streaming). Auto streams through this process when
+    /// source and destination endpoints or AWS partitions differ
+    #[arg(long, value_enum)]
+    pub data_path: Option<DataPath>,
+
+    /// Memory budget in MiB for part buffers on the streaming data path (default: 2048)
+    #[arg(long)]
+    pub max_buffer_mb: Option<usize>,
+
+    /// Cap on bytes copied per second across all parts and objects (e.g. 500MiB/s, 1GB/s)
+    #[arg(long, value_name = "RATE", value_parser = parse_bandwidth)]
+    pub max_bandwidth: Option<u64>,
+
+    /// Cap on S3 data requests per second (HeadObject, CopyObject, part copies and reads)
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
+    pub max_requests_per_second: Option<u32>,
+}
+
+/// Output, journaling and scheduling of a run.
+#[derive(clap::Args, Debug, Default)]
+#[command(next_help_heading = "Run")]
+pub struct RunArgs {
     /// Suppress informational output and progress bars
     #[arg(short, long, default_value_t = false)]
     pub quiet: bool,
@@ -88,47 +623,630 @@ pub struct Args {
     #[arg(long, default_value_t = false)]
     pub dry_run: bool,
 
-    /// Force full copy and overwrite destination even if object already matches
+    /// Keep a local journal of completed parts and resume an interrupted multipart copy
     #[arg(long, default_value_t = false)]
-    pub force_copy: bool,
+    pub resume: bool,
 
-    /// Post-copy integrity verification mode (off, etag, checksum)
+    /// Directory for upload markers and resume journals (default: .s3_largecopy)
+    #[arg(long)]
+    pub journal_dir: Option<String>,
+
+    /// What to do with an unfinished multipart upload on Ctrl-C/SIGTERM: abort it, or
+    /// keep it with its resume journal for a later --resume
     #[arg(long, value_enum)]
-    pub verify_integrity: Option<VerifyIntegrity>,
+    pub on_interrupt: Option<OnInterrupt>,
 
-    /// Checksum algorithm to use (CRC32, CRC32C, SHA1, SHA256)
-    #[arg(long, value_parser = ["CRC32", "CRC32C", "SHA1", "SHA256"])]
-    pub checksum_algorithm: Option<String>,
+    /// Only start new parts and objects inside this daily local-time window
+    /// (e.g. 22:00-06:00); in-progress multipart uploads resume when it reopens
+    #[arg(long, value_name = "HH:MM-HH:MM", value_parser = TransferWindow::parse)]
+    pub window: Option<TransferWindow>,
 
-    /// Server-side encryption algorithm (AES256, aws:kms)
-    #[arg(long, value_parser = ["AES256", "aws:kms"])]
-    pub sse: Option<String>,
+    /// Write NDJSON progress events to a file, an existing unix socket, or `-` for stdout
+    /// (stdout replaces the human-readable output)
+    #[arg(long, value_name = "PATH|SOCKET|-")]
+    pub progress_sink: Option<String>,
 
-    /// KMS Key ID (ARN or Alias) to use with aws:kms encryption
+    /// Write a per-object run report (action, bytes, duration, retries, verification, error)
+    #[arg(long, value_name = "FILE")]
+    pub report: Option<String>,
+
+    /// Report format (json, csv). Defaults to csv for `.csv` files, json otherwise
+    #[arg(long, value_enum)]
+    pub report_format: Option<ReportFormat>,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct CleanupArgs {
+    /// Bucket to scan for incomplete multipart uploads
+    #[arg(short = 'b', long)]
+    pub bucket: String,
+
+    /// Only consider uploads for keys under this prefix
     #[arg(long)]
-    pub sse_kms_key_id: Option<String>,
+    pub prefix: Option<String>,
 
-    /// Estimate the cost of the copy operation without executing it
+    /// Abort uploads initiated at least this long ago (e.g. 36h, 7d); without it,
+    /// uploads are only reported
+    #[arg(long, value_name = "AGE", value_parser = parse_age)]
+    pub older_than: Option<std::time::Duration>,
+
+    /// Also include uploads no upload marker or resume journal attributes to this tool
     #[arg(long, default_value_t = false)]
-    pub estimate: bool,
+    pub all: bool,
 
-    /// Destination region (for cross-region cost estimation; defaults to --region)
+    /// Directory holding upload markers and resume journals (default: .s3_largecopy)
     #[arg(long)]
-    pub dest_region: Option<String>,
+    pub journal_dir: Option<String>,
 
-    /// Get price for a specified storage class and region
+    /// Report what would be aborted without aborting anything
     #[arg(long, default_value_t = false)]
-    pub get_price: bool,
-
-    /// Include only keys that match these glob patterns (comma separated or repeat flag)
-    #[arg(long, value_delimiter = ',', num_args = 1..)]
-    pub include: Vec<String>,
+    pub dry_run: bool,
 
-    /// Exclude keys that match these glob patterns (comma separated or repeat flag)
-    #[arg(long, value_delimiter = ',', num_args = 1..)]
-    pub exclude: Vec<String>,
+    /// AWS region of the bucket (detected if not specified)
+    #[arg(short = 'r', long)]
+    pub region: Option<String>,
 
     /// AWS profile to use for credentials
     #[arg(long)]
     pub profile: Option<String>,
+
+    /// Custom endpoint URL (e.g. MinIO, Ceph)
+    #[arg(long)]
+    pub endpoint_url: Option<String>,
+
+    /// Use path-style addressing, required by most S3-compatible stores
+    #[arg(long, default_value_t = false)]
+    pub force_path_style: bool,
+}
+
+impl CleanupArgs {
+    pub fn client_config(&self, region: Option<String>) -> ClientConfig {
+        ClientConfig {
+            region,
+            profile: self.profile.clone(),
+            endpoint_url: self.endpoint_url.clone(),
+            force_path_style: self.force_path_style,
+            role_arn: None,
+        }
+    }
+}
+
+impl ConnectionArgs {
+    /// Client settings for the source side of the copy.
+    pub fn source_client_config(&self, region: Option<String>) -> ClientConfig {
+        ClientConfig {
+            region,
+            profile: self.source_profile.clone().or_else(|| self.profile.clone()),
+            endpoint_url: self.source_endpoint_url.clone(),
+            force_path_style: self.force_path_style,
+            role_arn: self.source_role_arn.clone(),
+        }
+    }
+
+    /// Client settings for the destination side of the copy.
+    pub fn dest_client_config(&self, region: Option<String>) -> ClientConfig {
+        ClientConfig {
+            region,
+            profile: self.dest_profile.clone().or_else(|| self.profile.clone()),
+            endpoint_url: self.dest_endpoint_url.clone(),
+            force_path_style: self.force_path_style,
+            role_arn: self.dest_role_arn.clone(),
+        }
+    }
+}
+
+impl CopyArgs {
+    /// Fill the bucket and key flags from positional URIs.
+    pub fn resolve_uris(&mut self) -> Result<()> {
+        let (Some(source), Some(dest)) = (self.source.take(), self.dest.take()) else {
+            return Ok(());
+        };
+        match CopyScope::between(&source, &dest)? {
+            CopyScope::Object {
+                source_key,
+                source_version_id,
+                dest_key,
+            } => {
+                self.source_key = Some(source_key);
+                self.source_version_id = source_version_id;
+                self.dest_key = Some(dest_key);
+            }
+            CopyScope::Prefix { .. } => {
+                anyhow::bail!(
+                    "{} is a prefix; use `s3_largecopy sync` to copy prefixes",
+                    source
+                );
+            }
+        }
+        self.source_bucket = Some(source.bucket);
+        self.dest_bucket = Some(dest.bucket);
+        Ok(())
+    }
+}
+
+impl SyncArgs {
+    /// Fill the bucket and prefix flags from positional URIs, then run the checks clap
+    /// cannot express on its own.
+    pub fn validate(&mut self) -> Result<()> {
+        if let (Some(source), Some(dest)) = (self.source.take(), self.dest.take()) {
+            match CopyScope::between(&source, &dest)? {
+                CopyScope::Prefix {
+                    source_prefix,
+                    dest_prefix,
+                } => {
+                    self.source_prefix = Some(source_prefix);
+                    self.dest_prefix = Some(dest_prefix);
+                }
+                CopyScope::Object { .. } => {
+                    anyhow::bail!(
+                        "{} names one object; end it with '/' to sync a prefix, or use `s3_largecopy copy`",
+                        source
+                    );
+                }
+            }
+            self.source_bucket = Some(source.bucket);
+            self.dest_bucket = Some(dest.bucket);
+        }
+
+        if self.delete {
+            app::check_delete_scope(
+                self.source_bucket.as_deref().unwrap_or_default(),
+                self.source_prefix.as_deref().unwrap_or_default(),
+                self.dest_bucket.as_deref().unwrap_or_default(),
+                self.dest_prefix.as_deref().unwrap_or_default(),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl RunArgs {
+    /// Whether human-readable output is suppressed; NDJSON on stdout replaces it.
+    pub fn is_quiet(&self) -> bool {
+        self.quiet || self.progress_sink.as_deref() == Some("-")
+    }
+}
+
+fn concurrency_parser() -> RangedU64ValueParser<usize> {
+    RangedU64ValueParser::new().range(1..=MAX_CONCURRENT_PARTS as u64)
+}
+
+fn object_concurrency_parser() -> RangedU64ValueParser<usize> {
+    RangedU64ValueParser::new().range(1..=MAX_OBJECT_CONCURRENCY as u64)
+}
+
+impl Args {
+    /// Map the deprecated flat flags onto the subcommand they stand for. clap cannot
+    /// validate flag combinations of the flat interface, so they are checked here.
+    pub fn into_command(mut self) -> Result<Command> {
+        if let (Some(source), Some(dest)) = (self.source.take(), self.dest.take()) {
+            self.set_locations(source, dest)?;
+        }
+
+        if let Some(file) = self.job {
+            return Ok(Command::Job(JobArgs {
+                file,
+                estimate: self.estimate,
+                connection: self.connection,
+                object: self.object,
+                transfer: self.transfer,
+                run: self.run,
+            }));
+        }
+
+        if self.get_price {
+            return Ok(Command::Price(PriceArgs {
+                region: self
+                    .connection
+                    .region
+                    .unwrap_or_else(|| "us-east-1".to_string()),
+                storage_class: self
+                    .object
+                    .storage_class
+                    .unwrap_or_else(|| "STANDARD".to_string()),
+                dest_region: self.connection.dest_region,
+                profile: self.connection.profile,
+            }));
+        }
+
+        let prefix_mode = self.source_prefix.is_some() || self.dest_prefix.is_some();
+        let source_bucket = self
+            .source_bucket
+            .ok_or_else(|| anyhow::anyhow!("--source-bucket is required"))?;
+        let dest_bucket = self
+            .dest_bucket
+            .ok_or_else(|| anyhow::anyhow!("--dest-bucket is required"))?;
+
+        if prefix_mode && (self.source_prefix.is_none() || self.dest_prefix.is_none()) {
+            anyhow::bail!("Both --source-prefix and --dest-prefix are required for directory mode");
+        }
+
+        let manifest_mode = self.from_manifest.is_some();
+        if manifest_mode && (self.source_key.is_some() || self.dest_key.is_some()) {
+            anyhow::bail!("--from-manifest cannot be combined with --source-key/--dest-key");
+        }
+
+        if !prefix_mode && !manifest_mode && (self.source_key.is_none() || self.dest_key.is_none())
+        {
+            anyhow::bail!(
+                "--source-key and --dest-key are required for single object copy. Use --source-prefix/--dest-prefix for directory mode."
+            );
+        }
+
+        if self.delete && (!prefix_mode || manifest_mode) {
+            anyhow::bail!(
+                "--delete requires --source-prefix/--dest-prefix (directory mode) without --from-manifest"
+            );
+        }
+
+        if self.delete {
+            app::check_delete_scope(
+                &source_bucket,
+                self.source_prefix.as_deref().unwrap_or_default(),
+                &dest_bucket,
+                self.dest_prefix.as_deref().unwrap_or_default(),
+            )?;
+        }
+
+        if self.inventory_manifest.is_some() {
+            if !prefix_mode || manifest_mode {
+                anyhow::bail!(
+                    "--inventory-manifest requires --source-prefix/--dest-prefix (directory mode)"
+                );
+            }
+            // An inventory is a snapshot; deleting against it could remove newer copies.
+            if self.delete {
+                anyhow::bail!("--delete cannot be used with --inventory-manifest");
+            }
+        }
+
+        if self.source_version_id.is_some() && (prefix_mode || manifest_mode) {
+            anyhow::bail!("--source-version-id is only valid for single object copies");
+        }
+
+        if self.if_dest_etag.is_some() && (prefix_mode || manifest_mode) {
+            anyhow::bail!("--if-dest-etag is only valid for single object copies");
+        }
+
+        if self.all_versions {
+            if !prefix_mode || manifest_mode || self.inventory_manifest.is_some() {
+                anyhow::bail!(
+                    "--all-versions requires --source-prefix/--dest-prefix (directory mode) without --from-manifest or --inventory-manifest"
+                );
+            }
+            if self.delete || self.transfer.no_clobber {
+                anyhow::bail!("--delete and --no-clobber cannot be used with --all-versions");
+            }
+        }
+
+        if self.estimate {
+            if manifest_mode {
+                anyhow::bail!("--estimate cannot be combined with --from-manifest");
+            }
+            return Ok(Command::Estimate(EstimateArgs {
+                source_bucket,
+                source_key: self.source_key,
+                source_prefix: self.source_prefix,
+                dest_bucket,
+                dest_key: self.dest_key,
+                dest_prefix: self.dest_prefix,
+                include: self.include,
+                exclude: self.exclude,
+                inventory_manifest: self.inventory_manifest,
+                connection: self.connection,
+                object: self.object,
+                transfer: self.transfer,
+            }));
+        }
+
+        if prefix_mode || manifest_mode {
+            return Ok(Command::Sync(SyncArgs {
+                source: None,
+                dest: None,
+                source_bucket: Some(source_bucket),
+                source_prefix: self.source_prefix,
+                dest_bucket: Some(dest_bucket),
+                dest_prefix: self.dest_prefix,
+                object_concurrency: self.object_concurrency,
+                delete: self.delete,
+                max_delete: self.max_delete,
+                include: self.include,
+                exclude: self.exclude,
+                all_versions: self.all_versions,
+                inventory_manifest: self.inventory_manifest,
+                from_manifest: self.from_manifest,
+                connection: self.connection,
+                object: self.object,
+                transfer: self.transfer,
+                run: self.run,
+            }));
+        }
+
+        Ok(Command::Copy(CopyArgs {
+            source: None,
+            dest: None,
+            source_bucket: Some(source_bucket),
+            source_key: self.source_key,
+            source_version_id: self.source_version_id,
+            dest_bucket: Some(dest_bucket),
+            dest_key: self.dest_key,
+            if_dest_etag: self.if_dest_etag,
+            connection: self.connection,
+            object: self.object,
+            transfer: self.transfer,
+            run: self.run,
+        }))
+    }
+}
+
+impl Args {
+    /// Fill the bucket, key and prefix flags from positional URIs.
+    fn set_locations(&mut self, source: S3Uri, dest: S3Uri) -> Result<()> {
+        match CopyScope::between(&source, &dest)? {
+            CopyScope::Object {
+                source_key,
+                source_version_id,
+                dest_key,
+            } => {
+                self.source_key = Some(source_key);
+                self.source_version_id = source_version_id;
+                self.dest_key = Some(dest_key);
+            }
+            CopyScope::Prefix {
+                source_prefix,
+                dest_prefix,
+            } => {
+                self.source_prefix = Some(source_prefix);
+                self.dest_prefix = Some(dest_prefix);
+            }
+        }
+        self.source_bucket = Some(source.bucket);
+        self.dest_bucket = Some(dest.bucket);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Args, clap::Error> {
+        Args::try_parse_from(std::iter::once("s3_largecopy").chain(args.iter().copied()))
+    }
+
+    /// Verifies each subcommand requires its own arguments and shares the flag groups.
+    #[test]
+    fn subcommands_validate_their_arguments() {
+        let args = parse(&[
+            "copy", "-s", "src", "-k", "a.bin", "-b", "dst", "-t", "b.bin", "--auto", "-q",
+        ])
+        .unwrap();
+        let Some(Command::Copy(copy)) = args.command else {
+            panic!("expected copy");
+        };
+        assert_eq!(copy.dest_key.as_deref(), Some("b.bin"));
+        assert!(copy.transfer.auto && copy.run.quiet);
+
+        assert!(parse(&["copy", "-s", "src", "-b", "dst", "-t", "b.bin"]).is_err());
+        assert!(parse(&["sync", "-s", "src", "-b", "dst", "--source-prefix", "a/"]).is_err());
+        assert!(
+            parse(&[
+                "sync",
+                "-s",
+                "src",
+                "-b",
+                "dst",
+                "--from-manifest",
+                "run.json"
+            ])
+            .is_ok()
+        );
+        assert!(
+            parse(&[
+                "sync",
+                "-s",
+                "src",
+                "-b",
+                "dst",
+                "--from-manifest",
+                "run.json",
+                "--delete",
+            ])
+            .is_err()
+        );
+        assert!(
+            parse(&[
+                "sync",
+                "-s",
+                "src",
+                "--source-prefix",
+                "a/",
+                "-b",
+                "dst",
+                "--dest-prefix",
+                "a/",
+                "--all-versions",
+                "--no-clobber",
+            ])
+            .is_err()
+        );
+        assert!(parse(&["estimate", "-s", "src", "-b", "dst"]).is_err());
+        assert!(
+            parse(&[
+                "copy",
+                "-s",
+                "s",
+                "-k",
+                "k",
+                "-b",
+                "d",
+                "-t",
+                "k",
+                "--concurrency",
+                "0"
+            ])
+            .is_err()
+        );
+        // The flat flags cannot be mixed with a subcommand.
+        assert!(parse(&["--dry-run", "price"]).is_err());
+    }
+
+    /// Ensures the deprecated flat flags map onto the matching subcommand.
+    #[test]
+    fn flat_flags_map_to_subcommands() {
+        let command = |args: &[&str]| parse(args).unwrap().into_command();
+
+        let copy = command(&["-s", "src", "-k", "a", "-b", "dst", "-t", "b", "--dry-run"]).unwrap();
+        assert!(
+            matches!(&copy, Command::Copy(c) if c.source_key.as_deref() == Some("a") && c.run.dry_run)
+        );
+
+        let sync = command(&[
+            "-s",
+            "src",
+            "--source-prefix",
+            "a/",
+            "-b",
+            "dst",
+            "--dest-prefix",
+            "b/",
+            "--delete",
+        ])
+        .unwrap();
+        assert!(matches!(&sync, Command::Sync(s) if s.delete));
+
+        let estimate = command(&["-s", "src", "-k", "a", "-b", "dst", "-t", "a", "--estimate"]);
+        assert_eq!(estimate.unwrap().name(), "estimate");
+
+        let price = command(&["--get-price", "--region", "eu-west-1"]).unwrap();
+        assert!(
+            matches!(&price, Command::Price(p) if p.region == "eu-west-1" && p.storage_class == "STANDARD")
+        );
+
+        assert!(command(&["-s", "src", "-b", "dst", "-k", "a"]).is_err());
+        assert!(command(&["-s", "src", "-b", "dst", "--source-prefix", "a/"]).is_err());
+        assert!(command(&["-s", "src", "-k", "a", "-b", "dst", "-t", "b", "--delete"]).is_err());
+        assert!(command(&["s3://b/data/in/", "s3://b/data/", "--delete"]).is_err());
+    }
+
+    /// Verifies positional URIs pick copy or sync from trailing slashes.
+    #[test]
+    fn positional_uris_infer_copy_or_sync() {
+        let command = |args: &[&str]| parse(args).unwrap().into_command();
+
+        let copy = command(&[
+            "s3://src/path/data.iso?versionId=v1",
+            "s3://dst/backup/",
+            "--auto",
+        ])
+        .unwrap();
+        let Command::Copy(copy) = copy else {
+            panic!("expected copy");
+        };
+        assert_eq!(
+            (copy.source_bucket.as_deref(), copy.source_key.as_deref()),
+            (Some("src"), Some("path/data.iso"))
+        );
+        assert_eq!(copy.source_version_id.as_deref(), Some("v1"));
+        assert_eq!(
+            (copy.dest_bucket.as_deref(), copy.dest_key.as_deref()),
+            (Some("dst"), Some("backup/data.iso"))
+        );
+        assert!(copy.transfer.auto);
+
+        let renamed = command(&["s3://src/a.bin", "s3://dst/b.bin"]).unwrap();
+        assert!(matches!(&renamed, Command::Copy(c) if c.dest_key.as_deref() == Some("b.bin")));
+
+        let sync = command(&["s3://src/logs/", "s3://dst", "--delete"]).unwrap();
+        let Command::Sync(sync) = sync else {
+            panic!("expected sync");
+        };
+        assert_eq!(sync.source_prefix.as_deref(), Some("logs/"));
+        assert_eq!(sync.dest_prefix.as_deref(), Some(""));
+        assert!(sync.delete);
+
+        let estimate = command(&["s3://src/logs/", "s3://dst/logs/", "--estimate"]).unwrap();
+        assert_eq!(estimate.name(), "estimate");
+
+        assert!(command(&["s3://src/logs/", "s3://dst/file"]).is_err());
+        assert!(command(&["s3://src/a.bin", "s3://dst/b.bin?versionId=v1"]).is_err());
+        assert!(parse(&["s3://src/a.bin"]).is_err());
+        assert!(parse(&["s3://src/a.bin", "s3://dst/", "-k", "other"]).is_err());
+    }
+
+    /// Ensures `copy` and `sync` take positional URIs in place of the location flags.
+    #[test]
+    fn subcommands_accept_positional_uris() {
+        let Some(Command::Copy(mut copy)) = parse(&["copy", "s3://src/a.bin", "s3://dst/in/"])
+            .unwrap()
+            .command
+        else {
+            panic!("expected copy");
+        };
+        copy.resolve_uris().unwrap();
+        assert_eq!(copy.source_bucket.as_deref(), Some("src"));
+        assert_eq!(copy.dest_key.as_deref(), Some("in/a.bin"));
+
+        let Some(Command::Sync(mut sync)) =
+            parse(&["sync", "s3://src/logs/", "s3://dst/logs/", "--delete"])
+                .unwrap()
+                .command
+        else {
+            panic!("expected sync");
+        };
+        sync.validate().unwrap();
+        assert_eq!(sync.dest_bucket.as_deref(), Some("dst"));
+        assert_eq!(sync.source_prefix.as_deref(), Some("logs/"));
+
+        let Some(Command::Copy(mut prefix)) = parse(&["copy", "s3://src/logs/", "s3://dst/"])
+            .unwrap()
+            .command
+        else {
+            panic!("expected copy");
+        };
+        assert!(prefix.resolve_uris().is_err());
+        let Some(Command::Sync(mut nested)) =
+            parse(&["sync", "s3://b/data/in/", "s3://b/data/", "--delete"])
+                .unwrap()
+                .command
+        else {
+            panic!("expected sync");
+        };
+        assert!(nested.validate().is_err());
+        assert!(parse(&["copy", "s3://src/a.bin"]).is_err());
+        assert!(parse(&["copy", "s3://src/a.bin", "s3://dst/", "-k", "other"]).is_err());
+        assert!(parse(&["sync", "s3://src/logs/", "s3://dst/", "-s", "src"]).is_err());
+    }
+
+    /// Verifies `job FILE` and its `--job FILE` alias, which excludes the location flags.
+    #[test]
+    fn job_file_runs_as_job_command() {
+        let args = parse(&[
+            "job",
+            "migration.toml",
+            "--estimate",
+            "--max-bandwidth",
+            "1G",
+        ])
+        .unwrap();
+        let Some(Command::Job(job)) = args.command else {
+            panic!("expected job");
+        };
+        assert_eq!(job.file, "migration.toml");
+        assert!(job.estimate);
+        assert!(job.transfer.max_bandwidth.is_some());
+
+        let alias = parse(&["--job", "migration.toml", "--storage-class", "GLACIER_IR"])
+            .unwrap()
+            .into_command()
+            .unwrap();
+        let Command::Job(job) = alias else {
+            panic!("expected job");
+        };
+        assert_eq!(job.object.storage_class.as_deref(), Some("GLACIER_IR"));
+
+        assert!(parse(&["job"]).is_err());
+        assert!(parse(&["--job", "migration.toml", "-s", "src"]).is_err());
+        assert!(parse(&["--job", "migration.toml", "s3://src/a", "s3://dst/"]).is_err());
+    }
 }
@@ -15,6 +15,40 @@ pub enum VerifyIntegrity {
     #[default]
     Etag,
     Checksum,
+    /// Re-read both objects and compare CRC32C/SHA-256 digests computed locally
+    Deep,
+}
+
+/// How object data moves from source to destination.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum DataPath {
+    /// Server-side copy unless endpoints/partitions differ or copies are denied across identities
+    #[default]
+    Auto,
+    /// Always UploadPartCopy/CopyObject
+    ServerSide,
+    /// Always ranged GetObject -> UploadPart through this process
+    Streaming,
+}
+
+/// What to do when the source object changes while it is being copied.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum OnSourceChange {
+    /// Abort the upload and fail the object
+    #[default]
+    Abort,
+    /// Abort the upload and copy the new source from scratch
+    Restart,
+}
+
+/// What to do with an unfinished multipart upload on SIGINT/SIGTERM.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum OnInterrupt {
+    /// Abort the upload so no incomplete parts are left billed
+    #[default]
+    Abort,
+    /// Keep the upload and its resume journal for a later --resume
+    Keep,
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -30,6 +64,8 @@ pub struct WindowMetrics {
     pub avg_part_seconds: f64,
     pub throughput_mib_s: f64,
     pub had_retryable_pressure: bool,
+    /// Parts waited on `--max-bandwidth`/`--max-requests-per-second` during the window
+    pub at_rate_cap: bool,
 }
 
 const MIB: i64 = 1024 * 1024;
@@ -205,6 +241,11 @@ pub fn adapt_concurrency(
         return current.saturating_sub(step).max(min_concurrency);
     }
 
+    // More parts in flight cannot go faster than the cap; they would only queue.
+    if metrics.at_rate_cap {
+        return current;
+    }
+
     if metrics.avg_part_seconds < 8.0 && metrics.throughput_mib_s > 0.0 {
         return (current + step).min(max_concurrency);
     }
@@ -288,6 +329,7 @@ mod tests {
                 avg_part_seconds: 6.0,
                 throughput_mib_s: 400.0,
                 had_retryable_pressure: false,
+                at_rate_cap: false,
             },
         );
         assert!(up > 20);
@@ -301,11 +343,30 @@ mod tests {
                 avg_part_seconds: 30.0,
                 throughput_mib_s: 100.0,
                 had_retryable_pressure: false,
+                at_rate_cap: false,
             },
         );
         assert!(down < 20);
     }
 
+    /// Ensures a window throttled by the rate caps never scales concurrency up.
+    #[test]
+    fn adaptive_concurrency_holds_at_rate_cap() {
+        let held = adapt_concurrency(
+            AutoProfile::Aggressive,
+            20,
+            4,
+            64,
+            WindowMetrics {
+                avg_part_seconds: 2.0,
+                throughput_mib_s: 500.0,
+                had_retryable_pressure: false,
+                at_rate_cap: true,
+            },
+        );
+        assert_eq!(held, 20);
+    }
+
     /// Validates that cost optimization increases part size for very large cross-region copies.
     #[test]
     fn cost_optimization_raises_part_size_for_large_cross_region_copy() {
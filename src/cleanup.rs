@@ -0,0 +1,309 @@
+use crate::estimate::monthly_storage_cost;
+use crate::journal::tracked_uploads;
+use anyhow::{Context, Result};
+use aws_sdk_s3::Client;
+use aws_sdk_s3::error::ProvideErrorMetadata;
+use aws_smithy_types::DateTime;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+/// Settings of the `cleanup` subcommand.
+pub struct CleanupOptions {
+    pub bucket: String,
+    pub prefix: Option<String>,
+    /// Abort uploads initiated at least this long ago; report only when `None`
+    pub older_than: Option<Duration>,
+    /// Include uploads that no upload marker or resume journal attributes to this tool
+    pub include_all: bool,
+    pub journal_dir: PathBuf,
+    pub dry_run: bool,
+}
+
+/// What the janitor does with one incomplete upload.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CleanupAction {
+    Abort,
+    /// Younger than `--older-than`
+    TooRecent,
+    /// No `--older-than` given
+    Report,
+}
+
+fn cleanup_action(age: Duration, older_than: Option<Duration>) -> CleanupAction {
+    match older_than {
+        Some(threshold) if age >= threshold => CleanupAction::Abort,
+        Some(_) => CleanupAction::TooRecent,
+        None => CleanupAction::Report,
+    }
+}
+
+/// Incomplete multipart upload found in the bucket.
+#[derive(Clone, Debug)]
+pub struct PendingUpload {
+    pub key: String,
+    pub upload_id: String,
+    pub age: Duration,
+    pub storage_class: String,
+    pub parts: usize,
+    pub bytes: i64,
+    pub monthly_cost: f64,
+    pub action: CleanupAction,
+    /// Upload marker and resume journal, removed once the upload is aborted
+    pub files: Vec<PathBuf>,
+}
+
+/// What `run_cleanup` found and aborted.
+#[derive(Clone, Debug, Default)]
+pub struct CleanupReport {
+    /// Uploads attributed to this tool, or every upload with `include_all`
+    pub uploads: Vec<PendingUpload>,
+    /// Upload IDs with an upload marker or resume journal for the bucket
+    pub tracked: usize,
+    /// Uploads left out because nothing attributes them to this tool
+    pub foreign: usize,
+    pub part_bytes: i64,
+    pub monthly_cost: f64,
+    pub aborted: usize,
+    pub aborted_cost: f64,
+    /// Upload ID and error of every failed abort
+    pub failures: Vec<(String, String)>,
+    /// Upload markers and journals that could not be removed after an abort
+    pub stale_files: Vec<(PathBuf, String)>,
+}
+
+/// Parse an age such as `90m`, `36h`, `7d` or `2w`.
+pub fn parse_age(value: &str) -> Result<Duration, String> {
+    let trimmed = value.trim();
+    let split = trimmed
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(trimmed.len());
+    let (number, unit) = trimmed.split_at(split);
+    let number: u64 = number
+        .parse()
+        .map_err(|_| format!("invalid age '{}', expected e.g. 36h or 7d", value))?;
+    let seconds = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        "w" => 7 * 24 * 60 * 60,
+        _ => {
+            return Err(format!(
+                "invalid age unit in '{}', expected one of s, m, h, d, w",
+                value
+            ));
+        }
+    };
+    Ok(Duration::from_secs(number * seconds))
+}
+
+pub fn format_age(age: Duration) -> String {
+    let hours = age.as_secs() / 3600;
+    if hours >= 48 {
+        format!("{}d", hours / 24)
+    } else {
+        format!("{}h{:02}m", hours, age.as_secs() / 60 % 60)
+    }
+}
+
+/// List the incomplete multipart uploads in a bucket/prefix, report their part bytes
+/// and storage cost, and abort those older than `--older-than`. Failed aborts are
+/// listed in the report rather than returned as an error.
+///
+/// S3 does not expose an upload's metadata (such as the `source-etag` this tool sets)
+/// before it completes, so uploads are attributed to this tool through the upload
+/// markers and resume journals in `journal_dir`; `include_all` covers uploads started
+/// elsewhere.
+pub async fn run_cleanup(
+    client: &Client,
+    region: &str,
+    options: &CleanupOptions,
+) -> Result<CleanupReport> {
+    let mut tracked: HashMap<String, Vec<PathBuf>> = HashMap::new();
+    for upload in tracked_uploads(&options.journal_dir)? {
+        if upload.dest_bucket == options.bucket {
+            tracked
+                .entry(upload.upload_id)
+                .or_default()
+                .push(upload.path);
+        }
+    }
+
+    let mut report = CleanupReport {
+        tracked: tracked.len(),
+        ..CleanupReport::default()
+    };
+    let now = DateTime::from(SystemTime::now());
+    let mut key_marker: Option<String> = None;
+    let mut upload_id_marker: Option<String> = None;
+    loop {
+        let response = client
+            .list_multipart_uploads()
+            .bucket(&options.bucket)
+            .set_prefix(options.prefix.clone())
+            .set_key_marker(key_marker.take())
+            .set_upload_id_marker(upload_id_marker.take())
+            .send()
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to list multipart uploads in s3://{}",
+                    options.bucket
+                )
+            })?;
+
+        for upload in response.uploads() {
+            let (Some(key), Some(upload_id)) = (upload.key(), upload.upload_id()) else {
+                continue;
+            };
+            let files = tracked.get(upload_id).cloned().unwrap_or_default();
+            if files.is_empty() && !options.include_all {
+                report.foreign += 1;
+                continue;
+            }
+            let age = upload
+                .initiated()
+                .map(|initiated| (now.secs() - initiated.secs()).max(0) as u64)
+                .unwrap_or_default();
+            let Some((parts, bytes)) =
+                list_part_bytes(client, &options.bucket, key, upload_id).await?
+            else {
+                continue;
+            };
+            let age = Duration::from_secs(age);
+            let storage_class = upload
+                .storage_class()
+                .map(|c| c.as_str().to_string())
+                .unwrap_or_else(|| "STANDARD".to_string());
+            report.uploads.push(PendingUpload {
+                key: key.to_string(),
+                upload_id: upload_id.to_string(),
+                age,
+                monthly_cost: monthly_storage_cost(region, &storage_class, bytes),
+                storage_class,
+                parts,
+                bytes,
+                action: cleanup_action(age, options.older_than),
+                files,
+            });
+        }
+
+        if response.is_truncated().unwrap_or(false) {
+            key_marker = response.next_key_marker().map(str::to_string);
+            upload_id_marker = response.next_upload_id_marker().map(str::to_string);
+            if key_marker.is_none() && upload_id_marker.is_none() {
+                break;
+            }
+        } else {
+            break;
+        }
+    }
+
+    for upload in &report.uploads {
+        report.part_bytes += upload.bytes;
+        report.monthly_cost += upload.monthly_cost;
+        if upload.action != CleanupAction::Abort || options.dry_run {
+            continue;
+        }
+        match client
+            .abort_multipart_upload()
+            .bucket(&options.bucket)
+            .key(&upload.key)
+            .upload_id(&upload.upload_id)
+            .send()
+            .await
+        {
+            Ok(_) => {
+                report.aborted += 1;
+                report.aborted_cost += upload.monthly_cost;
+                for path in &upload.files {
+                    if let Err(e) = std::fs::remove_file(path)
+                        && e.kind() != std::io::ErrorKind::NotFound
+                    {
+                        report.stale_files.push((path.clone(), e.to_string()));
+                    }
+                }
+            }
+            Err(e) => {
+                let e = e.into_service_error();
+                report
+                    .failures
+                    .push((upload.upload_id.clone(), e.to_string()));
+            }
+        }
+    }
+    Ok(report)
+}
+
+/// Number of parts and bytes uploaded so far; `None` if the upload is already gone.
+async fn list_part_bytes(
+    client: &Client,
+    bucket: &str,
+    key: &str,
+    upload_id: &str,
+) -> Result<Option<(usize, i64)>> {
+    let mut parts = 0usize;
+    let mut bytes = 0i64;
+    let mut marker: Option<String> = None;
+    loop {
+        let response = match client
+            .list_parts()
+            .bucket(bucket)
+            .key(key)
+            .upload_id(upload_id)
+            .set_part_number_marker(marker.take())
+            .send()
+            .await
+        {
+            Ok(r) => r,
+            Err(e) if e.code() == Some("NoSuchUpload") => return Ok(None),
+            Err(e) => {
+                return Err(anyhow::anyhow!(e.into_service_error())
+                    .context(format!("Failed to list parts of upload {}", upload_id)));
+            }
+        };
+        parts += response.parts().len();
+        bytes += response
+            .parts()
+            .iter()
+            .filter_map(|p| p.size())
+            .sum::<i64>();
+        if response.is_truncated().unwrap_or(false) {
+            marker = response.next_part_number_marker().map(str::to_string);
+            if marker.is_none() {
+                break;
+            }
+        } else {
+            break;
+        }
+    }
+    Ok(Some((parts, bytes)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verifies ages accept every unit and reject missing or unknown ones.
+    #[test]
+    fn parses_ages() {
+        assert_eq!(parse_age("90m"), Ok(Duration::from_secs(90 * 60)));
+        assert_eq!(parse_age("36h"), Ok(Duration::from_secs(36 * 3600)));
+        assert_eq!(parse_age("7d"), Ok(Duration::from_secs(7 * 86400)));
+        assert_eq!(parse_age("2w"), Ok(Duration::from_secs(14 * 86400)));
+        assert!(parse_age("7").is_err());
+        assert!(parse_age("7y").is_err());
+        assert!(parse_age("d").is_err());
+    }
+
+    /// Ensures only uploads at least `--older-than` old are aborted, and none without it.
+    #[test]
+    fn aborts_only_old_enough_uploads() {
+        let day = Duration::from_secs(86400);
+        assert_eq!(cleanup_action(2 * day, Some(day)), CleanupAction::Abort);
+        assert_eq!(cleanup_action(day, Some(day)), CleanupAction::Abort);
+        assert_eq!(cleanup_action(day / 2, Some(day)), CleanupAction::TooRecent);
+        assert_eq!(cleanup_action(30 * day, None), CleanupAction::Report);
+    }
+}
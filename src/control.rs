@@ -0,0 +1,321 @@
+use crate::events::ProgressEvent;
+use chrono::{Local, Timelike};
+use std::fmt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tokio::sync::watch;
+
+const SECONDS_PER_DAY: u32 = 24 * 60 * 60;
+/// Upper bound on one sleep while outside the window, so clock changes are noticed.
+const WINDOW_POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Daily transfer window in local time, such as `22:00-06:00`. A window whose end is
+/// before its start runs across midnight; equal bounds mean the whole day.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TransferWindow {
+    start: u32,
+    end: u32,
+}
+
+impl TransferWindow {
+    /// Parse `HH:MM-HH:MM`.
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let (start, end) = value
+            .split_once('-')
+            .ok_or_else(|| format!("invalid window '{}', expected HH:MM-HH:MM", value))?;
+        Ok(Self {
+            start: parse_time_of_day(start.trim())?,
+            end: parse_time_of_day(end.trim())?,
+        })
+    }
+
+    fn contains(&self, second_of_day: u32) -> bool {
+        if self.start == self.end {
+            true
+        } else if self.start < self.end {
+            (self.start..self.end).contains(&second_of_day)
+        } else {
+            second_of_day >= self.start || second_of_day < self.end
+        }
+    }
+
+    /// Seconds until the window next opens; zero inside the window.
+    fn seconds_until_open(&self, second_of_day: u32) -> u32 {
+        if self.contains(second_of_day) {
+            0
+        } else {
+            (self.start + SECONDS_PER_DAY - second_of_day) % SECONDS_PER_DAY
+        }
+    }
+}
+
+impl fmt::Display for TransferWindow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:02}:{:02}-{:02}:{:02}",
+            self.start / 3600,
+            self.start / 60 % 60,
+            self.end / 3600,
+            self.end / 60 % 60
+        )
+    }
+}
+
+fn parse_time_of_day(value: &str) -> Result<u32, String> {
+    let invalid = || format!("invalid time '{}', expected HH:MM", value);
+    let (hours, minutes) = value.split_once(':').ok_or_else(invalid)?;
+    let hours: u32 = hours.parse().map_err(|_| invalid())?;
+    let minutes: u32 = minutes.parse().map_err(|_| invalid())?;
+    if hours > 23 || minutes > 59 {
+        return Err(invalid());
+    }
+    Ok(hours * 3600 + minutes * 60)
+}
+
+fn local_second_of_day() -> u32 {
+    Local::now().num_seconds_from_midnight()
+}
+
+/// Returned to launches refused after SIGINT/SIGTERM.
+#[derive(Debug)]
+pub struct Interrupted;
+
+impl fmt::Display for Interrupted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Interrupted by signal")
+    }
+}
+
+impl std::error::Error for Interrupted {}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+struct ControlState {
+    paused: bool,
+    interrupted: bool,
+}
+
+/// Decides when new parts and objects may start: outside the `--window` schedule, or
+/// while paused with SIGUSR1, launches wait (in-flight requests finish) and the
+/// multipart upload is kept open until the copy can continue. After SIGINT/SIGTERM
+/// every launch fails with `Interrupted`.
+#[derive(Clone)]
+pub struct TransferControl {
+    window: Option<TransferWindow>,
+    state: Arc<watch::Sender<ControlState>>,
+    waiting_for_window: Arc<AtomicBool>,
+}
+
+impl Default for TransferControl {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl TransferControl {
+    pub fn new(window: Option<TransferWindow>) -> Self {
+        Self {
+            window,
+            state: Arc::new(watch::Sender::new(ControlState::default())),
+            waiting_for_window: Arc::default(),
+        }
+    }
+
+    /// Stop new launches; `false` if already paused.
+    pub fn pause(&self) -> bool {
+        self.state
+            .send_if_modified(|state| !std::mem::replace(&mut state.paused, true))
+    }
+
+    /// Allow launches again; `false` if not paused.
+    pub fn resume(&self) -> bool {
+        self.state
+            .send_if_modified(|state| std::mem::replace(&mut state.paused, false))
+    }
+
+    /// Refuse every further launch; `false` if already interrupted.
+    pub fn interrupt(&self) -> bool {
+        self.state
+            .send_if_modified(|state| !std::mem::replace(&mut state.interrupted, true))
+    }
+
+    pub fn is_interrupted(&self) -> bool {
+        self.state.borrow().interrupted
+    }
+
+    /// Pause on SIGUSR1, resume on SIGUSR2 (unix only) and interrupt on SIGINT/SIGTERM.
+    /// A second SIGINT exits immediately. `notify` receives a notice for each signal.
+    pub fn listen_for_signals(&self, notify: impl Fn(&str) + Send + 'static) -> anyhow::Result<()> {
+        #[cfg(unix)]
+        {
+            use tokio::signal::unix::{SignalKind, signal};
+
+            let mut pause = signal(SignalKind::user_defined1())?;
+            let mut resume = signal(SignalKind::user_defined2())?;
+            let mut interrupt = signal(SignalKind::interrupt())?;
+            let mut terminate = signal(SignalKind::terminate())?;
+            let control = self.clone();
+            tokio::spawn(async move {
+                loop {
+                    tokio::select! {
+                        Some(()) = pause.recv() => {
+                            if control.pause() {
+                                notify("⏸️  Paused (SIGUSR1): in-flight requests finish, no new parts start. Send SIGUSR2 to resume.");
+                            }
+                        }
+                        Some(()) = resume.recv() => {
+                            if control.resume() {
+                                notify("▶️  Resumed (SIGUSR2).");
+                            }
+                        }
+                        Some(()) = interrupt.recv() => control.on_interrupt_signal("SIGINT", &notify),
+                        Some(()) = terminate.recv() => control.on_interrupt_signal("SIGTERM", &notify),
+                        else => break,
+                    }
+                }
+            });
+        }
+        #[cfg(not(unix))]
+        {
+            let control = self.clone();
+            tokio::spawn(async move {
+                while tokio::signal::ctrl_c().await.is_ok() {
+                    control.on_interrupt_signal("Ctrl-C", &notify);
+                }
+            });
+        }
+        Ok(())
+    }
+
+    fn on_interrupt_signal(&self, signal: &str, notify: &impl Fn(&str)) {
+        if self.interrupt() {
+            notify(&format!(
+                "\n🛑 {} received: waiting for in-flight parts to finish (send it again to exit immediately)...",
+                signal
+            ));
+        } else {
+            notify(&format!("\n🛑 {} received again: exiting now.", signal));
+            std::process::exit(130);
+        }
+    }
+
+    /// Wait until the copy is neither paused nor outside the transfer window.
+    /// Fails with `Interrupted` once the copy has been interrupted. `on_event` receives
+    /// the transfer window waits and openings, once across all clones.
+    pub async fn wait_until_runnable(
+        &self,
+        on_event: impl Fn(ProgressEvent),
+    ) -> anyhow::Result<()> {
+        let mut state = self.state.subscribe();
+        loop {
+            let current = *state.borrow_and_update();
+            if current.interrupted {
+                return Err(Interrupted.into());
+            }
+            if current.paused {
+                let _ = state
+                    .wait_for(|state| !state.paused || state.interrupted)
+                    .await;
+                continue;
+            }
+            let Some(window) = self.window else {
+                return Ok(());
+            };
+            let until_open = window.seconds_until_open(local_second_of_day());
+            if until_open == 0 {
+                if self.waiting_for_window.swap(false, Ordering::SeqCst) {
+                    on_event(ProgressEvent::TransferWindowOpened {
+                        window: window.to_string(),
+                    });
+                }
+                return Ok(());
+            }
+            if !self.waiting_for_window.swap(true, Ordering::SeqCst) {
+                on_event(ProgressEvent::TransferWindowWaiting {
+                    window: window.to_string(),
+                    wait_seconds: until_open,
+                });
+            }
+            let poll = Duration::from_secs(until_open as u64).min(WINDOW_POLL_INTERVAL);
+            tokio::select! {
+                _ = tokio::time::sleep(poll) => {}
+                _ = state.changed() => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verifies windows that cross midnight and the wait until the next opening.
+    #[test]
+    fn overnight_window_contains_and_waits() {
+        let window = TransferWindow::parse("22:00-06:00").unwrap();
+        assert_eq!(window.to_string(), "22:00-06:00");
+        assert!(window.contains(23 * 3600));
+        assert!(window.contains(5 * 3600 + 59 * 60));
+        assert!(!window.contains(6 * 3600));
+        assert_eq!(window.seconds_until_open(23 * 3600), 0);
+        assert_eq!(window.seconds_until_open(21 * 3600 + 30 * 60), 30 * 60);
+        assert_eq!(window.seconds_until_open(6 * 3600), 16 * 3600);
+
+        let daytime = TransferWindow::parse("09:30-17:00").unwrap();
+        assert!(daytime.contains(12 * 3600));
+        assert_eq!(daytime.seconds_until_open(18 * 3600), 15 * 3600 + 30 * 60);
+        assert!(TransferWindow::parse("00:00-00:00").unwrap().contains(0));
+    }
+
+    /// Ensures malformed windows are rejected.
+    #[test]
+    fn rejects_invalid_windows() {
+        assert!(TransferWindow::parse("22:00").is_err());
+        assert!(TransferWindow::parse("24:00-06:00").is_err());
+        assert!(TransferWindow::parse("22:60-06:00").is_err());
+        assert!(TransferWindow::parse("ten-six").is_err());
+    }
+
+    /// Ensures a paused control blocks until resumed.
+    #[tokio::test]
+    async fn pause_blocks_until_resume() {
+        let control = TransferControl::default();
+        control.pause();
+        let waiter = tokio::spawn({
+            let control = control.clone();
+            async move { control.wait_until_runnable(|_| {}).await }
+        });
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(!waiter.is_finished());
+        control.resume();
+        tokio::time::timeout(Duration::from_secs(1), waiter)
+            .await
+            .expect("resume releases the waiter")
+            .unwrap()
+            .unwrap();
+    }
+
+    /// Ensures an interrupt releases paused waiters with `Interrupted` and refuses new launches.
+    #[tokio::test]
+    async fn interrupt_fails_waiting_and_new_launches() {
+        let control = TransferControl::default();
+        assert!(control.pause());
+        assert!(!control.pause());
+        let waiter = tokio::spawn({
+            let control = control.clone();
+            async move { control.wait_until_runnable(|_| {}).await }
+        });
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(control.interrupt());
+        assert!(!control.interrupt());
+
+        let result = tokio::time::timeout(Duration::from_secs(1), waiter)
+            .await
+            .expect("interrupt releases the waiter")
+            .unwrap();
+        assert!(result.unwrap_err().is::<Interrupted>());
+        assert!(control.is_interrupted());
+        assert!(control.wait_until_runnable(|_| {}).await.is_err());
+    }
+}
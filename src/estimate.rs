@@ -1,4 +1,7 @@
-use crate::auto::{AutoProfile, build_auto_plan, clamp_part_size_for_limit, is_instant_copy};
+use crate::auto::{
+    AutoProfile, VerifyIntegrity, build_auto_plan, clamp_part_size_for_limit, is_instant_copy,
+};
+use crate::verify::DEEP_VERIFY_RANGE_SIZE;
 use s3_pricing::s3_pricing_client::S3PricingClient;
 
 /// Cost estimation module for S3 copy operations.
@@ -13,10 +16,14 @@ use s3_pricing::s3_pricing_client::S3PricingClient;
 /// - Storage: varies by region & storage class
 /// - DELETE and CANCEL requests are free.
 /// - UploadPartCopy is billed as a PUT request on the destination bucket.
+/// - Data Transfer OUT to the internet: ~$0.09/GB (first 10 TB, most regions)
 ///
 /// Regional pricing data for S3 Standard storage class.
 /// Covers the most common AWS regions.
 /// Source: https://aws.amazon.com/s3/pricing/
+/// Fallback internet egress price per GB, used for `--verify-integrity deep` reads.
+const INTERNET_TRANSFER_OUT_PER_GB: f64 = 0.09;
+
 #[derive(Debug, Clone)]
 pub struct RegionPricing {
     /// Region identifier (e.g. "us-east-1")
@@ -203,6 +210,12 @@ fn storage_class_multiplier(storage_class: &str) -> f64 {
     }
 }
 
+/// Monthly storage cost of `bytes` in `storage_class`, from the built-in pricing table.
+pub fn monthly_storage_cost(region: &str, storage_class: &str, bytes: i64) -> f64 {
+    let gb = bytes as f64 / (1024.0 * 1024.0 * 1024.0);
+    gb * get_region_pricing(region).storage_per_gb * storage_class_multiplier(storage_class)
+}
+
 /// Get pricing for a region, falling back to us-east-1 defaults.
 pub fn get_region_pricing(region: &str) -> &'static RegionPricing {
     REGION_PRICING
@@ -231,6 +244,8 @@ pub struct CostEstimate {
     /// Individual cost items
     pub api_request_cost: f64,
     pub data_transfer_cost: f64,
+    /// Egress for `--verify-integrity deep` reading both objects back
+    pub verify_transfer_cost: f64,
     pub monthly_storage_cost: f64,
     /// Total one-time cost (API + transfer)
     pub total_one_time_cost: f64,
@@ -238,115 +253,9 @@ pub struct CostEstimate {
     pub breakdown: Vec<String>,
 }
 
-/// Orchestrate and run a cost estimate.
-pub async fn run_estimate(
-    args: &crate::args::Args,
-    source_region: &str,
-    dest_region: &str,
-    part_size_mb: i64,
-    concurrency: usize,
-    auto_profile: crate::auto::AutoProfile,
-    verify_integrity: crate::auto::VerifyIntegrity,
-) -> anyhow::Result<()> {
-    let source_key = args.source_key.clone().unwrap_or_default();
-    let dest_key = args.dest_key.clone().unwrap_or_default();
-
-    // Create app for getting size info
-    let app = crate::app::S3CopyApp::new(
-        args.source_bucket.clone().unwrap(),
-        source_key,
-        args.dest_bucket.clone().unwrap(),
-        dest_key,
-        args.dest_region
-            .clone()
-            .or(args.region.clone())
-            .or_else(|| Some(dest_region.to_string())),
-        Some(source_region.to_string()),
-        args.profile.clone(),
-        part_size_mb * 1024 * 1024,
-        concurrency,
-        args.storage_class.clone(),
-        args.full_control,
-        args.auto,
-        auto_profile,
-        args.no_metadata,
-        args.no_tags,
-        args.no_storage_class,
-        args.no_acl,
-        true, // quiet = true, we only want the estimate output
-        true, // dry_run = true, don't modify anything
-        args.force_copy,
-        verify_integrity,
-        args.checksum_algorithm.clone(),
-        args.sse.clone(),
-        args.sse_kms_key_id.clone(),
-        args.include.clone(),
-        args.exclude.clone(),
-    )
-    .await?;
-
-    // Clone prefix for later use in display
-    let source_prefix_clone = args.source_prefix.clone();
-
-    // Get file size or total size from prefix
-    let file_size = if let Some(ref source_prefix) = args.source_prefix {
-        app.get_total_size_from_prefix(source_prefix).await?
-    } else {
-        app.get_source_size().await?
-    };
-
-    // Attempt to load pricing client for accurate estimates, but fallback to static if it fails
-    let pricing = S3PricingClient::new(args.profile.as_deref()).await.ok();
-
-    let est = estimate_cost(
-        file_size,
-        part_size_mb * 1024 * 1024,
-        args.auto,
-        auto_profile,
-        source_region,
-        Some(dest_region),
-        args.storage_class.as_deref(),
-        args.no_tags,
-        pricing.as_ref(),
-    )
-    .await;
-
-    // Add object count to output if directory mode
-    if let Some(ref source_prefix) = source_prefix_clone {
-        let dest_prefix_display = args
-            .dest_prefix
-            .clone()
-            .unwrap_or_else(|| args.dest_key.clone().unwrap_or_default());
-        println!("\n=== S3 Directory Copy Estimate ===");
-        println!(
-            "Source prefix: s3://{}/{}",
-            args.source_bucket.clone().unwrap(),
-            source_prefix
-        );
-        println!(
-            "Destination:   s3://{}/{}",
-            args.dest_bucket.clone().unwrap(),
-            dest_prefix_display
-        );
-        println!();
-
-        // Need to get object count for directory mode
-        let object_count = app
-            .list_objects_with_prefix(source_prefix)
-            .await
-            .unwrap_or_default()
-            .len();
-        println!("Objects found: {}", object_count);
-        println!(
-            "Total size:    {} bytes ({:.2} GB)",
-            file_size,
-            file_size as f64 / (1024.0 * 1024.0 * 1024.0)
-        );
-        println!();
-    }
-
-    println!("{}", format_estimate(&est));
-    Ok(())
+/// ListObjectsV2 requests needed to enumerate `object_count` keys (1,000 per page).
+pub fn list_request_count(object_count: usize) -> usize {
+    object_count.div_ceil(1000).max(1)
 }
 
 /// Estimate the cost of a copy operation.
@@ -358,6 +267,7 @@ pub async fn run_estimate(
 /// * `source_region` - Source bucket region
 /// * `dest_region` - Destination bucket region (if different)
 /// * `storage_class` - Target storage class (defaults to STANDARD)
+/// * `verify_integrity` - Post-copy verification mode (`deep` adds ranged GETs and egress)
 #[allow(clippy::too_many_arguments)]
 pub async fn estimate_cost(
     file_size_bytes: i64,
@@ -368,6 +278,7 @@ pub async fn estimate_cost(
     dest_region: Option<&str>,
     storage_class: Option<&str>,
     no_tags: bool,
+    verify_integrity: VerifyIntegrity,
     pricing_client: Option<&S3PricingClient>,
 ) -> CostEstimate {
     let dest_region = dest_region.unwrap_or(source_region);
@@ -399,6 +310,8 @@ pub async fn estimate_cost(
     let mut storage_per_gb =
         fallback_pricing.storage_per_gb * storage_class_multiplier(storage_class_str);
     let mut transfer_out_per_gb = fallback_pricing.transfer_out_per_gb;
+    let mut internet_out_per_gb = INTERNET_TRANSFER_OUT_PER_GB;
+    let deep_verify = verify_integrity == VerifyIntegrity::Deep;
 
     if let Some(client) = pricing_client {
         if let Ok(p) = client
@@ -431,6 +344,9 @@ pub async fn estimate_cost(
                 transfer_out_per_gb = p;
             }
         }
+        if deep_verify && let Ok(p) = client.get_data_transfer_price(source_region).await {
+            internet_out_per_gb = p;
+        }
     }
 
     let mut breakdown = Vec::new();
@@ -499,13 +415,26 @@ pub async fn estimate_cost(
             "  CompleteMultipartUpload {:>6} req × ${:.4}/1k = ${:.6}",
             1, put_per_1k, complete_cost
         ));
+    }
+
+    // Both copy paths end with the same verification.
+    // HeadObject verification: 1x GET-class
+    let verify_cost = 1.0 / 1000.0 * get_per_1k;
+    api_request_cost += verify_cost;
+    breakdown.push(format!(
+        "  HeadObject (verify)     {:>6} req × ${:.4}/1k = ${:.6}",
+        1, get_per_1k, verify_cost
+    ));
 
-        // HeadObject verification: 1x GET-class
-        let verify_cost = 1.0 / 1000.0 * get_per_1k;
-        api_request_cost += verify_cost;
+    if deep_verify {
+        // One ranged GET per range on each object
+        let verify_gets =
+            2 * ((file_size_bytes + DEEP_VERIFY_RANGE_SIZE - 1) / DEEP_VERIFY_RANGE_SIZE).max(1);
+        let gets_cost = (verify_gets as f64) / 1000.0 * get_per_1k;
+        api_request_cost += gets_cost;
         breakdown.push(format!(
-            "  HeadObject (verify)     {:>6} req × ${:.4}/1k = ${:.6}",
-            1, get_per_1k, verify_cost
+            "  GetObject (deep verify) {:>6} req × ${:.4}/1k = ${:.6}",
+            verify_gets, get_per_1k, gets_cost
         ));
     }
 
@@ -522,7 +451,15 @@ pub async fn estimate_cost(
     // --- Storage Costs ---
     let monthly_storage_cost = file_size_gb * storage_per_gb;
 
-    let total_one_time_cost = api_request_cost + data_transfer_cost;
+    // Deep verification reads both objects through this process. Assumes it runs
+    // outside AWS; reads from EC2 in the buckets' regions are free.
+    let verify_transfer_cost = if deep_verify {
+        2.0 * file_size_gb * internet_out_per_gb
+    } else {
+        0.0
+    };
+
+    let total_one_time_cost = api_request_cost + data_transfer_cost + verify_transfer_cost;
 
     CostEstimate {
         source_region: source_region.to_string(),
@@ -534,6 +471,7 @@ pub async fn estimate_cost(
         same_region,
         api_request_cost,
         data_transfer_cost,
+        verify_transfer_cost,
         monthly_storage_cost,
         total_one_time_cost,
         breakdown,
@@ -615,6 +553,15 @@ pub fn format_estimate(est: &CostEstimate) -> String {
         let transfer_subtotal = format!("Subtotal: ${:.4}", est.data_transfer_cost);
         output.push_str(&format!("│ {:>60} │\n", transfer_subtotal));
     }
+    if est.verify_transfer_cost > 0.0 {
+        let line = format!(
+            "  Deep verify: 2 × {:.2} GB read = ${:.4}",
+            est.file_size_bytes as f64 / (1024.0 * 1024.0 * 1024.0),
+            est.verify_transfer_cost
+        );
+        output.push_str(&format!("│ {:<60}│\n", line));
+        output.push_str("│   (internet egress; free from EC2 in the same region)       │\n");
+    }
     output.push_str("└──────────────────────────────────────────────────────────────┘\n\n");
 
     output.push_str("┌──────────────────────────────────────────────────────────────┐\n");
@@ -659,6 +606,37 @@ pub fn format_estimate(est: &CostEstimate) -> String {
     output
 }
 
+/// Format the per-task and combined cost of a `--job` estimate.
+pub fn format_job_estimate(tasks: &[(String, CostEstimate)]) -> String {
+    let gb = |bytes: i64| bytes as f64 / (1024.0 * 1024.0 * 1024.0);
+    let mut output = String::new();
+    output.push_str(&format!("\n=== Job Estimate ({} tasks) ===\n", tasks.len()));
+    output.push_str(&format!(
+        "  {:<24} {:>12} {:>14} {:>14}\n",
+        "Task", "Size (GB)", "One-time", "Monthly"
+    ));
+    for (name, est) in tasks {
+        output.push_str(&format!(
+            "  {:<24} {:>12.2} {:>14} {:>14}\n",
+            name,
+            gb(est.file_size_bytes),
+            format!("${:.4}", est.total_one_time_cost),
+            format!("${:.4}/mo", est.monthly_storage_cost)
+        ));
+    }
+    let bytes: i64 = tasks.iter().map(|(_, est)| est.file_size_bytes).sum();
+    let one_time: f64 = tasks.iter().map(|(_, est)| est.total_one_time_cost).sum();
+    let monthly: f64 = tasks.iter().map(|(_, est)| est.monthly_storage_cost).sum();
+    output.push_str(&format!(
+        "  {:<24} {:>12.2} {:>14} {:>14}\n",
+        "TOTAL",
+        gb(bytes),
+        format!("${:.4}", one_time),
+        format!("${:.4}/mo", monthly)
+    ));
+    output
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -667,6 +645,45 @@ mod tests {
         n * 1024 * 1024 * 1024
     }
 
+    /// Verifies the job estimate lists every task and sums their costs.
+    #[tokio::test]
+    async fn job_estimate_sums_tasks() {
+        let mut tasks = Vec::new();
+        for (name, size) in [("logs", gib(10)), ("images", gib(30))] {
+            let est = estimate_cost(
+                size,
+                256 * 1024 * 1024,
+                false,
+                AutoProfile::Balanced,
+                "us-east-1",
+                Some("eu-west-1"),
+                Some("STANDARD"),
+                false,
+                VerifyIntegrity::Etag,
+                None,
+            )
+            .await;
+            tasks.push((name.to_string(), est));
+        }
+        let one_time = tasks[0].1.total_one_time_cost + tasks[1].1.total_one_time_cost;
+
+        let output = format_job_estimate(&tasks);
+        assert!(output.contains("Job Estimate (2 tasks)"));
+        assert!(output.contains("logs") && output.contains("images"));
+        let total = output.lines().find(|l| l.contains("TOTAL")).unwrap();
+        assert!(total.contains("40.00"));
+        assert!(total.contains(&format!("${:.4}", one_time)));
+    }
+
+    /// Verifies listing is billed one request per 1,000 keys, with at least one request.
+    #[test]
+    fn list_requests_are_counted_per_page() {
+        assert_eq!(list_request_count(0), 1);
+        assert_eq!(list_request_count(1000), 1);
+        assert_eq!(list_request_count(1001), 2);
+        assert_eq!(list_request_count(250_000_000), 250_000);
+    }
+
     /// Validates that auto mode uses Instant Copy for objects smaller than 5 GiB.
     #[tokio::test]
     async fn auto_small_file_uses_instant_copy_strategy() {
@@ -679,6 +696,7 @@ mod tests {
             Some("us-east-1"),
             Some("STANDARD"),
             false,
+            VerifyIntegrity::Etag,
             None,
         )
         .await;
@@ -704,6 +722,7 @@ mod tests {
             Some("eu-west-1"),
             Some("STANDARD"),
             false,
+            VerifyIntegrity::Etag,
             None,
         )
         .await;
@@ -712,6 +731,58 @@ mod tests {
         assert!(est.data_transfer_cost > 0.0);
     }
 
+    /// Verifies deep verification adds two ranged GETs per range and egress for both reads.
+    #[tokio::test]
+    async fn deep_verify_adds_gets_and_egress() {
+        let estimate = |verify_integrity| {
+            estimate_cost(
+                gib(10),
+                256 * 1024 * 1024,
+                false,
+                AutoProfile::Balanced,
+                "us-east-1",
+                Some("us-east-1"),
+                Some("STANDARD"),
+                false,
+                verify_integrity,
+                None,
+            )
+        };
+        let etag = estimate(VerifyIntegrity::Etag).await;
+        let deep = estimate(VerifyIntegrity::Deep).await;
+
+        assert_eq!(etag.verify_transfer_cost, 0.0);
+        assert!(
+            deep.breakdown
+                .iter()
+                .any(|line| line.contains("GetObject (deep verify)    320 req"))
+        );
+        assert!((deep.verify_transfer_cost - 20.0 * INTERNET_TRANSFER_OUT_PER_GB).abs() < 1e-9);
+        assert!(deep.total_one_time_cost > etag.total_one_time_cost + deep.verify_transfer_cost);
+
+        let instant = estimate_cost(
+            gib(1),
+            256 * 1024 * 1024,
+            true,
+            AutoProfile::Balanced,
+            "us-east-1",
+            Some("us-east-1"),
+            Some("STANDARD"),
+            false,
+            VerifyIntegrity::Deep,
+            None,
+        )
+        .await;
+        assert_eq!(instant.num_parts, 0);
+        assert!(
+            instant
+                .breakdown
+                .iter()
+                .any(|line| line.contains("GetObject (deep verify)     32 req"))
+        );
+        assert!((instant.verify_transfer_cost - 2.0 * INTERNET_TRANSFER_OUT_PER_GB).abs() < 1e-9);
+    }
+
     /// Ensures same-region estimates keep transfer charges at zero.
     #[tokio::test]
     async fn same_region_copy_has_zero_transfer_cost() {
@@ -724,6 +795,7 @@ mod tests {
             Some("us-east-1"),
             Some("STANDARD"),
             false,
+            VerifyIntegrity::Etag,
             None,
         )
         .await;
@@ -744,6 +816,7 @@ mod tests {
             Some("us-east-1"),
             Some("STANDARD"),
             false,
+            VerifyIntegrity::Etag,
             None,
         )
         .await;
@@ -756,6 +829,7 @@ mod tests {
             Some("us-east-1"),
             Some("STANDARD"),
             true,
+            VerifyIntegrity::Etag,
             None,
         )
         .await;
@@ -789,6 +863,7 @@ mod tests {
             Some("eu-west-1"),
             Some("STANDARD"),
             false,
+            VerifyIntegrity::Etag,
             None,
         )
         .await;
@@ -801,6 +876,7 @@ mod tests {
             Some("eu-west-1"),
             Some("STANDARD"),
             false,
+            VerifyIntegrity::Etag,
             None,
         )
         .await;
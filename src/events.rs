@@ -0,0 +1,396 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+use std::sync::{
+    Arc, Mutex,
+    atomic::{AtomicBool, Ordering},
+};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// How an object ended up at the destination.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum CopyAction {
+    /// Destination already identical in data and properties
+    Skipped,
+    /// CopyObject with the REPLACE directive, data untouched
+    PropertySync,
+    /// PutObjectTagging only
+    TagSync,
+    /// Single CopyObject
+    InstantCopy,
+    /// Multipart upload (server-side or streamed parts)
+    Multipart,
+}
+
+/// Where the object data travelled.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum DataRoute {
+    /// UploadPartCopy/CopyObject, the data never leaves S3
+    ServerSide,
+    /// Ranged GetObject -> UploadPart through this process
+    Streaming,
+}
+
+/// Outcome of the post-copy integrity check.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum Verification {
+    /// Disabled, dry run, or not performed for this copy path
+    NotRun,
+    /// Size and ETag/checksum matched
+    Passed,
+    /// Destination does not match the source
+    Failed,
+}
+
+/// Totals for a finished run, shared by the `summary` event and the run report.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct RunSummary {
+    pub total: usize,
+    pub processed: usize,
+    pub copied: usize,
+    pub skipped: usize,
+    pub failed: usize,
+    pub retried: usize,
+    pub deleted: usize,
+    pub bytes_copied: i64,
+    pub elapsed_ms: u64,
+}
+
+impl RunSummary {
+    /// Add the totals of another (sequential) run.
+    pub fn add(&mut self, other: &RunSummary) {
+        self.total += other.total;
+        self.processed += other.processed;
+        self.copied += other.copied;
+        self.skipped += other.skipped;
+        self.failed += other.failed;
+        self.retried += other.retried;
+        self.deleted += other.deleted;
+        self.bytes_copied += other.bytes_copied;
+        self.elapsed_ms += other.elapsed_ms;
+    }
+}
+
+/// One line of the NDJSON progress stream.
+///
+/// `copy_started` and `info` narrate the copy for a console and are only emitted when
+/// the app is not quiet; every other event is always emitted.
+#[derive(Serialize, Clone, Debug, PartialEq)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum ProgressEvent {
+    /// A single-object copy begins (the console banner).
+    CopyStarted {
+        source: String,
+        destination: String,
+        version_id: Option<String>,
+        part_size: i64,
+        concurrency: usize,
+        /// Streaming buffer budget, when parts are already streamed
+        streaming_buffer_mb: Option<u32>,
+        dry_run: bool,
+    },
+    /// One line of console narration: a step, a decision or a summary.
+    Info {
+        destination: String,
+        message: String,
+    },
+    /// A problem the copy survives, such as a fallback or a failed cleanup.
+    Warning {
+        destination: String,
+        message: String,
+    },
+    UploadStarted {
+        source: String,
+        destination: String,
+        upload_id: String,
+        size: i64,
+        part_size: i64,
+        parts: usize,
+        resumed_parts: usize,
+        /// Bytes of the resumed parts and warm-up probe, already in the upload
+        resumed_bytes: i64,
+    },
+    PartCompleted {
+        destination: String,
+        part_number: i32,
+        bytes: u64,
+        latency_ms: u64,
+        completed_parts: usize,
+        total_parts: usize,
+        copied_bytes: u64,
+    },
+    ConcurrencyChanged {
+        destination: String,
+        from: usize,
+        to: usize,
+        avg_part_seconds: f64,
+        throughput_mib_s: f64,
+    },
+    UploadCompleted {
+        destination: String,
+        upload_id: String,
+        parts: usize,
+    },
+    /// The run was interrupted while `upload_id` was in progress.
+    UploadInterrupted {
+        destination: String,
+        upload_id: String,
+        copied_parts: usize,
+        copied_bytes: u64,
+        /// Kept for `--resume` rather than aborted
+        kept: bool,
+    },
+    /// The upload failed and was kept, with its journal, for `--resume`.
+    UploadKept {
+        destination: String,
+        upload_id: String,
+        journal: String,
+        error: String,
+    },
+    /// The upload failed and was aborted.
+    UploadAborted {
+        destination: String,
+        upload_id: String,
+        error: String,
+        /// Set when the abort itself failed and the upload is left behind
+        abort_error: Option<String>,
+    },
+    /// New parts wait for the `--window` transfer window to open.
+    TransferWindowWaiting {
+        window: String,
+        wait_seconds: u32,
+    },
+    /// The transfer window opened; new parts start again.
+    TransferWindowOpened {
+        window: String,
+    },
+    ObjectSkipped {
+        source: String,
+        destination: String,
+        size: i64,
+    },
+    ObjectCopied {
+        source: String,
+        destination: String,
+        size: i64,
+        action: CopyAction,
+        retries: usize,
+        duration_ms: u64,
+    },
+    ObjectFailed {
+        source: String,
+        destination: String,
+        size: i64,
+        retries: usize,
+        error: String,
+    },
+    /// An extraneous destination object removed by `--delete`, or only planned for
+    /// removal in a dry run.
+    ObjectDeleted {
+        destination: String,
+        dry_run: bool,
+    },
+    Summary(RunSummary),
+}
+
+#[derive(Serialize)]
+struct Envelope<'a> {
+    ts_ms: u64,
+    #[serde(flatten)]
+    event: &'a ProgressEvent,
+}
+
+/// Callback receiving every progress event of a library copy.
+pub type ProgressCallback = Arc<dyn Fn(&ProgressEvent) + Send + Sync>;
+
+/// Shared destination of progress events: the NDJSON writer of `--progress-sink`, or a
+/// library callback. Write failures are reported once and never fail the copy.
+#[derive(Clone)]
+pub struct EventSink {
+    target: SinkTarget,
+    failed: Arc<AtomicBool>,
+}
+
+#[derive(Clone)]
+enum SinkTarget {
+    Writer(Arc<Mutex<Box<dyn Write + Send>>>),
+    Callback(ProgressCallback),
+}
+
+impl EventSink {
+    /// Open `-` (stdout), an existing unix socket, or a file (created/truncated).
+    pub fn open(target: &str) -> Result<Self> {
+        if target == "-" {
+            return Ok(Self::from_writer(Box::new(std::io::stdout())));
+        }
+        let path = Path::new(target);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::FileTypeExt;
+            if let Ok(meta) = std::fs::metadata(path)
+                && meta.file_type().is_socket()
+            {
+                let stream = std::os::unix::net::UnixStream::connect(path).with_context(|| {
+                    format!("Failed to connect to progress socket {}", path.display())
+                })?;
+                return Ok(Self::from_writer(Box::new(stream)));
+            }
+        }
+        let file = File::create(path)
+            .with_context(|| format!("Failed to create progress sink {}", path.display()))?;
+        Ok(Self::from_writer(Box::new(file)))
+    }
+
+    pub fn from_writer(writer: Box<dyn Write + Send>) -> Self {
+        Self {
+            target: SinkTarget::Writer(Arc::new(Mutex::new(writer))),
+            failed: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Hand every event to `callback` instead of writing NDJSON.
+    pub fn from_callback(callback: ProgressCallback) -> Self {
+        Self {
+            target: SinkTarget::Callback(callback),
+            failed: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    pub fn emit(&self, event: &ProgressEvent) {
+        let writer = match &self.target {
+            SinkTarget::Writer(writer) => writer,
+            SinkTarget::Callback(callback) => return callback(event),
+        };
+        let ts_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or_default();
+        let Ok(mut line) = serde_json::to_string(&Envelope { ts_ms, event }) else {
+            return;
+        };
+        line.push('\n');
+        let written = match writer.lock() {
+            Ok(mut w) => w.write_all(line.as_bytes()).and_then(|_| w.flush()),
+            Err(_) => return,
+        };
+        if let Err(e) = written
+            && !self.failed.swap(true, Ordering::SeqCst)
+        {
+            eprintln!("⚠️  Progress sink write failed: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct SharedBuf(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuf {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Ensures every event is written as one tagged JSON line with a timestamp.
+    #[test]
+    fn events_are_written_as_tagged_json_lines() {
+        let buf = SharedBuf::default();
+        let sink = EventSink::from_writer(Box::new(buf.clone()));
+
+        sink.emit(&ProgressEvent::ObjectSkipped {
+            source: "s3://src/a".to_string(),
+            destination: "s3://dst/a".to_string(),
+            size: 10,
+        });
+        sink.clone().emit(&ProgressEvent::ObjectCopied {
+            source: "s3://src/b".to_string(),
+            destination: "s3://dst/b".to_string(),
+            size: 20,
+            action: CopyAction::InstantCopy,
+            retries: 1,
+            duration_ms: 5,
+        });
+
+        let output = String::from_utf8(buf.0.lock().unwrap().clone()).unwrap();
+        let lines: Vec<serde_json::Value> = output
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["event"], "object_skipped");
+        assert!(lines[0]["ts_ms"].as_u64().unwrap() > 0);
+        assert_eq!(lines[1]["event"], "object_copied");
+        assert_eq!(lines[1]["action"], "instant-copy");
+        assert_eq!(lines[1]["size"], 20);
+    }
+
+    /// Ensures warnings and upload lifecycle events are tagged like the others.
+    #[test]
+    fn warning_and_upload_events_are_tagged() {
+        let buf = SharedBuf::default();
+        let sink = EventSink::from_writer(Box::new(buf.clone()));
+
+        sink.emit(&ProgressEvent::Warning {
+            destination: "s3://dst/a".to_string(),
+            message: "Failed to write upload marker".to_string(),
+        });
+        sink.emit(&ProgressEvent::UploadAborted {
+            destination: "s3://dst/a".to_string(),
+            upload_id: "u1".to_string(),
+            error: "boom".to_string(),
+            abort_error: None,
+        });
+
+        let output = String::from_utf8(buf.0.lock().unwrap().clone()).unwrap();
+        let lines: Vec<serde_json::Value> = output
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(lines[0]["event"], "warning");
+        assert_eq!(lines[0]["message"], "Failed to write upload marker");
+        assert_eq!(lines[1]["event"], "upload_aborted");
+        assert_eq!(lines[1]["upload_id"], "u1");
+        assert!(lines[1]["abort_error"].is_null());
+    }
+
+    /// Verifies a callback sink receives the events themselves, in order.
+    #[test]
+    fn callback_sink_receives_events() {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let seen = received.clone();
+        let sink = EventSink::from_callback(Arc::new(move |event: &ProgressEvent| {
+            seen.lock().unwrap().push(event.clone());
+        }));
+
+        let summary = RunSummary {
+            copied: 2,
+            ..RunSummary::default()
+        };
+        sink.emit(&ProgressEvent::ObjectSkipped {
+            source: "s3://src/a".to_string(),
+            destination: "s3://dst/a".to_string(),
+            size: 10,
+        });
+        sink.emit(&ProgressEvent::Summary(summary.clone()));
+
+        let received = received.lock().unwrap();
+        assert_eq!(received.len(), 2);
+        assert!(matches!(
+            received[0],
+            ProgressEvent::ObjectSkipped { size: 10, .. }
+        ));
+        assert_eq!(received[1], ProgressEvent::Summary(summary));
+    }
+}
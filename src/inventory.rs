@@ -0,0 +1,442 @@
+use crate::uri::S3Uri;
+use anyhow::{Context, Result};
+use arrow_array::cast::AsArray;
+use arrow_array::{Array, ArrayRef, RecordBatch};
+use arrow_schema::{ArrowError, DataType};
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use percent_encoding::percent_decode_str;
+use serde::Deserialize;
+use std::fs::File;
+
+/// Data file format of an inventory report.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum InventoryFormat {
+    /// Gzipped CSV with the columns listed in `fileSchema`
+    Csv,
+    Orc,
+    Parquet,
+}
+
+/// Parsed S3 Inventory `manifest.json`.
+#[derive(Clone, Debug)]
+pub struct InventoryManifest {
+    pub source_bucket: String,
+    /// Bucket holding the data files (from the `destinationBucket` ARN)
+    pub files_bucket: String,
+    pub format: InventoryFormat,
+    /// CSV column positions; ORC and Parquet files carry their own schema
+    pub columns: InventoryColumns,
+    pub files: Vec<InventoryFile>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InventoryFile {
+    pub key: String,
+    pub size: i64,
+}
+
+/// Positions of the fields used from each inventory row.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InventoryColumns {
+    key: usize,
+    size: Option<usize>,
+    e_tag: Option<usize>,
+    storage_class: Option<usize>,
+    is_latest: Option<usize>,
+    is_delete_marker: Option<usize>,
+}
+
+/// One current object from an inventory data file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InventoryRow {
+    pub key: String,
+    pub size: i64,
+    pub e_tag: Option<String>,
+    pub storage_class: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawManifest {
+    source_bucket: String,
+    destination_bucket: String,
+    file_format: String,
+    file_schema: String,
+    files: Vec<RawFile>,
+}
+
+#[derive(Deserialize)]
+struct RawFile {
+    key: String,
+    #[serde(default)]
+    size: i64,
+}
+
+/// Split the `s3://bucket/key` URI of an object into bucket and key.
+pub fn parse_s3_uri(uri: &str) -> Result<(String, String)> {
+    match S3Uri::parse(uri) {
+        Ok(parsed) if !parsed.is_prefix() => Ok((parsed.bucket, parsed.key)),
+        _ => anyhow::bail!("Expected an s3://bucket/key URI, got '{}'", uri),
+    }
+}
+
+impl InventoryManifest {
+    pub fn parse(json: &[u8]) -> Result<Self> {
+        let raw: RawManifest =
+            serde_json::from_slice(json).context("Failed to parse inventory manifest.json")?;
+        let format = match raw.file_format.to_ascii_uppercase().as_str() {
+            "CSV" => InventoryFormat::Csv,
+            "ORC" => InventoryFormat::Orc,
+            "PARQUET" => InventoryFormat::Parquet,
+            _ => anyhow::bail!("Inventory format {} is not supported", raw.file_format),
+        };
+        let columns = match format {
+            InventoryFormat::Csv => InventoryColumns::from_schema(&raw.file_schema)?,
+            InventoryFormat::Orc | InventoryFormat::Parquet => InventoryColumns::default(),
+        };
+        let files_bucket = raw
+            .destination_bucket
+            .rsplit(':')
+            .next()
+            .unwrap_or_default()
+            .to_string();
+        Ok(Self {
+            source_bucket: raw.source_bucket,
+            files_bucket,
+            format,
+            columns,
+            files: raw
+                .files
+                .into_iter()
+                .map(|f| InventoryFile {
+                    key: f.key,
+                    size: f.size,
+                })
+                .collect(),
+        })
+    }
+}
+
+impl InventoryColumns {
+    fn from_schema(schema: &str) -> Result<Self> {
+        let names: Vec<&str> = schema.split(',').map(str::trim).collect();
+        Self::from_names(&names)
+    }
+
+    /// Locate the fields by name: CSV schemas name them `ETag`, ORC and Parquet `e_tag`.
+    fn from_names(names: &[&str]) -> Result<Self> {
+        let normalized: Vec<String> = names
+            .iter()
+            .map(|n| n.replace('_', "").to_ascii_lowercase())
+            .collect();
+        let find = |name: &str| normalized.iter().position(|n| n == name);
+        Ok(Self {
+            key: find("key").ok_or_else(|| anyhow::anyhow!("Inventory schema has no Key field"))?,
+            size: find("size"),
+            e_tag: find("etag"),
+            storage_class: find("storageclass"),
+            is_latest: find("islatest"),
+            is_delete_marker: find("isdeletemarker"),
+        })
+    }
+
+    /// Parse one CSV line. Non-current versions and delete markers are dropped.
+    pub fn parse_row(&self, line: &str) -> Option<InventoryRow> {
+        let fields = split_csv_line(line);
+        let field = |idx: Option<usize>| idx.and_then(|i| fields.get(i)).map(String::as_str);
+        if field(self.is_latest).is_some_and(|v| v.eq_ignore_ascii_case("false"))
+            || field(self.is_delete_marker).is_some_and(|v| v.eq_ignore_ascii_case("true"))
+        {
+            return None;
+        }
+        let key = decode_key(field(Some(self.key))?);
+        if key.is_empty() {
+            return None;
+        }
+        Some(InventoryRow {
+            key,
+            size: field(self.size).and_then(|s| s.parse().ok()).unwrap_or(0),
+            e_tag: field(self.e_tag)
+                .filter(|v| !v.is_empty())
+                .map(str::to_string),
+            storage_class: field(self.storage_class)
+                .filter(|v| !v.is_empty())
+                .map(str::to_string),
+        })
+    }
+
+    /// Current objects of one ORC or Parquet record batch. Unlike CSV, keys in these
+    /// formats are not URL-encoded.
+    fn batch_rows(batch: &RecordBatch) -> Result<Vec<InventoryRow>> {
+        let schema = batch.schema();
+        let names: Vec<&str> = schema.fields().iter().map(|f| f.name().as_str()).collect();
+        let columns = Self::from_names(&names)?;
+        let column = |idx: Option<usize>, data_type: &DataType| -> Result<Option<ArrayRef>> {
+            idx.map(|i| arrow_cast::cast(batch.column(i), data_type))
+                .transpose()
+                .context("Unexpected inventory column type")
+        };
+
+        let keys = arrow_cast::cast(batch.column(columns.key), &DataType::Utf8)
+            .context("Unexpected inventory column type")?;
+        let keys = keys.as_string::<i32>();
+        let sizes = column(columns.size, &DataType::Int64)?;
+        let sizes = sizes
+            .as_ref()
+            .map(|a| a.as_primitive::<arrow_array::types::Int64Type>());
+        let e_tags = column(columns.e_tag, &DataType::Utf8)?;
+        let e_tags = e_tags.as_ref().map(|a| a.as_string::<i32>());
+        let classes = column(columns.storage_class, &DataType::Utf8)?;
+        let classes = classes.as_ref().map(|a| a.as_string::<i32>());
+        let latest = column(columns.is_latest, &DataType::Boolean)?;
+        let latest = latest.as_ref().map(|a| a.as_boolean());
+        let markers = column(columns.is_delete_marker, &DataType::Boolean)?;
+        let markers = markers.as_ref().map(|a| a.as_boolean());
+
+        let mut rows = Vec::with_capacity(batch.num_rows());
+        for i in 0..batch.num_rows() {
+            if latest.is_some_and(|a| a.is_valid(i) && !a.value(i))
+                || markers.is_some_and(|a| a.is_valid(i) && a.value(i))
+                || keys.is_null(i)
+                || keys.value(i).is_empty()
+            {
+                continue;
+            }
+            let text = |array: Option<&arrow_array::StringArray>| {
+                array
+                    .filter(|a| a.is_valid(i) && !a.value(i).is_empty())
+                    .map(|a| a.value(i).to_string())
+            };
+            rows.push(InventoryRow {
+                key: keys.value(i).to_string(),
+                size: sizes
+                    .filter(|a| a.is_valid(i))
+                    .map(|a| a.value(i))
+                    .unwrap_or(0),
+                e_tag: text(e_tags),
+                storage_class: text(classes),
+            });
+        }
+        Ok(rows)
+    }
+}
+
+/// Read the current objects of an ORC or Parquet data file, handing them to `emit`
+/// one record batch at a time until it returns `false`.
+pub fn read_columnar_file(
+    format: InventoryFormat,
+    file: File,
+    mut emit: impl FnMut(Vec<InventoryRow>) -> bool,
+) -> Result<()> {
+    let batches: Box<dyn Iterator<Item = std::result::Result<RecordBatch, ArrowError>>> =
+        match format {
+            InventoryFormat::Orc => Box::new(
+                orc_rust::ArrowReaderBuilder::try_new(file)
+                    .context("Failed to open ORC inventory file")?
+                    .build(),
+            ),
+            InventoryFormat::Parquet => Box::new(
+                ParquetRecordBatchReaderBuilder::try_new(file)
+                    .context("Failed to open Parquet inventory file")?
+                    .build()
+                    .context("Failed to open Parquet inventory file")?,
+            ),
+            InventoryFormat::Csv => anyhow::bail!("CSV inventory files are read line by line"),
+        };
+    for batch in batches {
+        let batch = batch.context("Failed to decode inventory file")?;
+        if !emit(InventoryColumns::batch_rows(&batch)?) {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Inventory CSV keys are URL-encoded (spaces as `+`).
+fn decode_key(raw: &str) -> String {
+    percent_decode_str(&raw.replace('+', " "))
+        .decode_utf8_lossy()
+        .into_owned()
+}
+
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.trim_end_matches(['\r', '\n']).chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => fields.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = r#"{
+        "sourceBucket": "example-source-bucket",
+        "destinationBucket": "arn:aws:s3:::example-inventory-bucket",
+        "version": "2016-11-30",
+        "fileFormat": "CSV",
+        "fileSchema": "Bucket, Key, VersionId, IsLatest, IsDeleteMarker, Size, LastModifiedDate, ETag, StorageClass",
+        "files": [
+            {"key": "inv/data/1.csv.gz", "size": 2048, "MD5checksum": "f111"}
+        ]
+    }"#;
+
+    /// Verifies manifest fields, data file locations and schema positions are read.
+    #[test]
+    fn parses_csv_manifest() {
+        let manifest = InventoryManifest::parse(MANIFEST.as_bytes()).unwrap();
+        assert_eq!(manifest.source_bucket, "example-source-bucket");
+        assert_eq!(manifest.files_bucket, "example-inventory-bucket");
+        assert_eq!(manifest.format, InventoryFormat::Csv);
+        assert_eq!(
+            manifest.files,
+            vec![InventoryFile {
+                key: "inv/data/1.csv.gz".to_string(),
+                size: 2048
+            }]
+        );
+
+        let parquet = MANIFEST.replace("\"CSV\"", "\"Parquet\"");
+        let parquet = InventoryManifest::parse(parquet.as_bytes()).unwrap();
+        assert_eq!(parquet.format, InventoryFormat::Parquet);
+
+        let json = MANIFEST.replace("\"CSV\"", "\"JSON\"");
+        let err = InventoryManifest::parse(json.as_bytes()).unwrap_err();
+        assert!(err.to_string().contains("JSON"));
+    }
+
+    /// Ensures rows decode keys and skip old versions and delete markers.
+    #[test]
+    fn parses_rows_and_skips_non_current_versions() {
+        let columns = InventoryManifest::parse(MANIFEST.as_bytes())
+            .unwrap()
+            .columns;
+        let row = |key: &str, latest: &str, marker: &str| {
+            format!(
+                "\"example-source-bucket\",\"{}\",\"v1\",\"{}\",\"{}\",\"1024\",\"2024-01-01T00:00:00.000Z\",\"abc\",\"STANDARD\"",
+                key, latest, marker
+            )
+        };
+
+        assert_eq!(
+            columns.parse_row(&row("data/my+file%2B1.bin", "true", "false")),
+            Some(InventoryRow {
+                key: "data/my file+1.bin".to_string(),
+                size: 1024,
+                e_tag: Some("abc".to_string()),
+                storage_class: Some("STANDARD".to_string()),
+            })
+        );
+        assert_eq!(columns.parse_row(&row("data/a", "false", "false")), None);
+        assert_eq!(columns.parse_row(&row("data/a", "true", "true")), None);
+    }
+
+    /// Inventory batch with one current object, one old version and one delete marker.
+    fn inventory_batch() -> RecordBatch {
+        use arrow_array::{BooleanArray, Int64Array, StringArray};
+        use arrow_schema::{Field, Schema};
+        use std::sync::Arc;
+
+        let schema = Schema::new(vec![
+            Field::new("bucket", DataType::Utf8, false),
+            Field::new("key", DataType::Utf8, false),
+            Field::new("is_latest", DataType::Boolean, true),
+            Field::new("is_delete_marker", DataType::Boolean, true),
+            Field::new("size", DataType::Int64, true),
+            Field::new("e_tag", DataType::Utf8, true),
+            Field::new("storage_class", DataType::Utf8, true),
+        ]);
+        RecordBatch::try_new(
+            Arc::new(schema),
+            vec![
+                Arc::new(StringArray::from(vec!["b", "b", "b"])),
+                Arc::new(StringArray::from(vec!["data/a b", "data/old", "data/gone"])),
+                Arc::new(BooleanArray::from(vec![
+                    Some(true),
+                    Some(false),
+                    Some(true),
+                ])),
+                Arc::new(BooleanArray::from(vec![None, Some(false), Some(true)])),
+                Arc::new(Int64Array::from(vec![Some(7), Some(8), None])),
+                Arc::new(StringArray::from(vec![
+                    Some("etag-a"),
+                    Some("etag-old"),
+                    None,
+                ])),
+                Arc::new(StringArray::from(vec![Some("GLACIER_IR"), None, None])),
+            ],
+        )
+        .unwrap()
+    }
+
+    fn read_back(format: InventoryFormat, file: File) -> Vec<InventoryRow> {
+        let mut rows = Vec::new();
+        read_columnar_file(format, file, |batch| {
+            rows.extend(batch);
+            true
+        })
+        .unwrap();
+        rows
+    }
+
+    fn expected_rows() -> Vec<InventoryRow> {
+        vec![InventoryRow {
+            key: "data/a b".to_string(),
+            size: 7,
+            e_tag: Some("etag-a".to_string()),
+            storage_class: Some("GLACIER_IR".to_string()),
+        }]
+    }
+
+    /// Verifies ORC data files yield current objects with their ETag and storage class.
+    #[test]
+    fn reads_orc_inventory_files() {
+        let batch = inventory_batch();
+        let mut file = tempfile::tempfile().unwrap();
+        let mut writer = orc_rust::ArrowWriterBuilder::new(&mut file, batch.schema())
+            .try_build()
+            .unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        assert_eq!(read_back(InventoryFormat::Orc, file), expected_rows());
+    }
+
+    /// Verifies Parquet data files yield current objects with their ETag and storage class.
+    #[test]
+    fn reads_parquet_inventory_files() {
+        let batch = inventory_batch();
+        let file = tempfile::tempfile().unwrap();
+        let mut writer =
+            parquet::arrow::ArrowWriter::try_new(file.try_clone().unwrap(), batch.schema(), None)
+                .unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        assert_eq!(read_back(InventoryFormat::Parquet, file), expected_rows());
+    }
+
+    /// Confirms s3:// URIs are split into bucket and key.
+    #[test]
+    fn parses_s3_uris() {
+        assert_eq!(
+            parse_s3_uri("s3://inv-bucket/path/manifest.json").unwrap(),
+            ("inv-bucket".to_string(), "path/manifest.json".to_string())
+        );
+        assert!(parse_s3_uri("https://inv-bucket/manifest.json").is_err());
+        assert!(parse_s3_uri("s3://inv-bucket/").is_err());
+    }
+}
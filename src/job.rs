@@ -0,0 +1,404 @@
+use crate::args::{
+    MAX_CONCURRENT_PARTS, MAX_OBJECT_CONCURRENCY, MAX_PART_SIZE_MB, MIN_PART_SIZE_MB, ObjectArgs,
+    TransferArgs,
+};
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+use s3_largecopy::app;
+use s3_largecopy::auto::{AutoProfile, DataPath, OnSourceChange, VerifyIntegrity};
+use s3_largecopy::uri::{CopyScope, S3Uri};
+use serde::{Deserialize, Deserializer};
+use std::path::Path;
+
+const CHECKSUM_ALGORITHMS: [&str; 4] = ["CRC32", "CRC32C", "SHA1", "SHA256"];
+const SSE_ALGORITHMS: [&str; 2] = ["AES256", "aws:kms"];
+
+/// `--job` TOML file: a `[defaults]` table and one `[[task]]` table per copy.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct JobFile {
+    #[serde(default)]
+    defaults: TaskSpec,
+    #[serde(default, rename = "task")]
+    tasks: Vec<TaskSpec>,
+}
+
+/// One `[[task]]` (or the `[defaults]` table). Fields use the names of the matching
+/// flags; unset fields fall back to `[defaults]`, then to the command line.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct TaskSpec {
+    name: Option<String>,
+    source: Option<String>,
+    dest: Option<String>,
+
+    storage_class: Option<String>,
+    no_storage_class: Option<bool>,
+    full_control: Option<bool>,
+    no_acl: Option<bool>,
+    no_metadata: Option<bool>,
+    no_tags: Option<bool>,
+    checksum_algorithm: Option<String>,
+    sse: Option<String>,
+    sse_kms_key_id: Option<String>,
+    match_source_parts: Option<bool>,
+
+    /// Part size in MB
+    part_size: Option<i64>,
+    concurrency: Option<usize>,
+    auto: Option<bool>,
+    #[serde(deserialize_with = "value_enum")]
+    auto_profile: Option<AutoProfile>,
+    force_copy: Option<bool>,
+    no_clobber: Option<bool>,
+    #[serde(deserialize_with = "value_enum")]
+    verify_integrity: Option<VerifyIntegrity>,
+    #[serde(deserialize_with = "value_enum")]
+    on_source_change: Option<OnSourceChange>,
+    #[serde(deserialize_with = "value_enum")]
+    data_path: Option<DataPath>,
+
+    include: Option<Vec<String>>,
+    exclude: Option<Vec<String>>,
+    object_concurrency: Option<usize>,
+    delete: Option<bool>,
+    max_delete: Option<usize>,
+    all_versions: Option<bool>,
+}
+
+/// Accept the command-line spelling of an enum flag (`cost-efficient`, `deep`, ...).
+fn value_enum<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: ValueEnum,
+{
+    Option::<String>::deserialize(deserializer)?
+        .map(|value| T::from_str(&value, true).map_err(serde::de::Error::custom))
+        .transpose()
+}
+
+/// Fill every field the task leaves unset from `[defaults]`.
+macro_rules! or_defaults {
+    ($task:ident, $defaults:ident, $($field:ident),+ $(,)?) => {
+        $(
+            if $task.$field.is_none() {
+                $task.$field = $defaults.$field.clone();
+            }
+        )+
+    };
+}
+
+impl TaskSpec {
+    fn with_defaults(mut self, defaults: &TaskSpec) -> Self {
+        or_defaults!(
+            self,
+            defaults,
+            storage_class,
+            no_storage_class,
+            full_control,
+            no_acl,
+            no_metadata,
+            no_tags,
+            checksum_algorithm,
+            sse,
+            sse_kms_key_id,
+            match_source_parts,
+            part_size,
+            concurrency,
+            auto,
+            auto_profile,
+            force_copy,
+            no_clobber,
+            verify_integrity,
+            on_source_change,
+            data_path,
+            include,
+            exclude,
+            object_concurrency,
+            delete,
+            max_delete,
+            all_versions,
+        );
+        self
+    }
+}
+
+/// One copy of a job, with `[defaults]` applied.
+#[derive(Debug)]
+pub struct JobTask {
+    pub name: String,
+    pub source: S3Uri,
+    pub dest: S3Uri,
+    pub scope: CopyScope,
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+    pub object_concurrency: Option<usize>,
+    pub delete: bool,
+    pub max_delete: Option<usize>,
+    pub all_versions: bool,
+    spec: TaskSpec,
+}
+
+impl JobTask {
+    fn resolve(index: usize, spec: TaskSpec) -> Result<Self> {
+        let source = required_uri(spec.source.as_deref(), "source")?;
+        let dest = required_uri(spec.dest.as_deref(), "dest")?;
+        let scope = CopyScope::between(&source, &dest)?;
+        let prefix = matches!(scope, CopyScope::Prefix { .. });
+
+        if let Some(part_size) = spec.part_size
+            && !(MIN_PART_SIZE_MB..=MAX_PART_SIZE_MB).contains(&part_size)
+        {
+            anyhow::bail!(
+                "part_size must be between {} and {} MB",
+                MIN_PART_SIZE_MB,
+                MAX_PART_SIZE_MB
+            );
+        }
+        if let Some(concurrency) = spec.concurrency
+            && !(1..=MAX_CONCURRENT_PARTS).contains(&concurrency)
+        {
+            anyhow::bail!("concurrency must be between 1 and {}", MAX_CONCURRENT_PARTS);
+        }
+        if let Some(object_concurrency) = spec.object_concurrency
+            && !(1..=MAX_OBJECT_CONCURRENCY).contains(&object_concurrency)
+        {
+            anyhow::bail!(
+                "object_concurrency must be between 1 and {}",
+                MAX_OBJECT_CONCURRENCY
+            );
+        }
+        if let Some(algorithm) = &spec.checksum_algorithm
+            && !CHECKSUM_ALGORITHMS.contains(&algorithm.as_str())
+        {
+            anyhow::bail!(
+                "checksum_algorithm must be one of {}",
+                CHECKSUM_ALGORITHMS.join(", ")
+            );
+        }
+        if let Some(sse) = &spec.sse
+            && !SSE_ALGORITHMS.contains(&sse.as_str())
+        {
+            anyhow::bail!("sse must be one of {}", SSE_ALGORITHMS.join(", "));
+        }
+
+        let delete = spec.delete.unwrap_or(false);
+        let all_versions = spec.all_versions.unwrap_or(false);
+        let no_clobber = spec.no_clobber.unwrap_or(false);
+        if !prefix
+            && (spec.include.is_some()
+                || spec.exclude.is_some()
+                || spec.object_concurrency.is_some()
+                || delete
+                || spec.max_delete.is_some()
+                || all_versions)
+        {
+            anyhow::bail!(
+                "include, exclude, object_concurrency, delete, max_delete and all_versions need a prefix source (ending in '/')"
+            );
+        }
+        if spec.max_delete.is_some() && !delete {
+            anyhow::bail!("max_delete requires delete = true");
+        }
+        if all_versions && (delete || no_clobber) {
+            anyhow::bail!("delete and no_clobber cannot be used with all_versions");
+        }
+        if let CopyScope::Prefix {
+            source_prefix,
+            dest_prefix,
+        } = &scope
+            && delete
+        {
+            app::check_delete_scope(&source.bucket, source_prefix, &dest.bucket, dest_prefix)?;
+        }
+        if no_clobber && spec.force_copy.unwrap_or(false) {
+            anyhow::bail!("no_clobber cannot be used with force_copy");
+        }
+
+        Ok(Self {
+            name: spec
+                .name
+                .clone()
+                .unwrap_or_else(|| format!("task {}", index + 1)),
+            source,
+            dest,
+            scope,
+            include: spec.include.clone().unwrap_or_default(),
+            exclude: spec.exclude.clone().unwrap_or_default(),
+            object_concurrency: spec.object_concurrency,
+            delete,
+            max_delete: spec.max_delete,
+            all_versions,
+            spec,
+        })
+    }
+
+    /// Destination object settings: the task's, else the command line's.
+    pub fn object_args(&self, flags: &ObjectArgs) -> ObjectArgs {
+        let spec = &self.spec;
+        ObjectArgs {
+            storage_class: spec
+                .storage_class
+                .clone()
+                .or_else(|| flags.storage_class.clone()),
+            no_storage_class: spec.no_storage_class.unwrap_or(flags.no_storage_class),
+            full_control: spec.full_control.unwrap_or(flags.full_control),
+            no_acl: spec.no_acl.unwrap_or(flags.no_acl),
+            no_metadata: spec.no_metadata.unwrap_or(flags.no_metadata),
+            no_tags: spec.no_tags.unwrap_or(flags.no_tags),
+            checksum_algorithm: spec
+                .checksum_algorithm
+                .clone()
+                .or_else(|| flags.checksum_algorithm.clone()),
+            sse: spec.sse.clone().or_else(|| flags.sse.clone()),
+            sse_kms_key_id: spec
+                .sse_kms_key_id
+                .clone()
+                .or_else(|| flags.sse_kms_key_id.clone()),
+            match_source_parts: spec.match_source_parts.unwrap_or(flags.match_source_parts),
+        }
+    }
+
+    /// Transfer settings: the task's, else the command line's. Rate limits and the
+    /// buffer budget are shared by the whole job and only come from the command line.
+    pub fn transfer_args(&self, flags: &TransferArgs) -> TransferArgs {
+        let spec = &self.spec;
+        TransferArgs {
+            part_size: spec.part_size.or(flags.part_size),
+            concurrency: spec.concurrency.or(flags.concurrency),
+            auto: spec.auto.unwrap_or(flags.auto),
+            auto_profile: spec.auto_profile.or(flags.auto_profile),
+            force_copy: spec.force_copy.unwrap_or(flags.force_copy),
+            no_clobber: spec.no_clobber.unwrap_or(flags.no_clobber),
+            verify_integrity: spec.verify_integrity.or(flags.verify_integrity),
+            on_source_change: spec.on_source_change.or(flags.on_source_change),
+            data_path: spec.data_path.or(flags.data_path),
+            max_buffer_mb: flags.max_buffer_mb,
+            max_bandwidth: flags.max_bandwidth,
+            max_requests_per_second: flags.max_requests_per_second,
+        }
+    }
+}
+
+fn required_uri(value: Option<&str>, field: &str) -> Result<S3Uri> {
+    let value = value.ok_or_else(|| anyhow::anyhow!("missing {}", field))?;
+    S3Uri::parse(value).map_err(|e| anyhow::anyhow!("invalid {}: {}", field, e))
+}
+
+/// Parse a job file into its tasks.
+pub fn parse_job(text: &str) -> Result<Vec<JobTask>> {
+    let job: JobFile = toml::from_str(text)?;
+    if job.defaults.name.is_some() || job.defaults.source.is_some() || job.defaults.dest.is_some() {
+        anyhow::bail!("[defaults] cannot set name, source or dest");
+    }
+    if job.tasks.is_empty() {
+        anyhow::bail!("The job has no [[task]] tables");
+    }
+    job.tasks
+        .into_iter()
+        .enumerate()
+        .map(|(index, spec)| {
+            let label = spec.name.clone().unwrap_or_else(|| (index + 1).to_string());
+            JobTask::resolve(index, spec.with_defaults(&job.defaults))
+                .with_context(|| format!("Invalid task {}", label))
+        })
+        .collect()
+}
+
+/// Load a `--job` file.
+pub fn load_job(path: &Path) -> Result<Vec<JobTask>> {
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read job file {}", path.display()))?;
+    parse_job(&text).with_context(|| format!("Invalid job file {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const JOB: &str = r#"
+        [defaults]
+        auto = true
+        auto_profile = "cost-efficient"
+        storage_class = "STANDARD_IA"
+
+        [[task]]
+        name = "logs"
+        source = "s3://src-a/logs/"
+        dest = "s3://dst-a/archive/logs/"
+        storage_class = "GLACIER_IR"
+        include = ["*.gz"]
+        delete = true
+
+        [[task]]
+        source = "s3://src-b/images/disk.img"
+        dest = "s3://dst-b/images/"
+        sse = "aws:kms"
+        sse_kms_key_id = "alias/migration"
+        auto = false
+    "#;
+
+    /// Verifies tasks inherit `[defaults]`, override it, and infer their scope from URIs.
+    #[test]
+    fn tasks_override_defaults_and_flags() {
+        let tasks = parse_job(JOB).unwrap();
+        assert_eq!(tasks.len(), 2);
+
+        let logs = &tasks[0];
+        assert_eq!(logs.name, "logs");
+        assert!(
+            matches!(&logs.scope, CopyScope::Prefix { dest_prefix, .. } if dest_prefix == "archive/logs/")
+        );
+        assert_eq!(logs.include, vec!["*.gz".to_string()]);
+        assert!(logs.delete);
+        let flags = ObjectArgs {
+            no_tags: true,
+            ..ObjectArgs::default()
+        };
+        let object = logs.object_args(&flags);
+        assert_eq!(object.storage_class.as_deref(), Some("GLACIER_IR"));
+        assert!(object.no_tags);
+        let transfer = logs.transfer_args(&TransferArgs {
+            max_bandwidth: Some(1000),
+            ..TransferArgs::default()
+        });
+        assert!(transfer.auto);
+        assert_eq!(transfer.auto_profile, Some(AutoProfile::CostEfficient));
+        assert_eq!(transfer.max_bandwidth, Some(1000));
+
+        let image = &tasks[1];
+        assert_eq!(image.name, "task 2");
+        assert!(
+            matches!(&image.scope, CopyScope::Object { dest_key, .. } if dest_key == "images/disk.img")
+        );
+        let object = image.object_args(&ObjectArgs::default());
+        assert_eq!(object.storage_class.as_deref(), Some("STANDARD_IA"));
+        assert_eq!(object.sse_kms_key_id.as_deref(), Some("alias/migration"));
+        assert!(!image.transfer_args(&TransferArgs::default()).auto);
+    }
+
+    /// Ensures unknown fields, bad values and prefix-only settings on objects are rejected.
+    #[test]
+    fn rejects_invalid_tasks() {
+        let task = |body: &str| parse_job(&format!("[[task]]\n{}", body));
+        let object = "source = \"s3://a/k\"\ndest = \"s3://b/k\"\n";
+
+        assert!(task(object).is_ok());
+        assert!(task(&format!("{}storage_clas = \"GLACIER\"", object)).is_err());
+        assert!(task(&format!("{}auto_profile = \"fastest\"", object)).is_err());
+        assert!(task(&format!("{}part_size = 2", object)).is_err());
+        assert!(task(&format!("{}delete = true", object)).is_err());
+        assert!(task("source = \"s3://a/k\"").is_err());
+        assert!(
+            task(
+                "source = \"s3://a/p/\"\ndest = \"s3://b/p/\"\nall_versions = true\ndelete = true"
+            )
+            .is_err()
+        );
+        assert!(task("source = \"s3://a/p/in/\"\ndest = \"s3://a/p/\"\ndelete = true").is_err());
+        assert!(
+            parse_job("[defaults]\nsource = \"s3://a/\"\n[[task]]\ndest = \"s3://b/\"").is_err()
+        );
+        assert!(parse_job("[defaults]\nauto = true").is_err());
+    }
+}
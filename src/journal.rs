@@ -0,0 +1,505 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+pub const DEFAULT_JOURNAL_DIR: &str = ".s3_largecopy";
+const JOURNAL_VERSION: u32 = 1;
+
+/// Byte range assigned to a multipart part number.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PlannedPart {
+    pub part_number: i32,
+    pub start: i64,
+    pub end: i64,
+}
+
+impl PlannedPart {
+    pub fn len(&self) -> i64 {
+        self.end - self.start + 1
+    }
+
+    pub fn range(&self) -> String {
+        format!("bytes={}-{}", self.start, self.end)
+    }
+}
+
+/// One line of the append-only journal file.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum JournalRecord {
+    Header {
+        version: u32,
+        source_bucket: String,
+        source_key: String,
+        dest_bucket: String,
+        dest_key: String,
+        source_etag: String,
+        content_length: i64,
+        upload_id: String,
+    },
+    Plan {
+        parts: Vec<PlannedPart>,
+    },
+    Part {
+        part_number: i32,
+        e_tag: String,
+    },
+}
+
+/// Identity of the copy a journal belongs to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JournalKey {
+    pub source_bucket: String,
+    pub source_key: String,
+    pub dest_bucket: String,
+    pub dest_key: String,
+}
+
+/// State rebuilt by replaying a journal file.
+#[derive(Clone, Debug, Default)]
+pub struct JournalState {
+    pub source_etag: String,
+    pub content_length: i64,
+    pub upload_id: String,
+    pub plan: BTreeMap<i32, PlannedPart>,
+    pub completed: BTreeMap<i32, String>,
+}
+
+/// Append-only journal of a multipart upload, shared by all part tasks.
+#[derive(Clone)]
+pub struct UploadJournal {
+    path: PathBuf,
+    file: Arc<Mutex<File>>,
+}
+
+impl JournalKey {
+    /// Stable journal file path for this source/destination pair.
+    pub fn path_in(&self, dir: &Path) -> PathBuf {
+        let identity = format!(
+            "{}/{}\n{}/{}",
+            self.source_bucket, self.source_key, self.dest_bucket, self.dest_key
+        );
+        dir.join(format!("{:016x}.journal", fnv1a64(identity.as_bytes())))
+    }
+}
+
+fn fnv1a64(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for b in bytes {
+        hash ^= u64::from(*b);
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+/// Replay a journal file. Returns `None` if the file does not exist.
+pub fn load_journal(path: &Path, key: &JournalKey) -> Result<Option<JournalState>> {
+    let file = match File::open(path) {
+        Ok(f) => f,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => {
+            return Err(anyhow::anyhow!(e))
+                .with_context(|| format!("Failed to open journal {}", path.display()));
+        }
+    };
+
+    let mut state: Option<JournalState> = None;
+    for line in BufReader::new(file).lines() {
+        let line = line.with_context(|| format!("Failed to read journal {}", path.display()))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        // A torn trailing line from a crash mid-write is ignored.
+        let Ok(record) = serde_json::from_str::<JournalRecord>(&line) else {
+            continue;
+        };
+        match record {
+            JournalRecord::Header {
+                version,
+                source_bucket,
+                source_key,
+                dest_bucket,
+                dest_key,
+                source_etag,
+                content_length,
+                upload_id,
+            } => {
+                if version != JOURNAL_VERSION {
+                    anyhow::bail!(
+                        "Unsupported journal version {} in {}",
+                        version,
+                        path.display()
+                    );
+                }
+                let found = JournalKey {
+                    source_bucket,
+                    source_key,
+                    dest_bucket,
+                    dest_key,
+                };
+                if &found != key {
+                    anyhow::bail!(
+                        "Journal {} belongs to a different copy (s3://{}/{} -> s3://{}/{})",
+                        path.display(),
+                        found.source_bucket,
+                        found.source_key,
+                        found.dest_bucket,
+                        found.dest_key
+                    );
+                }
+                state = Some(JournalState {
+                    source_etag,
+                    content_length,
+                    upload_id,
+                    ..Default::default()
+                });
+            }
+            JournalRecord::Plan { parts } => {
+                if let Some(s) = state.as_mut() {
+                    for p in parts {
+                        s.plan.insert(p.part_number, p);
+                    }
+                }
+            }
+            JournalRecord::Part { part_number, e_tag } => {
+                if let Some(s) = state.as_mut() {
+                    s.completed.insert(part_number, e_tag);
+                }
+            }
+        }
+    }
+
+    Ok(state)
+}
+
+/// Marker file written for every multipart upload this tool initiates, with or without
+/// `--resume`, and removed once the upload is completed or aborted.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UploadMarker {
+    pub dest_bucket: String,
+    pub dest_key: String,
+    pub upload_id: String,
+}
+
+impl UploadMarker {
+    /// Marker file path for this upload.
+    pub fn path_in(&self, dir: &Path) -> PathBuf {
+        let identity = format!("{}/{}\n{}", self.dest_bucket, self.dest_key, self.upload_id);
+        dir.join(format!("{:016x}.upload", fnv1a64(identity.as_bytes())))
+    }
+
+    pub fn write(&self, dir: &Path) -> Result<()> {
+        fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create journal directory {}", dir.display()))?;
+        let path = self.path_in(dir);
+        let json = serde_json::to_string(self).context("Failed to encode upload marker")?;
+        fs::write(&path, json)
+            .with_context(|| format!("Failed to write upload marker {}", path.display()))
+    }
+
+    pub fn remove(&self, dir: &Path) -> Result<()> {
+        let path = self.path_in(dir);
+        match fs::remove_file(&path) {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(anyhow::anyhow!(e))
+                .with_context(|| format!("Failed to remove upload marker {}", path.display())),
+        }
+    }
+}
+
+/// Multipart upload named by an upload marker or in the header of a journal file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TrackedUpload {
+    pub path: PathBuf,
+    pub dest_bucket: String,
+    pub dest_key: String,
+    pub upload_id: String,
+}
+
+/// Uploads referenced by the upload markers and journals in `dir`; an upload with both
+/// is listed once per file. A missing directory holds none; unreadable files are skipped.
+pub fn tracked_uploads(dir: &Path) -> Result<Vec<TrackedUpload>> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => {
+            return Err(anyhow::anyhow!(e))
+                .with_context(|| format!("Failed to read journal directory {}", dir.display()));
+        }
+    };
+
+    let mut uploads = Vec::new();
+    for entry in entries {
+        let path = entry
+            .with_context(|| format!("Failed to read journal directory {}", dir.display()))?
+            .path();
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("journal") => {}
+            Some("upload") => {
+                let marker = fs::read_to_string(&path)
+                    .ok()
+                    .and_then(|json| serde_json::from_str::<UploadMarker>(&json).ok());
+                if let Some(marker) = marker {
+                    uploads.push(TrackedUpload {
+                        path,
+                        dest_bucket: marker.dest_bucket,
+                        dest_key: marker.dest_key,
+                        upload_id: marker.upload_id,
+                    });
+                }
+                continue;
+            }
+            _ => continue,
+        }
+        let Ok(file) = File::open(&path) else {
+            continue;
+        };
+        let mut header = String::new();
+        if BufReader::new(file).read_line(&mut header).is_err() {
+            continue;
+        }
+        if let Ok(JournalRecord::Header {
+            dest_bucket,
+            dest_key,
+            upload_id,
+            ..
+        }) = serde_json::from_str(&header)
+        {
+            uploads.push(TrackedUpload {
+                path,
+                dest_bucket,
+                dest_key,
+                upload_id,
+            });
+        }
+    }
+    uploads.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(uploads)
+}
+
+impl UploadJournal {
+    /// Start a fresh journal for a newly initiated upload, replacing any previous file.
+    pub fn create(
+        path: &Path,
+        key: &JournalKey,
+        source_etag: &str,
+        content_length: i64,
+        upload_id: &str,
+    ) -> Result<Self> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create journal directory {}", dir.display()))?;
+        }
+        let file = File::create(path)
+            .with_context(|| format!("Failed to create journal {}", path.display()))?;
+        let journal = Self {
+            path: path.to_path_buf(),
+            file: Arc::new(Mutex::new(file)),
+        };
+        journal.append(&JournalRecord::Header {
+            version: JOURNAL_VERSION,
+            source_bucket: key.source_bucket.clone(),
+            source_key: key.source_key.clone(),
+            dest_bucket: key.dest_bucket.clone(),
+            dest_key: key.dest_key.clone(),
+            source_etag: source_etag.to_string(),
+            content_length,
+            upload_id: upload_id.to_string(),
+        })?;
+        Ok(journal)
+    }
+
+    /// Reopen an existing journal to keep appending to it.
+    pub fn open(path: &Path) -> Result<Self> {
+        let file = OpenOptions::new()
+            .append(true)
+            .open(path)
+            .with_context(|| format!("Failed to open journal {}", path.display()))?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            file: Arc::new(Mutex::new(file)),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn record_plan(&self, parts: &[PlannedPart]) -> Result<()> {
+        if parts.is_empty() {
+            return Ok(());
+        }
+        self.append(&JournalRecord::Plan {
+            parts: parts.to_vec(),
+        })
+    }
+
+    pub fn record_part(&self, part_number: i32, e_tag: &str) -> Result<()> {
+        self.append(&JournalRecord::Part {
+            part_number,
+            e_tag: e_tag.to_string(),
+        })
+    }
+
+    /// Delete the journal once the upload has completed or been discarded.
+    pub fn remove(&self) -> Result<()> {
+        match fs::remove_file(&self.path) {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(anyhow::anyhow!(e))
+                .with_context(|| format!("Failed to remove journal {}", self.path.display())),
+        }
+    }
+
+    fn append(&self, record: &JournalRecord) -> Result<()> {
+        let mut line = serde_json::to_string(record).context("Failed to encode journal record")?;
+        line.push('\n');
+        let mut file = self
+            .file
+            .lock()
+            .map_err(|_| anyhow::anyhow!("Journal lock poisoned"))?;
+        file.write_all(line.as_bytes())
+            .and_then(|_| file.flush())
+            .with_context(|| format!("Failed to write journal {}", self.path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_key() -> JournalKey {
+        JournalKey {
+            source_bucket: "src".to_string(),
+            source_key: "data/big.bin".to_string(),
+            dest_bucket: "dst".to_string(),
+            dest_key: "copy/big.bin".to_string(),
+        }
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "s3_largecopy-journal-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    /// Ensures journal paths are deterministic and differ per destination.
+    #[test]
+    fn path_is_stable_per_copy_pair() {
+        let dir = Path::new("/tmp/j");
+        let key = test_key();
+        let mut other = test_key();
+        other.dest_key = "copy/other.bin".to_string();
+
+        assert_eq!(key.path_in(dir), key.path_in(dir));
+        assert_ne!(key.path_in(dir), other.path_in(dir));
+    }
+
+    /// Verifies a written journal replays its header, plan and completed parts.
+    #[test]
+    fn journal_round_trip_replays_state() {
+        let dir = temp_dir("roundtrip");
+        let key = test_key();
+        let path = key.path_in(&dir);
+
+        let journal = UploadJournal::create(&path, &key, "\"etag\"", 300, "upload-1").unwrap();
+        journal
+            .record_plan(&[
+                PlannedPart {
+                    part_number: 1,
+                    start: 0,
+                    end: 99,
+                },
+                PlannedPart {
+                    part_number: 2,
+                    start: 100,
+                    end: 299,
+                },
+            ])
+            .unwrap();
+        journal.record_part(2, "\"p2\"").unwrap();
+
+        let reopened = UploadJournal::open(&path).unwrap();
+        reopened.record_part(1, "\"p1\"").unwrap();
+
+        let state = load_journal(&path, &key).unwrap().expect("journal exists");
+        assert_eq!(state.upload_id, "upload-1");
+        assert_eq!(state.source_etag, "\"etag\"");
+        assert_eq!(state.content_length, 300);
+        assert_eq!(state.plan.len(), 2);
+        assert_eq!(state.plan[&2].len(), 200);
+        assert_eq!(state.completed[&1], "\"p1\"");
+
+        journal.remove().unwrap();
+        assert!(load_journal(&path, &key).unwrap().is_none());
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    /// Confirms a journal written for another copy pair is rejected.
+    #[test]
+    fn load_rejects_foreign_journal() {
+        let dir = temp_dir("foreign");
+        let key = test_key();
+        let path = key.path_in(&dir);
+        UploadJournal::create(&path, &key, "\"etag\"", 1, "upload-1").unwrap();
+
+        let mut other = test_key();
+        other.source_key = "data/other.bin".to_string();
+        assert!(load_journal(&path, &other).is_err());
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    /// Ensures uploads are discovered from markers and journal headers, and stray files
+    /// are ignored.
+    #[test]
+    fn tracked_uploads_lists_markers_and_headers() {
+        let dir = temp_dir("listing");
+        assert!(tracked_uploads(&dir).unwrap().is_empty());
+
+        let key = test_key();
+        UploadJournal::create(&key.path_in(&dir), &key, "\"etag\"", 300, "upload-1").unwrap();
+        let marker = UploadMarker {
+            dest_bucket: "dst".to_string(),
+            dest_key: "copy/small.bin".to_string(),
+            upload_id: "upload-2".to_string(),
+        };
+        marker.write(&dir).unwrap();
+        fs::write(dir.join("notes.txt"), "not a journal").unwrap();
+        fs::write(dir.join("broken.journal"), "{").unwrap();
+        fs::write(dir.join("broken.upload"), "{").unwrap();
+
+        let mut uploads = tracked_uploads(&dir).unwrap();
+        uploads.sort_by(|a, b| a.upload_id.cmp(&b.upload_id));
+        assert_eq!(
+            uploads,
+            vec![
+                TrackedUpload {
+                    path: key.path_in(&dir),
+                    dest_bucket: "dst".to_string(),
+                    dest_key: "copy/big.bin".to_string(),
+                    upload_id: "upload-1".to_string(),
+                },
+                TrackedUpload {
+                    path: marker.path_in(&dir),
+                    dest_bucket: "dst".to_string(),
+                    dest_key: "copy/small.bin".to_string(),
+                    upload_id: "upload-2".to_string(),
+                },
+            ]
+        );
+
+        marker.remove(&dir).unwrap();
+        marker.remove(&dir).unwrap();
+        assert_eq!(tracked_uploads(&dir).unwrap().len(), 1);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
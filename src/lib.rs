@@ -0,0 +1,45 @@
+//! Copy engine of the `s3_largecopy` command line: multipart server-side or streamed
+//! copies of large S3 objects and prefixes, with auto-tuning, verification, resume
+//! journals, rate limits and cost estimates.
+//!
+//! [`CopyRequest`] is the entry point. It is built with the command line's defaults,
+//! runs with `copy` or `sync`, and reports progress through a callback instead of the
+//! console:
+//!
+//! ```no_run
+//! # async fn example() -> anyhow::Result<()> {
+//! let summary = s3_largecopy::CopyRequest::from_uris("s3://src/logs/", "s3://dst/logs/")?
+//!     .object_concurrency(8)
+//!     .include(vec!["*.gz".to_string()])
+//!     .on_progress(|event| eprintln!("{:?}", event))
+//!     .sync()
+//!     .await?;
+//! println!("{} copied, {} skipped", summary.copied, summary.skipped);
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod app;
+pub mod auto;
+pub mod cleanup;
+pub mod control;
+pub mod estimate;
+pub mod events;
+mod inventory;
+mod journal;
+pub mod manifest;
+mod progress;
+pub mod report;
+pub mod request;
+pub mod s3_utils;
+pub mod throttle;
+pub mod uri;
+mod verify;
+
+pub use app::{CopyOutcome, DestinationGuard, ObjectsFailed, S3CopyApp};
+pub use auto::{AutoProfile, DataPath, OnInterrupt, OnSourceChange, VerifyIntegrity};
+pub use events::{CopyAction, DataRoute, ProgressEvent, RunSummary, Verification};
+pub use journal::DEFAULT_JOURNAL_DIR;
+pub use request::CopyRequest;
+pub use s3_utils::{ClientConfig, ClientPool};
+pub use uri::S3Uri;
@@ -1,165 +1,851 @@
 use anyhow::Result;
 use clap::Parser;
 
-mod app;
 mod args;
-mod auto;
-mod estimate;
-mod progress;
-mod s3_utils;
+mod job;
+mod render;
 
-use app::S3CopyApp;
 use args::{
-    Args, DEFAULT_CONCURRENCY, DEFAULT_PART_SIZE_MB, MAX_CONCURRENT_PARTS, MAX_PART_SIZE_MB,
-    MIN_PART_SIZE_MB,
+    Args, CleanupArgs, Command, ConnectionArgs, CopyArgs, DEFAULT_CONCURRENCY,
+    DEFAULT_MAX_BUFFER_MB, DEFAULT_OBJECT_CONCURRENCY, DEFAULT_PART_SIZE_MB, EstimateArgs, JobArgs,
+    ObjectArgs, PriceArgs, RunArgs, SyncArgs, TransferArgs, VerifyArgs,
 };
-use auto::{AutoProfile, VerifyIntegrity};
+use job::JobTask;
+use s3_largecopy::app::{DestinationGuard, S3CopyApp};
+use s3_largecopy::cleanup::{CleanupAction, CleanupOptions, CleanupReport};
+use s3_largecopy::control::{Interrupted, TransferControl};
+use s3_largecopy::estimate::{self, CostEstimate};
+use s3_largecopy::events::EventSink;
+use s3_largecopy::report::{ReportFormat, RunReport};
+use s3_largecopy::s3_utils::{self, ClientPool};
+use s3_largecopy::throttle::Throttle;
+use s3_largecopy::uri::CopyScope;
+use s3_largecopy::{CopyRequest, DEFAULT_JOURNAL_DIR, cleanup, manifest};
 use s3_pricing::s3_pricing_client::S3PricingClient;
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let args = Args::parse();
-    run(args).await
+    match run(args).await {
+        // Conventional exit status for a run stopped by SIGINT.
+        Err(e) if e.is::<Interrupted>() => std::process::exit(130),
+        result => result,
+    }
 }
 
-async fn run(args: Args) -> Result<()> {
-    let part_size_mb = args.part_size.unwrap_or(DEFAULT_PART_SIZE_MB);
-    let concurrency = args.concurrency.unwrap_or(DEFAULT_CONCURRENCY);
-    let auto_profile = args.auto_profile.unwrap_or(AutoProfile::Balanced);
-    let verify_integrity = args.verify_integrity.unwrap_or(VerifyIntegrity::Etag);
-    let prefix_mode = args.source_prefix.is_some() || args.dest_prefix.is_some();
+async fn run(mut args: Args) -> Result<()> {
+    let command = match args.command.take() {
+        Some(command) => command,
+        None if args.source.is_some() || args.job.is_some() => args.into_command()?,
+        None => {
+            let command = args.into_command()?;
+            eprintln!(
+                "⚠️  Running without a subcommand is deprecated and will be removed in a future release; use `s3_largecopy {}` with the same flags instead.",
+                command.name()
+            );
+            command
+        }
+    };
 
-    if args.get_price {
-        let region = args
-            .region
-            .clone()
-            .unwrap_or_else(|| "us-east-1".to_string());
-        let storage_class = args
-            .storage_class
-            .clone()
-            .unwrap_or_else(|| "STANDARD".to_string());
-        let pricing = S3PricingClient::new(args.profile.as_deref()).await?;
-        return pricing
-            .display_pricing(&region, &storage_class, args.dest_region.as_ref())
-            .await;
+    match command {
+        Command::Copy(args) => run_copy(args).await,
+        Command::Sync(args) => run_sync(args).await,
+        Command::Estimate(args) => run_estimate(args).await,
+        Command::Price(args) => run_price(&args).await,
+        Command::Verify(args) => run_verify(args).await,
+        Command::Cleanup(args) => run_cleanup(&args).await,
+        Command::Job(args) => run_job(args).await,
     }
+}
 
-    let source_bucket = args
-        .source_bucket
-        .clone()
-        .ok_or_else(|| anyhow::anyhow!("--source-bucket is required"))?;
-    let dest_bucket = args
-        .dest_bucket
-        .clone()
-        .ok_or_else(|| anyhow::anyhow!("--dest-bucket is required"))?;
+/// Objects an app is built for, with the settings only `copy` or `sync` have.
+#[derive(Default)]
+struct CopyTarget {
+    source_bucket: String,
+    source_key: String,
+    dest_bucket: String,
+    dest_key: String,
+    source_version_id: Option<String>,
+    if_dest_etag: Option<String>,
+    include: Vec<String>,
+    exclude: Vec<String>,
+    object_concurrency: Option<usize>,
+    delete: bool,
+    max_delete: Option<usize>,
+}
 
-    if prefix_mode && (args.source_prefix.is_none() || args.dest_prefix.is_none()) {
-        anyhow::bail!("Both --source-prefix and --dest-prefix are required for directory mode");
-    }
+async fn run_copy(mut args: CopyArgs) -> Result<()> {
+    args.resolve_uris()?;
+    let context = RunContext::open(&args.run, &args.transfer)?;
+    let target = CopyTarget {
+        source_bucket: args.source_bucket.unwrap_or_default(),
+        source_key: args.source_key.unwrap_or_default(),
+        dest_bucket: args.dest_bucket.unwrap_or_default(),
+        dest_key: args.dest_key.unwrap_or_default(),
+        source_version_id: args.source_version_id,
+        if_dest_etag: args.if_dest_etag,
+        ..CopyTarget::default()
+    };
+    let app = build_app(
+        target,
+        &args.connection,
+        &args.object,
+        &args.transfer,
+        &args.run,
+        &context,
+    )
+    .await?;
 
-    if !prefix_mode && (args.source_key.is_none() || args.dest_key.is_none()) {
-        anyhow::bail!(
-            "--source-key and --dest-key are required for single object copy. Use --source-prefix/--dest-prefix for directory mode."
-        );
+    app.copy_single().await.map_err(|e| {
+        eprintln!("\n❌ Error: {}", e);
+        e
+    })?;
+    print_completion(&app, context.report.as_ref());
+    Ok(())
+}
+
+async fn run_sync(mut args: SyncArgs) -> Result<()> {
+    args.validate()?;
+    let source_bucket = args.source_bucket.clone().unwrap_or_default();
+    let dest_bucket = args.dest_bucket.clone().unwrap_or_default();
+    let manifest = args
+        .from_manifest
+        .as_deref()
+        .map(|path| manifest::load_manifest(std::path::Path::new(path)))
+        .transpose()?;
+
+    let context = RunContext::open(&args.run, &args.transfer)?;
+    let target = CopyTarget {
+        source_bucket: source_bucket.clone(),
+        dest_bucket: dest_bucket.clone(),
+        include: args.include.clone(),
+        exclude: args.exclude.clone(),
+        object_concurrency: args.object_concurrency,
+        delete: args.delete,
+        max_delete: args.max_delete,
+        ..CopyTarget::default()
+    };
+    let app = build_app(
+        target,
+        &args.connection,
+        &args.object,
+        &args.transfer,
+        &args.run,
+        &context,
+    )
+    .await?;
+    let source_prefix = args.source_prefix.unwrap_or_default();
+    let dest_prefix = args.dest_prefix.unwrap_or_default();
+
+    let copied = if let Some(entries) = manifest {
+        if !app.quiet {
+            println!("\n=== S3 Manifest Copy ===");
+            println!(
+                "Manifest:    {} ({} objects)",
+                args.from_manifest.as_deref().unwrap_or_default(),
+                entries.len()
+            );
+            println!("Source:      s3://{}", source_bucket);
+            println!("Destination: s3://{}", dest_bucket);
+        }
+        app.copy_from_manifest(entries, &source_prefix, &dest_prefix)
+            .await
+    } else {
+        if !app.quiet {
+            println!("\n=== S3 Directory Copy ===");
+            println!("Source prefix: s3://{}/{}", source_bucket, source_prefix);
+            println!("Destination:   s3://{}/{}", dest_bucket, dest_prefix);
+        }
+        match args.inventory_manifest.as_deref() {
+            None if args.all_versions => {
+                if !app.quiet {
+                    println!("Versions:      all (oldest first)");
+                }
+                app.copy_all_versions_from_prefix(&source_prefix, &dest_prefix)
+                    .await
+            }
+            Some(manifest_uri) => {
+                if !app.quiet {
+                    println!("Inventory:     {}", manifest_uri);
+                }
+                app.copy_from_inventory(manifest_uri, &source_prefix, &dest_prefix)
+                    .await
+            }
+            None => app.copy_from_prefix(&source_prefix, &dest_prefix).await,
+        }
+    };
+    copied.map_err(|e| {
+        eprintln!("\n❌ Error: {}", e);
+        e
+    })?;
+    print_completion(&app, context.report.as_ref());
+    Ok(())
+}
+
+async fn run_job(args: JobArgs) -> Result<()> {
+    let tasks = job::load_job(std::path::Path::new(&args.file))?;
+    if args.estimate {
+        return estimate_job(&args, &tasks).await;
     }
 
-    let source_key = args.source_key.clone().unwrap_or_default();
-    let dest_key = args.dest_key.clone().unwrap_or_default();
+    let mut context = RunContext::open(&args.run, &args.transfer)?;
+    context.report = context.report.take().map(RunReport::combining);
+    let quiet = args.run.is_quiet();
+    let mut failed = Vec::new();
+    for (index, task) in tasks.iter().enumerate() {
+        if !quiet {
+            println!(
+                "\n=== Task {}/{}: {} ===",
+                index + 1,
+                tasks.len(),
+                task.name
+            );
+            println!("{} -> {}", task.source, task.dest);
+        }
+        match run_job_task(task, &args, &context).await {
+            Ok(()) => {}
+            Err(e) if e.is::<Interrupted>() => {
+                if let Some(report) = &context.report {
+                    report.finish_combined()?;
+                }
+                return Err(e);
+            }
+            Err(e) => {
+                eprintln!("\n❌ Task {} failed: {}", task.name, e);
+                failed.push(task.name.as_str());
+            }
+        }
+    }
 
-    if !(MIN_PART_SIZE_MB..=MAX_PART_SIZE_MB).contains(&part_size_mb) {
+    let summary = context
+        .report
+        .as_ref()
+        .map(RunReport::finish_combined)
+        .transpose()?;
+    if !quiet {
+        println!("\n=== Job Summary ===");
+        println!("Tasks:   {} ({} failed)", tasks.len(), failed.len());
+        if let Some(summary) = &summary {
+            println!(
+                "Objects: {} copied, {} skipped, {} failed ({:.2} GiB)",
+                summary.copied,
+                summary.skipped,
+                summary.failed,
+                summary.bytes_copied as f64 / (1024.0 * 1024.0 * 1024.0)
+            );
+        }
+        if let Some(report) = &context.report {
+            println!("\n📝 Report written to {}", report.path().display());
+        }
+    }
+    if !failed.is_empty() {
         anyhow::bail!(
-            "Part size must be between {} and {} MB",
-            MIN_PART_SIZE_MB,
-            MAX_PART_SIZE_MB
+            "{} of {} tasks failed: {}",
+            failed.len(),
+            tasks.len(),
+            failed.join(", ")
         );
     }
-    if concurrency == 0 || concurrency > MAX_CONCURRENT_PARTS {
-        anyhow::bail!("Concurrency must be between 1 and {}", MAX_CONCURRENT_PARTS);
+    if !quiet {
+        println!("\n🎉 Job completed successfully!");
     }
+    Ok(())
+}
 
-    let mut config_loader = aws_config::defaults(aws_config::BehaviorVersion::latest());
-    if let Some(p) = &args.profile {
-        config_loader = config_loader.profile_name(p);
+/// Copy one task of a job with the job's shared context.
+async fn run_job_task(task: &JobTask, args: &JobArgs, context: &RunContext) -> Result<()> {
+    let object = task.object_args(&args.object);
+    let transfer = task.transfer_args(&args.transfer);
+    let mut target = CopyTarget {
+        source_bucket: task.source.bucket.clone(),
+        dest_bucket: task.dest.bucket.clone(),
+        include: task.include.clone(),
+        exclude: task.exclude.clone(),
+        object_concurrency: task.object_concurrency,
+        delete: task.delete,
+        max_delete: task.max_delete,
+        ..CopyTarget::default()
+    };
+    match &task.scope {
+        CopyScope::Object {
+            source_key,
+            source_version_id,
+            dest_key,
+        } => {
+            target.source_key = source_key.clone();
+            target.source_version_id = source_version_id.clone();
+            target.dest_key = dest_key.clone();
+            let app = build_app(
+                target,
+                &args.connection,
+                &object,
+                &transfer,
+                &args.run,
+                context,
+            )
+            .await?;
+            app.copy_single().await?;
+        }
+        CopyScope::Prefix {
+            source_prefix,
+            dest_prefix,
+        } => {
+            let app = build_app(
+                target,
+                &args.connection,
+                &object,
+                &transfer,
+                &args.run,
+                context,
+            )
+            .await?;
+            if task.all_versions {
+                app.copy_all_versions_from_prefix(source_prefix, dest_prefix)
+                    .await?;
+            } else {
+                app.copy_from_prefix(source_prefix, dest_prefix).await?;
+            }
+        }
     }
-    let detection_client = aws_sdk_s3::Client::new(&config_loader.load().await);
+    Ok(())
+}
 
-    let source_region =
-        s3_utils::get_bucket_region(&detection_client, &source_bucket, args.region.as_ref())
-            .await?;
-    let dest_region = s3_utils::get_bucket_region(
-        &detection_client,
-        &dest_bucket,
-        args.dest_region.as_ref().or(args.region.as_ref()),
+/// Estimate every task of a job, then print the combined cost.
+async fn estimate_job(args: &JobArgs, tasks: &[JobTask]) -> Result<()> {
+    let clients = ClientPool::default();
+    let mut estimates = Vec::new();
+    for (index, task) in tasks.iter().enumerate() {
+        println!(
+            "\n=== Task {}/{}: {} ===",
+            index + 1,
+            tasks.len(),
+            task.name
+        );
+        let (source_key, source_prefix, dest_key, dest_prefix) = match &task.scope {
+            CopyScope::Object {
+                source_key,
+                dest_key,
+                ..
+            } => (Some(source_key.clone()), None, Some(dest_key.clone()), None),
+            CopyScope::Prefix {
+                source_prefix,
+                dest_prefix,
+            } => (
+                None,
+                Some(source_prefix.clone()),
+                None,
+                Some(dest_prefix.clone()),
+            ),
+        };
+        let estimate_args = EstimateArgs {
+            source_bucket: task.source.bucket.clone(),
+            source_key,
+            source_prefix,
+            dest_bucket: task.dest.bucket.clone(),
+            dest_key,
+            dest_prefix,
+            include: task.include.clone(),
+            exclude: task.exclude.clone(),
+            inventory_manifest: None,
+            connection: args.connection.clone(),
+            object: task.object_args(&args.object),
+            transfer: task.transfer_args(&args.transfer),
+        };
+        let (source_region, dest_region) = detect_regions(
+            &args.connection,
+            &estimate_args.source_bucket,
+            &estimate_args.dest_bucket,
+            &clients,
+        )
+        .await?;
+        let estimate =
+            estimate_copy(&estimate_args, &source_region, &dest_region, &clients).await?;
+        estimates.push((task.name.clone(), estimate));
+    }
+    println!("{}", estimate::format_job_estimate(&estimates));
+    Ok(())
+}
+
+async fn run_estimate(args: EstimateArgs) -> Result<()> {
+    let clients = ClientPool::default();
+    let (source_region, dest_region) = detect_regions(
+        &args.connection,
+        &args.source_bucket,
+        &args.dest_bucket,
+        &clients,
     )
     .await?;
+    estimate_copy(&args, &source_region, &dest_region, &clients).await?;
+    Ok(())
+}
 
-    if args.estimate {
-        return estimate::run_estimate(
-            &args,
-            &source_region,
-            &dest_region,
-            part_size_mb,
-            concurrency,
-            auto_profile,
-            verify_integrity,
-        )
-        .await;
-    }
+/// Print the cost estimate of a `copy` or `sync` and return it.
+async fn estimate_copy(
+    args: &EstimateArgs,
+    source_region: &str,
+    dest_region: &str,
+    clients: &ClientPool,
+) -> Result<CostEstimate> {
+    let part_size_mb = args.transfer.part_size.unwrap_or(DEFAULT_PART_SIZE_MB);
+    let auto_profile = args.transfer.auto_profile.unwrap_or_default();
+    let verify_integrity = args.transfer.verify_integrity.unwrap_or_default();
 
-    let app = S3CopyApp::new(
-        source_bucket.clone(),
-        source_key.clone(),
-        dest_bucket.clone(),
-        dest_key.clone(),
-        args.region.clone().or_else(|| Some(dest_region.clone())),
-        Some(source_region),
-        args.profile.clone(),
+    // App for size info only: quiet, dry run, and no journals.
+    let app = CopyRequest::new(
+        args.source_bucket.clone(),
+        args.source_key.clone().unwrap_or_default(),
+        args.dest_bucket.clone(),
+        args.dest_key.clone().unwrap_or_default(),
+    )
+    .dest_config(
+        args.connection.dest_client_config(
+            args.connection
+                .dest_region
+                .clone()
+                .or(args.connection.region.clone())
+                .or_else(|| Some(dest_region.to_string())),
+        ),
+    )
+    .source_config(
+        args.connection
+            .source_client_config(Some(source_region.to_string())),
+    )
+    .part_size_mb(part_size_mb)
+    .concurrency(args.transfer.concurrency.unwrap_or(DEFAULT_CONCURRENCY))
+    .set_storage_class(args.object.storage_class.clone())
+    .full_control(args.object.full_control)
+    .auto(args.transfer.auto)
+    .auto_profile(auto_profile)
+    .no_metadata(args.object.no_metadata)
+    .no_tags(args.object.no_tags)
+    .no_storage_class(args.object.no_storage_class)
+    .no_acl(args.object.no_acl)
+    .dry_run(true)
+    .force_copy(args.transfer.force_copy)
+    .verify_integrity(verify_integrity)
+    .set_checksum_algorithm(args.object.checksum_algorithm.clone())
+    .set_sse(args.object.sse.clone())
+    .set_sse_kms_key_id(args.object.sse_kms_key_id.clone())
+    .include(args.include.clone())
+    .exclude(args.exclude.clone())
+    .data_path(args.transfer.data_path.unwrap_or_default())
+    .on_source_change(args.transfer.on_source_change.unwrap_or_default())
+    .match_source_parts(args.object.match_source_parts)
+    .clients(clients.clone())
+    .build()
+    .await?;
+
+    // Object count and total size of a prefix, or the size of the single object
+    let prefix_totals = match &args.source_prefix {
+        Some(source_prefix) => Some(
+            app.get_prefix_totals(source_prefix, args.inventory_manifest.as_deref())
+                .await?,
+        ),
+        None => None,
+    };
+    let file_size = match prefix_totals {
+        Some((_, bytes)) => bytes,
+        None => app.get_source_size().await?,
+    };
+
+    // Attempt to load pricing client for accurate estimates, but fallback to static if it fails
+    let pricing = S3PricingClient::new(args.connection.profile.as_deref())
+        .await
+        .ok();
+
+    let est = estimate::estimate_cost(
+        file_size,
         part_size_mb * 1024 * 1024,
-        concurrency,
-        args.storage_class.clone(),
-        args.full_control,
-        args.auto,
+        args.transfer.auto,
         auto_profile,
-        args.no_metadata,
-        args.no_tags,
-        args.no_storage_class,
-        args.no_acl,
-        args.quiet,
-        args.dry_run,
-        args.force_copy,
+        source_region,
+        Some(dest_region),
+        args.object.storage_class.as_deref(),
+        args.object.no_tags,
         verify_integrity,
-        args.checksum_algorithm.clone(),
-        args.sse.clone(),
-        args.sse_kms_key_id.clone(),
-        args.include.clone(),
-        args.exclude.clone(),
+        pricing.as_ref(),
+    )
+    .await;
+
+    // Add object count to output if directory mode
+    if let (Some(source_prefix), Some((object_count, _))) = (&args.source_prefix, prefix_totals) {
+        let dest_prefix_display = args
+            .dest_prefix
+            .clone()
+            .unwrap_or_else(|| args.dest_key.clone().unwrap_or_default());
+        println!("\n=== S3 Directory Copy Estimate ===");
+        println!(
+            "Source prefix: s3://{}/{}",
+            args.source_bucket.clone(),
+            source_prefix
+        );
+        println!(
+            "Destination:   s3://{}/{}",
+            args.dest_bucket.clone(),
+            dest_prefix_display
+        );
+        println!();
+
+        println!("Objects found: {}", object_count);
+        println!(
+            "Total size:    {} bytes ({:.2} GB)",
+            file_size,
+            file_size as f64 / (1024.0 * 1024.0 * 1024.0)
+        );
+        if args.inventory_manifest.is_some() {
+            println!("Listing:       S3 Inventory (no LIST requests during the copy)");
+        } else {
+            let list_requests = estimate::list_request_count(object_count);
+            println!(
+                "Listing:       {} ListObjectsV2 requests (${:.4})",
+                list_requests,
+                list_requests as f64 / 1000.0
+                    * estimate::get_region_pricing(source_region).put_per_1k
+            );
+        }
+        println!();
+    }
+
+    println!("{}", estimate::format_estimate(&est));
+    Ok(est)
+}
+
+async fn run_price(args: &PriceArgs) -> Result<()> {
+    let pricing = S3PricingClient::new(args.profile.as_deref()).await?;
+    pricing
+        .display_pricing(&args.region, &args.storage_class, args.dest_region.as_ref())
+        .await
+}
+
+async fn run_verify(args: VerifyArgs) -> Result<()> {
+    let target = CopyTarget {
+        source_bucket: args.source_bucket,
+        source_key: args.source_key,
+        dest_bucket: args.dest_bucket,
+        dest_key: args.dest_key,
+        source_version_id: args.source_version_id,
+        ..CopyTarget::default()
+    };
+    let object = ObjectArgs {
+        checksum_algorithm: args.checksum_algorithm,
+        ..ObjectArgs::default()
+    };
+    let transfer = TransferArgs {
+        concurrency: args.concurrency,
+        verify_integrity: Some(args.mode),
+        ..TransferArgs::default()
+    };
+    let run = RunArgs {
+        quiet: args.quiet,
+        ..RunArgs::default()
+    };
+    let app = build_app(
+        target,
+        &args.connection,
+        &object,
+        &transfer,
+        &run,
+        &RunContext {
+            progress_sink: Some(render::console_sink(args.quiet, None)),
+            ..RunContext::default()
+        },
     )
     .await?;
 
-    // Check if directory mode is enabled (source_prefix provided)
-    if let Some(ref source_prefix) = args.source_prefix {
-        let dest_prefix = args.dest_prefix.clone().unwrap_or_default();
-        println!("\n=== S3 Directory Copy ===");
-        let sb = source_bucket.clone();
-        let db = dest_bucket.clone();
-        println!("Source prefix: s3://{}/{}", sb, source_prefix);
-        println!("Destination:   s3://{}/{}", db, dest_prefix);
+    app.verify_existing().await.map_err(|e| {
+        eprintln!("\n❌ Error: {}", e);
+        e
+    })
+}
 
-        app.copy_from_prefix(source_prefix, &dest_prefix)
-            .await
-            .map_err(|e| {
-                eprintln!("\n❌ Error: {}", e);
-                e
-            })?;
+/// Detect the source and destination bucket regions, falling back to `--region`
+/// (and `--dest-region` for the destination).
+async fn detect_regions(
+    connection: &ConnectionArgs,
+    source_bucket: &str,
+    dest_bucket: &str,
+    clients: &ClientPool,
+) -> Result<(String, String)> {
+    let source_detection_client = clients
+        .client(&connection.source_client_config(connection.region.clone()))
+        .await;
+    let dest_detection_client = clients
+        .client(&connection.dest_client_config(connection.region.clone()))
+        .await;
+
+    let source_region = s3_utils::get_bucket_region(
+        &source_detection_client,
+        source_bucket,
+        connection.region.as_ref(),
+    )
+    .await?;
+    let dest_region = s3_utils::get_bucket_region(
+        &dest_detection_client,
+        dest_bucket,
+        connection
+            .dest_region
+            .as_ref()
+            .or(connection.region.as_ref()),
+    )
+    .await?;
+    Ok((source_region, dest_region))
+}
+
+/// Transfer control of a `copy` or `sync` run, listening for pause, resume and
+/// interrupt signals.
+fn transfer_control(run: &RunArgs) -> Result<TransferControl> {
+    let control = TransferControl::new(run.window);
+    control.listen_for_signals(|notice| eprintln!("{}", notice))?;
+    if let Some(window) = run.window
+        && !run.is_quiet()
+    {
+        println!("🕙 Transfer window: {} (local time)", window);
+    }
+    Ok(control)
+}
+
+/// Outputs, limits and clients shared by every app of a run (each task of a job).
+#[derive(Default)]
+struct RunContext {
+    progress_sink: Option<EventSink>,
+    report: Option<RunReport>,
+    throttle: Throttle,
+    control: TransferControl,
+    clients: ClientPool,
+}
+
+impl RunContext {
+    /// Open the progress sink and run report, and start the transfer control.
+    fn open(run: &RunArgs, transfer: &TransferArgs) -> Result<Self> {
+        let ndjson = run
+            .progress_sink
+            .as_deref()
+            .map(EventSink::open)
+            .transpose()?;
+        let report = run
+            .report
+            .as_deref()
+            .map(|path| {
+                let path = std::path::Path::new(path);
+                RunReport::create(
+                    path,
+                    run.report_format
+                        .unwrap_or_else(|| ReportFormat::from_path(path)),
+                )
+            })
+            .transpose()?;
+        Ok(Self {
+            progress_sink: Some(render::console_sink(run.is_quiet(), ndjson)),
+            report,
+            throttle: Throttle::new(transfer.max_bandwidth, transfer.max_requests_per_second),
+            control: transfer_control(run)?,
+            clients: ClientPool::default(),
+        })
+    }
+}
+
+/// Detect bucket regions and build the app on the shared run context.
+async fn build_app(
+    target: CopyTarget,
+    connection: &ConnectionArgs,
+    object: &ObjectArgs,
+    transfer: &TransferArgs,
+    run: &RunArgs,
+    context: &RunContext,
+) -> Result<S3CopyApp> {
+    let (source_region, dest_region) = detect_regions(
+        connection,
+        &target.source_bucket,
+        &target.dest_bucket,
+        &context.clients,
+    )
+    .await?;
+
+    let dest_guard = if transfer.no_clobber {
+        Some(DestinationGuard::NoClobber)
     } else {
-        app.copy_file().await.map_err(|e| {
+        target
+            .if_dest_etag
+            .as_deref()
+            .map(DestinationGuard::if_etag)
+    };
+
+    CopyRequest::new(
+        target.source_bucket,
+        target.source_key,
+        target.dest_bucket,
+        target.dest_key,
+    )
+    .dest_config(connection.dest_client_config(connection.region.clone().or(Some(dest_region))))
+    .source_config(connection.source_client_config(Some(source_region)))
+    .part_size_mb(transfer.part_size.unwrap_or(DEFAULT_PART_SIZE_MB))
+    .concurrency(transfer.concurrency.unwrap_or(DEFAULT_CONCURRENCY))
+    .set_storage_class(object.storage_class.clone())
+    .full_control(object.full_control)
+    .auto(transfer.auto)
+    .auto_profile(transfer.auto_profile.unwrap_or_default())
+    .no_metadata(object.no_metadata)
+    .no_tags(object.no_tags)
+    .no_storage_class(object.no_storage_class)
+    .no_acl(object.no_acl)
+    .quiet(run.is_quiet())
+    .dry_run(run.dry_run)
+    .force_copy(transfer.force_copy)
+    .verify_integrity(transfer.verify_integrity.unwrap_or_default())
+    .set_checksum_algorithm(object.checksum_algorithm.clone())
+    .set_sse(object.sse.clone())
+    .set_sse_kms_key_id(object.sse_kms_key_id.clone())
+    .include(target.include)
+    .exclude(target.exclude)
+    .resume(run.resume)
+    .set_journal_dir(run.journal_dir.clone())
+    .object_concurrency(
+        target
+            .object_concurrency
+            .unwrap_or(DEFAULT_OBJECT_CONCURRENCY),
+    )
+    .delete(target.delete)
+    .set_max_delete(target.max_delete)
+    .data_path(transfer.data_path.unwrap_or_default())
+    .max_buffer_mb(transfer.max_buffer_mb.unwrap_or(DEFAULT_MAX_BUFFER_MB))
+    .set_progress_sink(context.progress_sink.clone())
+    .set_report(context.report.clone())
+    .set_source_version_id(target.source_version_id)
+    .on_source_change(transfer.on_source_change.unwrap_or_default())
+    .set_dest_guard(dest_guard)
+    .match_source_parts(object.match_source_parts)
+    .throttle(context.throttle.clone())
+    .control(context.control.clone())
+    .on_interrupt(run.on_interrupt.unwrap_or_default())
+    .clients(context.clients.clone())
+    .build()
+    .await
+}
+
+fn print_completion(app: &S3CopyApp, report: Option<&RunReport>) {
+    if !app.quiet {
+        if let Some(report) = report {
+            println!("\n📝 Report written to {}", report.path().display());
+        }
+        println!("\n🎉 Copy completed successfully!");
+    }
+}
+
+async fn run_cleanup(args: &CleanupArgs) -> Result<()> {
+    let detection_client = args
+        .client_config(args.region.clone())
+        .build_client(aws_config::defaults(aws_config::BehaviorVersion::latest()))
+        .await;
+    let region =
+        s3_utils::get_bucket_region(&detection_client, &args.bucket, args.region.as_ref()).await?;
+    let client = args
+        .client_config(Some(region.clone()))
+        .build_client(aws_config::defaults(aws_config::BehaviorVersion::latest()))
+        .await;
+
+    let options = CleanupOptions {
+        bucket: args.bucket.clone(),
+        prefix: args.prefix.clone(),
+        older_than: args.older_than,
+        include_all: args.all,
+        journal_dir: args
+            .journal_dir
+            .as_deref()
+            .unwrap_or(DEFAULT_JOURNAL_DIR)
+            .into(),
+        dry_run: args.dry_run,
+    };
+    let report = cleanup::run_cleanup(&client, &region, &options)
+        .await
+        .map_err(|e| {
             eprintln!("\n❌ Error: {}", e);
             e
         })?;
+    print_cleanup_report(&options, &region, &report);
+    if !report.failures.is_empty() {
+        anyhow::bail!("{} uploads failed to abort", report.failures.len());
     }
+    Ok(())
+}
 
-    if !app.quiet {
-        println!("\n🎉 Copy completed successfully!");
+fn print_cleanup_report(options: &CleanupOptions, region: &str, report: &CleanupReport) {
+    let gib = |bytes: i64| bytes as f64 / (1024.0 * 1024.0 * 1024.0);
+
+    println!("\n=== Multipart Upload Cleanup ===");
+    println!(
+        "Bucket:      s3://{}/{}",
+        options.bucket,
+        options.prefix.as_deref().unwrap_or_default()
+    );
+    println!(
+        "Tracked:     {} ({} uploads)",
+        options.journal_dir.display(),
+        report.tracked
+    );
+    match options.older_than {
+        Some(age) => println!(
+            "Abort:       uploads older than {}",
+            cleanup::format_age(age)
+        ),
+        None => println!("Abort:       none (report only; use --older-than to abort)"),
+    }
+    println!("================================\n");
+    if options.dry_run {
+        println!("🚨 DRY RUN MODE: No uploads will be aborted.\n");
+    }
+
+    for upload in &report.uploads {
+        let label = match upload.action {
+            CleanupAction::Abort if options.dry_run => "would abort",
+            CleanupAction::Abort => "abort",
+            CleanupAction::TooRecent => "keep (too recent)",
+            CleanupAction::Report => "found",
+        };
+        println!(
+            "  {:<17} s3://{}/{} (upload {}, {} old, {} parts, {:.2} GiB, ${:.4}/month{})",
+            label,
+            options.bucket,
+            upload.key,
+            upload.upload_id,
+            cleanup::format_age(upload.age),
+            upload.parts,
+            gib(upload.bytes),
+            upload.monthly_cost,
+            if !upload.files.is_empty() {
+                ", tracked"
+            } else {
+                ""
+            }
+        );
+    }
+    for (upload_id, error) in &report.failures {
+        eprintln!("   Failed to abort upload {}: {}", upload_id, error);
+    }
+    for (path, error) in &report.stale_files {
+        eprintln!("   Failed to remove {}: {}", path.display(), error);
+    }
+
+    println!("\n=== Summary ===");
+    println!("Uploads:      {}", report.uploads.len());
+    if report.foreign > 0 {
+        println!(
+            "Not ours:     {} (no upload marker or resume journal; use --all to include them)",
+            report.foreign
+        );
+    }
+    println!(
+        "Part bytes:   {} ({:.2} GiB)",
+        report.part_bytes,
+        gib(report.part_bytes)
+    );
+    println!(
+        "Storage cost: ${:.4}/month ({})",
+        report.monthly_cost, region
+    );
+    if options.older_than.is_some() && !options.dry_run {
+        println!(
+            "Aborted:      {} (saves ${:.4}/month)",
+            report.aborted, report.aborted_cost
+        );
     }
-    Ok(())
 }
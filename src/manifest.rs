@@ -0,0 +1,229 @@
+use crate::report::ReportEntry;
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::path::Path;
+
+/// One object to copy from a `--from-manifest` file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ManifestEntry {
+    pub source_key: String,
+    /// Explicit destination key; derived from the prefixes when absent
+    pub dest_key: Option<String>,
+    /// Object size in bytes, when the manifest records it
+    pub size: Option<i64>,
+}
+
+#[derive(Deserialize)]
+struct JsonLine {
+    #[serde(alias = "key")]
+    source_key: String,
+    #[serde(default)]
+    dest_key: Option<String>,
+    #[serde(default)]
+    size: Option<i64>,
+}
+
+#[derive(Deserialize)]
+struct JsonReport {
+    objects: Vec<ReportEntry>,
+}
+
+/// Read a manifest file. Supported inputs:
+/// - JSON lines: `{"source_key": "...", "dest_key": "...", "size": 123}` (`key` is accepted
+///   for `source_key`)
+/// - CSV: `source_key[,dest_key]`, optionally with a header row that may add a `size` column
+/// - A `--report` file (JSON or CSV), of which only the failed entries are kept
+pub fn load_manifest(path: &Path) -> Result<Vec<ManifestEntry>> {
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read manifest {}", path.display()))?;
+    parse_manifest(&text).with_context(|| format!("Invalid manifest {}", path.display()))
+}
+
+pub fn parse_manifest(text: &str) -> Result<Vec<ManifestEntry>> {
+    let trimmed = text.trim_start();
+    if trimmed.starts_with("{\"objects\"") {
+        let report: JsonReport =
+            serde_json::from_str(trimmed).context("Failed to parse JSON report")?;
+        return Ok(report
+            .objects
+            .into_iter()
+            .filter(|e| e.error.is_some())
+            .map(|e| ManifestEntry {
+                source_key: e.source_key,
+                dest_key: Some(e.dest_key),
+                size: Some(e.bytes).filter(|b| *b > 0),
+            })
+            .collect());
+    }
+    if trimmed.starts_with('{') {
+        return trimmed
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                let parsed: JsonLine = serde_json::from_str(line)
+                    .with_context(|| format!("Line {}: invalid JSON", i + 1))?;
+                Ok(ManifestEntry {
+                    source_key: parsed.source_key,
+                    dest_key: parsed.dest_key.filter(|k| !k.is_empty()),
+                    size: parsed.size,
+                })
+            })
+            .collect();
+    }
+    parse_csv_manifest(text)
+}
+
+fn parse_csv_manifest(text: &str) -> Result<Vec<ManifestEntry>> {
+    let mut rows = parse_csv(text).into_iter().peekable();
+    let Some(first) = rows.peek() else {
+        return Ok(Vec::new());
+    };
+
+    let column = |name: &str| first.iter().position(|c| c == name);
+    let (source_col, dest_col, size_col, error_col) = match column("source_key").or(column("key")) {
+        Some(source_col) => {
+            let cols = (
+                source_col,
+                column("dest_key"),
+                column("size").or(column("bytes")),
+                column("error"),
+            );
+            rows.next();
+            cols
+        }
+        None => (0, Some(1), None, None),
+    };
+
+    let mut entries = Vec::new();
+    for row in rows {
+        let Some(source_key) = row.get(source_col).filter(|k| !k.is_empty()) else {
+            continue;
+        };
+        // Report files: replay only what failed.
+        if let Some(col) = error_col
+            && row.get(col).is_none_or(|e| e.is_empty())
+        {
+            continue;
+        }
+        entries.push(ManifestEntry {
+            source_key: source_key.clone(),
+            dest_key: dest_col
+                .and_then(|c| row.get(c))
+                .filter(|k| !k.is_empty())
+                .cloned(),
+            size: size_col
+                .and_then(|c| row.get(c))
+                .and_then(|s| s.parse().ok())
+                .filter(|b| *b > 0),
+        });
+    }
+    Ok(entries)
+}
+
+/// Minimal RFC 4180 reader: quoted fields may contain separators, quotes and newlines.
+fn parse_csv(text: &str) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    field.push('"');
+                    chars.next();
+                }
+                '"' => in_quotes = false,
+                _ => field.push(c),
+            }
+            continue;
+        }
+        match c {
+            '"' => in_quotes = true,
+            ',' => row.push(std::mem::take(&mut field)),
+            '\r' => {}
+            '\n' => {
+                row.push(std::mem::take(&mut field));
+                if !(row.len() == 1 && row[0].is_empty()) {
+                    rows.push(std::mem::take(&mut row));
+                }
+                row.clear();
+            }
+            _ => field.push(c),
+        }
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(source: &str, dest: Option<&str>) -> ManifestEntry {
+        ManifestEntry {
+            source_key: source.to_string(),
+            dest_key: dest.map(str::to_string),
+            size: None,
+        }
+    }
+
+    fn sized(source: &str, dest: &str, size: i64) -> ManifestEntry {
+        ManifestEntry {
+            size: Some(size),
+            ..entry(source, Some(dest))
+        }
+    }
+
+    /// Verifies plain and headed CSV manifests, including quoted keys.
+    #[test]
+    fn parses_csv_manifests() {
+        let plain = "a.bin\n\"b,1.bin\",copy/b.bin\n\n";
+        assert_eq!(
+            parse_manifest(plain).unwrap(),
+            vec![entry("a.bin", None), entry("b,1.bin", Some("copy/b.bin"))]
+        );
+
+        let headed = "dest_key,source_key,size\r\nx/a,a,42\r\n,b,\r\n";
+        assert_eq!(
+            parse_manifest(headed).unwrap(),
+            vec![sized("a", "x/a", 42), entry("b", None)]
+        );
+    }
+
+    /// Ensures JSON lines accept `key` as an alias and optional destination keys.
+    #[test]
+    fn parses_json_lines_manifests() {
+        let text = "{\"source_key\":\"a\",\"dest_key\":\"x/a\",\"size\":7}\n\n{\"key\":\"b\"}\n";
+        assert_eq!(
+            parse_manifest(text).unwrap(),
+            vec![sized("a", "x/a", 7), entry("b", None)]
+        );
+        assert!(parse_manifest("{\"dest_key\":\"x\"}\n").is_err());
+    }
+
+    /// Confirms run reports replay only their failed entries, in both formats.
+    #[test]
+    fn reports_replay_failed_entries_only() {
+        let json = r#"{"objects":[
+{"source_bucket":"s","source_key":"ok","dest_bucket":"d","dest_key":"x/ok","action":"multipart","bytes":1,"duration_ms":1,"retries":0,"verification":"passed","error":null},
+{"source_bucket":"s","source_key":"bad","dest_bucket":"d","dest_key":"x/bad","action":null,"bytes":1,"duration_ms":1,"retries":3,"verification":"not-run","error":"SlowDown"}
+],"summary":{"total":2,"processed":2,"copied":1,"skipped":0,"failed":1,"retried":3,"deleted":0,"bytes_copied":1,"elapsed_ms":5}}
+"#;
+        assert_eq!(
+            parse_manifest(json).unwrap(),
+            vec![sized("bad", "x/bad", 1)]
+        );
+
+        let csv = "source_bucket,source_key,dest_bucket,dest_key,action,bytes,duration_ms,retries,verification,error\n\
+s,ok,d,x/ok,multipart,1,1,0,passed,\n\
+s,bad,d,x/bad,,1,1,3,not-run,\"line one\nline two\"\n";
+        assert_eq!(parse_manifest(csv).unwrap(), vec![sized("bad", "x/bad", 1)]);
+    }
+}
@@ -1,6 +1,6 @@
 use std::sync::{
     Arc,
-    atomic::{AtomicU64, AtomicUsize, Ordering},
+    atomic::{AtomicBool, AtomicI64, AtomicU64, AtomicUsize, Ordering},
 };
 
 /// Progress tracking structure
@@ -26,6 +26,51 @@ impl CopyProgress {
     }
 }
 
+/// Totals discovered so far by a streaming prefix listing
+#[derive(Clone, Default)]
+pub struct ListingProgress {
+    objects: Arc<AtomicUsize>,
+    bytes: Arc<AtomicI64>,
+    complete: Arc<AtomicBool>,
+}
+
+impl ListingProgress {
+    pub fn add_object(&self, size: i64) {
+        self.objects.fetch_add(1, Ordering::SeqCst);
+        self.bytes.fetch_add(size, Ordering::SeqCst);
+    }
+
+    /// Correct the byte total once an object's actual size is known.
+    pub fn add_bytes(&self, size: i64) {
+        self.bytes.fetch_add(size, Ordering::SeqCst);
+    }
+
+    pub fn mark_complete(&self) {
+        self.complete.store(true, Ordering::SeqCst);
+    }
+
+    pub fn objects(&self) -> usize {
+        self.objects.load(Ordering::SeqCst)
+    }
+
+    pub fn bytes(&self) -> i64 {
+        self.bytes.load(Ordering::SeqCst)
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.complete.load(Ordering::SeqCst)
+    }
+
+    /// Object total for display, suffixed with `+` while listing is still running.
+    pub fn objects_label(&self) -> String {
+        if self.is_complete() {
+            self.objects().to_string()
+        } else {
+            format!("{}+", self.objects())
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -65,4 +110,20 @@ mod tests {
         assert_eq!(progress.completed_parts.load(Ordering::SeqCst), 2);
         assert_eq!(clone.completed_parts.load(Ordering::SeqCst), 2);
     }
+
+    /// Verifies listing totals accumulate and the label marks an unfinished listing.
+    #[test]
+    fn listing_progress_tracks_totals_until_complete() {
+        let listing = ListingProgress::default();
+        listing.clone().add_object(10);
+        listing.add_object(32);
+
+        assert_eq!(listing.objects(), 2);
+        assert_eq!(listing.bytes(), 42);
+        assert_eq!(listing.objects_label(), "2+");
+
+        listing.mark_complete();
+        assert!(listing.is_complete());
+        assert_eq!(listing.objects_label(), "2");
+    }
 }
@@ -0,0 +1,273 @@
+//! Console output of the command line, rendered from the copy engine's progress
+//! events: banners, status lines, progress bars and warnings.
+
+use indicatif::{ProgressBar, ProgressStyle};
+use s3_largecopy::events::{EventSink, ProgressEvent};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+
+/// Event sink printing the human output (unless `quiet`) and passing every event on
+/// to the `--progress-sink` stream, if any.
+pub fn console_sink(quiet: bool, forward: Option<EventSink>) -> EventSink {
+    let renderer = ConsoleRenderer {
+        quiet,
+        forward,
+        state: Mutex::default(),
+    };
+    EventSink::from_callback(Arc::new(move |event: &ProgressEvent| {
+        renderer.render(event)
+    }))
+}
+
+struct ConsoleRenderer {
+    quiet: bool,
+    forward: Option<EventSink>,
+    state: Mutex<RenderState>,
+}
+
+#[derive(Default)]
+struct RenderState {
+    /// Destinations whose copy printed a banner. Only they get upload details and a
+    /// progress bar; the objects of a prefix run are reported one line each.
+    narrated: HashSet<String>,
+    bars: HashMap<String, ProgressBar>,
+}
+
+impl RenderState {
+    /// Print a line above the progress bars rather than through them.
+    fn print(&self, line: &str, to_stderr: bool) {
+        let print = || {
+            if to_stderr {
+                eprintln!("{}", line);
+            } else {
+                println!("{}", line);
+            }
+        };
+        match self.bars.values().next() {
+            Some(bar) => bar.suspend(print),
+            None => print(),
+        }
+    }
+
+    /// Stop the progress bar of a failed or interrupted upload.
+    fn abandon_bar(&mut self, destination: &str, message: &'static str) {
+        if let Some(bar) = self.bars.remove(destination) {
+            bar.abandon_with_message(message);
+        }
+    }
+}
+
+impl ConsoleRenderer {
+    fn render(&self, event: &ProgressEvent) {
+        if let Some(forward) = &self.forward {
+            forward.emit(event);
+        }
+        if self.quiet {
+            return;
+        }
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+
+        match event {
+            ProgressEvent::CopyStarted {
+                source,
+                destination,
+                version_id,
+                part_size,
+                concurrency,
+                streaming_buffer_mb,
+                dry_run,
+            } => {
+                state.narrated.insert(destination.clone());
+                let mut lines = vec![
+                    "\n=== S3 Large File Copy ===".to_string(),
+                    format!("Source:      {}", source),
+                ];
+                if let Some(version_id) = version_id {
+                    lines.push(format!("Version:     {}", version_id));
+                }
+                lines.push(format!("Destination: {}", destination));
+                lines.push(format!("Part size:   {} MB", part_size / 1024 / 1024));
+                lines.push(format!("Concurrency: {} parts", concurrency));
+                if let Some(buffer_mb) = streaming_buffer_mb {
+                    lines.push(format!(
+                        "Data path:   streaming (GetObject -> UploadPart, buffer {} MiB)",
+                        buffer_mb
+                    ));
+                }
+                lines.push("=========================\n".to_string());
+                if *dry_run {
+                    lines.push("🚨 DRY RUN MODE: No data will be modified.".to_string());
+                }
+                state.print(&lines.join("\n"), false);
+            }
+            ProgressEvent::Info { message, .. } => state.print(message, false),
+            ProgressEvent::Warning { message, .. } => {
+                state.print(&format!("⚠️  {}", message), true)
+            }
+            ProgressEvent::UploadStarted {
+                destination,
+                size,
+                part_size,
+                parts,
+                resumed_parts,
+                resumed_bytes,
+                ..
+            } if state.narrated.contains(destination) => {
+                state.print(
+                    &format!(
+                        "File size: {:.2} GB\nNumber of parts: {}\nFinal part size: {} MB",
+                        *size as f64 / (1024.0 * 1024.0 * 1024.0),
+                        parts,
+                        part_size / 1024 / 1024
+                    ),
+                    false,
+                );
+                if parts > resumed_parts {
+                    state.print("\n📥 Copying parts...\n", false);
+                    let bar = ProgressBar::new((size - resumed_bytes).max(0) as u64);
+                    bar.set_style(
+                        ProgressStyle::default_bar()
+                            .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {bytes}/{total_bytes} ({percent}%) {binary_bytes_per_sec} ETA: {eta} {msg}")
+                            .unwrap()
+                            .progress_chars("=>-"),
+                    );
+                    state.bars.insert(destination.clone(), bar);
+                }
+            }
+            ProgressEvent::PartCompleted {
+                destination,
+                bytes,
+                completed_parts,
+                total_parts,
+                ..
+            } => {
+                if let Some(bar) = state.bars.get(destination) {
+                    bar.inc(*bytes);
+                    bar.set_message(format!(
+                        "{}/{} parts completed",
+                        completed_parts, total_parts
+                    ));
+                    if completed_parts >= total_parts {
+                        bar.finish_with_message("All parts copied!");
+                        state.bars.remove(destination);
+                    }
+                }
+            }
+            ProgressEvent::ConcurrencyChanged {
+                destination,
+                from,
+                to,
+                avg_part_seconds,
+                throughput_mib_s,
+            } if state.narrated.contains(destination) => state.print(
+                &format!(
+                    "🤖 Auto Mode: concurrency {} -> {} (avg part {:.1}s, throughput {:.1} MiB/s)",
+                    from, to, avg_part_seconds, throughput_mib_s
+                ),
+                false,
+            ),
+            ProgressEvent::UploadCompleted { destination, .. }
+                if state.narrated.contains(destination) =>
+            {
+                state.print("   ✅ Multipart upload completed successfully!", false)
+            }
+            ProgressEvent::UploadInterrupted {
+                destination,
+                upload_id,
+                copied_parts,
+                copied_bytes,
+                kept,
+            } => {
+                state.abandon_bar(destination, "Interrupted");
+                if state.narrated.contains(destination) {
+                    state.print(
+                        &format!(
+                            "\n🛑 Interrupted: {} parts copied ({} bytes) to {}; upload {} {}",
+                            copied_parts,
+                            copied_bytes,
+                            destination,
+                            upload_id,
+                            if *kept {
+                                "kept for --resume"
+                            } else {
+                                "aborted"
+                            }
+                        ),
+                        false,
+                    );
+                }
+            }
+            ProgressEvent::UploadKept {
+                destination,
+                upload_id,
+                journal,
+                error,
+            } => {
+                state.abandon_bar(destination, "Failed");
+                state.print(
+                    &format!(
+                        "\n⚠️  Error occurred during upload: {}. Keeping upload {} for --resume (journal: {})",
+                        error, upload_id, journal
+                    ),
+                    true,
+                );
+            }
+            ProgressEvent::UploadAborted {
+                destination,
+                upload_id,
+                error,
+                abort_error,
+            } => {
+                state.abandon_bar(destination, "Failed");
+                let message = match abort_error {
+                    None => format!(
+                        "\n⚠️  Error occurred during upload: {}. Aborted upload {}.",
+                        error, upload_id
+                    ),
+                    Some(abort_error) => format!(
+                        "\n⚠️  Error occurred during upload: {}. Failed to abort upload {}: {}",
+                        error, upload_id, abort_error
+                    ),
+                };
+                state.print(&message, true);
+            }
+            // A banner copy's failure is the run's error, printed by the command.
+            ProgressEvent::ObjectFailed {
+                source,
+                destination,
+                error,
+                ..
+            } if !state.narrated.contains(destination) => state.print(
+                &format!("  ❌ Failed: {} -> {}: {}", source, destination, error),
+                true,
+            ),
+            ProgressEvent::TransferWindowWaiting {
+                window,
+                wait_seconds,
+            } => state.print(
+                &format!(
+                    "⏸️  Outside transfer window {}; waiting {}h{:02}m before starting new parts.",
+                    window,
+                    wait_seconds / 3600,
+                    wait_seconds / 60 % 60
+                ),
+                true,
+            ),
+            ProgressEvent::TransferWindowOpened { window } => state.print(
+                &format!("▶️  Transfer window {} opened; continuing.", window),
+                true,
+            ),
+            ProgressEvent::ObjectDeleted {
+                destination,
+                dry_run: true,
+            } => state.print(&format!("   [Dry Run] Would delete {}", destination), false),
+            ProgressEvent::ObjectDeleted {
+                destination,
+                dry_run: false,
+            } => state.print(&format!("   🗑️  {}", destination), false),
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,310 @@
+use crate::events::{CopyAction, RunSummary, Verification};
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum)]
+pub enum ReportFormat {
+    Json,
+    Csv,
+}
+
+impl ReportFormat {
+    /// `.csv` files get CSV, everything else JSON.
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("csv") => Self::Csv,
+            _ => Self::Json,
+        }
+    }
+}
+
+/// Per-object line of the run report.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ReportEntry {
+    pub source_bucket: String,
+    pub source_key: String,
+    pub dest_bucket: String,
+    pub dest_key: String,
+    /// `None` when the object failed before any action completed
+    pub action: Option<CopyAction>,
+    pub bytes: i64,
+    pub duration_ms: u64,
+    pub retries: usize,
+    pub verification: Verification,
+    pub error: Option<String>,
+}
+
+const CSV_HEADER: &str = "source_bucket,source_key,dest_bucket,dest_key,action,bytes,duration_ms,retries,verification,error";
+
+struct ReportState {
+    writer: BufWriter<File>,
+    format: ReportFormat,
+    entries: usize,
+    error: Option<String>,
+    /// Totals of the runs finished so far, when the report combines several runs
+    combined: Option<RunSummary>,
+}
+
+/// `--report` writer. Entries are streamed to disk as objects finish so very large
+/// runs do not accumulate in memory; the JSON document is closed by `finish`.
+#[derive(Clone)]
+pub struct RunReport {
+    path: PathBuf,
+    state: Arc<Mutex<ReportState>>,
+}
+
+impl RunReport {
+    pub fn create(path: &Path, format: ReportFormat) -> Result<Self> {
+        let file = File::create(path)
+            .with_context(|| format!("Failed to create report {}", path.display()))?;
+        let mut writer = BufWriter::new(file);
+        let header = match format {
+            ReportFormat::Json => "{\"objects\":[\n".to_string(),
+            ReportFormat::Csv => format!("{}\n", CSV_HEADER),
+        };
+        writer
+            .write_all(header.as_bytes())
+            .with_context(|| format!("Failed to write report {}", path.display()))?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            state: Arc::new(Mutex::new(ReportState {
+                writer,
+                format,
+                entries: 0,
+                error: None,
+                combined: None,
+            })),
+        })
+    }
+
+    /// Collect several runs (the tasks of a job) in this report: `finish` only adds
+    /// each run's totals, and `finish_combined` closes the report with their sum.
+    pub fn combining(self) -> Self {
+        if let Ok(mut state) = self.state.lock() {
+            state.combined = Some(RunSummary::default());
+        }
+        self
+    }
+
+    /// Close a combining report with the totals of every run and return them.
+    pub fn finish_combined(&self) -> Result<RunSummary> {
+        let summary = self
+            .state
+            .lock()
+            .map_err(|_| anyhow::anyhow!("Report lock poisoned"))?
+            .combined
+            .take()
+            .unwrap_or_default();
+        self.finish(&summary)?;
+        Ok(summary)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Append one object. A write failure is kept and returned by `finish`.
+    pub fn record(&self, entry: &ReportEntry) {
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        if state.error.is_some() {
+            return;
+        }
+        let line = match state.format {
+            ReportFormat::Json => {
+                let separator = if state.entries == 0 { "" } else { ",\n" };
+                serde_json::to_string(entry)
+                    .map(|json| format!("{}{}", separator, json))
+                    .map_err(|e| e.to_string())
+            }
+            ReportFormat::Csv => Ok(format!("{}\n", csv_row(entry))),
+        };
+        let written = line.and_then(|l| {
+            state
+                .writer
+                .write_all(l.as_bytes())
+                .map_err(|e| e.to_string())
+        });
+        match written {
+            Ok(()) => state.entries += 1,
+            Err(e) => state.error = Some(e),
+        }
+    }
+
+    /// Close the report with the run totals (JSON only; CSV has no summary row).
+    pub fn finish(&self, summary: &RunSummary) -> Result<()> {
+        let mut state = self
+            .state
+            .lock()
+            .map_err(|_| anyhow::anyhow!("Report lock poisoned"))?;
+        if let Some(combined) = &mut state.combined {
+            combined.add(summary);
+            return Ok(());
+        }
+        if let Some(e) = state.error.take() {
+            anyhow::bail!("Failed to write report {}: {}", self.path.display(), e);
+        }
+        if state.format == ReportFormat::Json {
+            let summary = serde_json::to_string(summary).context("Failed to encode summary")?;
+            let tail = format!("\n],\"summary\":{}}}\n", summary);
+            state
+                .writer
+                .write_all(tail.as_bytes())
+                .with_context(|| format!("Failed to write report {}", self.path.display()))?;
+        }
+        state
+            .writer
+            .flush()
+            .with_context(|| format!("Failed to write report {}", self.path.display()))
+    }
+}
+
+fn csv_row(entry: &ReportEntry) -> String {
+    let action = entry
+        .action
+        .and_then(|a| serde_json::to_value(a).ok())
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_default();
+    let verification = serde_json::to_value(entry.verification)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_default();
+    [
+        csv_field(&entry.source_bucket),
+        csv_field(&entry.source_key),
+        csv_field(&entry.dest_bucket),
+        csv_field(&entry.dest_key),
+        action,
+        entry.bytes.to_string(),
+        entry.duration_ms.to_string(),
+        entry.retries.to_string(),
+        verification,
+        csv_field(entry.error.as_deref().unwrap_or_default()),
+    ]
+    .join(",")
+}
+
+/// Quote a CSV field when it contains a separator, quote or line break.
+pub fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(key: &str, error: Option<&str>) -> ReportEntry {
+        ReportEntry {
+            source_bucket: "src".to_string(),
+            source_key: key.to_string(),
+            dest_bucket: "dst".to_string(),
+            dest_key: format!("copy/{}", key),
+            action: if error.is_some() {
+                None
+            } else {
+                Some(CopyAction::Multipart)
+            },
+            bytes: 42,
+            duration_ms: 7,
+            retries: 1,
+            verification: if error.is_some() {
+                Verification::NotRun
+            } else {
+                Verification::Passed
+            },
+            error: error.map(str::to_string),
+        }
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "s3_largecopy-report-{}-{}",
+            std::process::id(),
+            name
+        ))
+    }
+
+    /// Verifies the JSON report is a single document with every object and the summary.
+    #[test]
+    fn json_report_round_trips() {
+        let path = temp_path("report.json");
+        let report = RunReport::create(&path, ReportFormat::from_path(&path)).unwrap();
+        report.record(&entry("a.bin", None));
+        report.clone().record(&entry("b.bin", Some("AccessDenied")));
+        report
+            .finish(&RunSummary {
+                total: 2,
+                processed: 2,
+                copied: 1,
+                failed: 1,
+                ..Default::default()
+            })
+            .unwrap();
+
+        let doc: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        let objects: Vec<ReportEntry> = serde_json::from_value(doc["objects"].clone()).unwrap();
+        assert_eq!(
+            objects,
+            vec![entry("a.bin", None), entry("b.bin", Some("AccessDenied"))]
+        );
+        assert_eq!(doc["summary"]["failed"], 1);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    /// Ensures a combining report stays open across runs and closes with their sum.
+    #[test]
+    fn combined_report_sums_runs() {
+        let path = temp_path("combined.json");
+        let report = RunReport::create(&path, ReportFormat::Json)
+            .unwrap()
+            .combining();
+        for (key, copied) in [("a.bin", 1), ("b.bin", 2)] {
+            report.record(&entry(key, None));
+            report
+                .finish(&RunSummary {
+                    total: copied,
+                    copied,
+                    bytes_copied: 42,
+                    ..Default::default()
+                })
+                .unwrap();
+        }
+        let summary = report.finish_combined().unwrap();
+        assert_eq!((summary.copied, summary.bytes_copied), (3, 84));
+
+        let doc: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(doc["objects"].as_array().unwrap().len(), 2);
+        assert_eq!(doc["summary"]["copied"], 3);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    /// Ensures CSV rows escape separators and quotes in keys and errors.
+    #[test]
+    fn csv_report_escapes_fields() {
+        let path = temp_path("report.csv");
+        let report = RunReport::create(&path, ReportFormat::from_path(&path)).unwrap();
+        report.record(&entry("a,b.bin", Some("bad \"etag\"")));
+        report.finish(&RunSummary::default()).unwrap();
+
+        let text = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[0], CSV_HEADER);
+        assert_eq!(
+            lines[1],
+            "src,\"a,b.bin\",dst,\"copy/a,b.bin\",,42,7,1,not-run,\"bad \"\"etag\"\"\""
+        );
+        let _ = std::fs::remove_file(&path);
+    }
+}
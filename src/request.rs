@@ -0,0 +1,559 @@
+use crate::app::{self, CopyOutcome, DestinationGuard, S3CopyApp};
+use crate::auto::{AutoProfile, DataPath, OnInterrupt, OnSourceChange, VerifyIntegrity};
+use crate::control::TransferControl;
+use crate::events::{EventSink, ProgressEvent, RunSummary};
+use crate::report::RunReport;
+use crate::s3_utils::{self, ClientConfig, ClientPool};
+use crate::throttle::Throttle;
+use crate::uri::{CopyScope, S3Uri};
+use anyhow::Result;
+use std::sync::Arc;
+
+pub const MIN_PART_SIZE_MB: i64 = 5;
+pub const DEFAULT_PART_SIZE_MB: i64 = 256;
+pub const MAX_PART_SIZE_MB: i64 = 5 * 1024; // 5GB maximum in MB
+pub const DEFAULT_CONCURRENCY: usize = 50;
+pub const MAX_CONCURRENT_PARTS: usize = 1000;
+pub const DEFAULT_OBJECT_CONCURRENCY: usize = 1;
+pub const MAX_OBJECT_CONCURRENCY: usize = 256;
+pub const DEFAULT_MAX_BUFFER_MB: usize = 2048;
+
+/// Builder for one copy (`copy`) or prefix copy (`sync`), with the defaults of the
+/// command line. The library never prints: progress, warnings and, with `quiet(false)`,
+/// the console narration are reported through [`CopyRequest::on_progress`].
+///
+/// ```no_run
+/// use s3_largecopy::{CopyRequest, ProgressEvent};
+///
+/// # async fn example() -> anyhow::Result<()> {
+/// let outcome = CopyRequest::from_uris("s3://src/images/disk.img", "s3://dst/backup/")?
+///     .storage_class("GLACIER_IR")
+///     .auto(true)
+///     .on_progress(|event| {
+///         if let ProgressEvent::PartCompleted { completed_parts, total_parts, .. } = event {
+///             eprintln!("{}/{} parts", completed_parts, total_parts);
+///         }
+///     })
+///     .copy()
+///     .await?;
+/// println!("{:?}: {} bytes in {} parts", outcome.action, outcome.size, outcome.parts);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct CopyRequest {
+    source_bucket: String,
+    /// Object key for `copy`, prefix for `sync`
+    source_key: String,
+    dest_bucket: String,
+    dest_key: String,
+    source_config: ClientConfig,
+    dest_config: ClientConfig,
+    part_size_mb: i64,
+    concurrency: usize,
+    storage_class: Option<String>,
+    full_control: bool,
+    auto: bool,
+    auto_profile: AutoProfile,
+    no_metadata: bool,
+    no_tags: bool,
+    no_storage_class: bool,
+    no_acl: bool,
+    quiet: bool,
+    dry_run: bool,
+    force_copy: bool,
+    verify_integrity: VerifyIntegrity,
+    checksum_algorithm: Option<String>,
+    sse: Option<String>,
+    sse_kms_key_id: Option<String>,
+    include: Vec<String>,
+    exclude: Vec<String>,
+    resume: bool,
+    journal_dir: Option<String>,
+    object_concurrency: usize,
+    delete: bool,
+    max_delete: Option<usize>,
+    all_versions: bool,
+    data_path: DataPath,
+    max_buffer_mb: usize,
+    progress_sink: Option<EventSink>,
+    report: Option<RunReport>,
+    source_version_id: Option<String>,
+    on_source_change: OnSourceChange,
+    dest_guard: Option<DestinationGuard>,
+    match_source_parts: bool,
+    throttle: Throttle,
+    control: TransferControl,
+    on_interrupt: OnInterrupt,
+    clients: ClientPool,
+}
+
+impl CopyRequest {
+    /// Copy `source_key` to `dest_key`; for [`CopyRequest::sync`] the keys are the
+    /// source and destination prefixes.
+    pub fn new(
+        source_bucket: impl Into<String>,
+        source_key: impl Into<String>,
+        dest_bucket: impl Into<String>,
+        dest_key: impl Into<String>,
+    ) -> Self {
+        Self {
+            source_bucket: source_bucket.into(),
+            source_key: source_key.into(),
+            dest_bucket: dest_bucket.into(),
+            dest_key: dest_key.into(),
+            source_config: ClientConfig::default(),
+            dest_config: ClientConfig::default(),
+            part_size_mb: DEFAULT_PART_SIZE_MB,
+            concurrency: DEFAULT_CONCURRENCY,
+            storage_class: None,
+            full_control: false,
+            auto: false,
+            auto_profile: AutoProfile::default(),
+            no_metadata: false,
+            no_tags: false,
+            no_storage_class: false,
+            no_acl: false,
+            quiet: true,
+            dry_run: false,
+            force_copy: false,
+            verify_integrity: VerifyIntegrity::default(),
+            checksum_algorithm: None,
+            sse: None,
+            sse_kms_key_id: None,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            resume: false,
+            journal_dir: None,
+            object_concurrency: DEFAULT_OBJECT_CONCURRENCY,
+            delete: false,
+            max_delete: None,
+            all_versions: false,
+            data_path: DataPath::default(),
+            max_buffer_mb: DEFAULT_MAX_BUFFER_MB,
+            progress_sink: None,
+            report: None,
+            source_version_id: None,
+            on_source_change: OnSourceChange::default(),
+            dest_guard: None,
+            match_source_parts: false,
+            throttle: Throttle::default(),
+            control: TransferControl::default(),
+            on_interrupt: OnInterrupt::default(),
+            clients: ClientPool::default(),
+        }
+    }
+
+    /// Request between two `s3://` URIs or access point ARNs, resolved like the
+    /// positional `SOURCE DEST` arguments: a source ending in `/` is a prefix.
+    pub fn from_uris(source: &str, dest: &str) -> Result<Self> {
+        let parse = |value: &str| S3Uri::parse(value).map_err(anyhow::Error::msg);
+        let (source, dest) = (parse(source)?, parse(dest)?);
+        Ok(match CopyScope::between(&source, &dest)? {
+            CopyScope::Object {
+                source_key,
+                source_version_id,
+                dest_key,
+            } => Self::new(source.bucket, source_key, dest.bucket, dest_key)
+                .set_source_version_id(source_version_id),
+            CopyScope::Prefix {
+                source_prefix,
+                dest_prefix,
+            } => Self::new(source.bucket, source_prefix, dest.bucket, dest_prefix),
+        })
+    }
+
+    /// Connection settings of the source; the region is detected when unset.
+    pub fn source_config(mut self, config: ClientConfig) -> Self {
+        self.source_config = config;
+        self
+    }
+
+    /// Connection settings of the destination; the region is detected when unset.
+    pub fn dest_config(mut self, config: ClientConfig) -> Self {
+        self.dest_config = config;
+        self
+    }
+
+    pub fn part_size_mb(mut self, part_size_mb: i64) -> Self {
+        self.part_size_mb = part_size_mb;
+        self
+    }
+
+    /// Concurrent parts per object (the cap in auto mode).
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency;
+        self
+    }
+
+    pub fn storage_class(self, storage_class: impl Into<String>) -> Self {
+        self.set_storage_class(Some(storage_class.into()))
+    }
+
+    pub fn set_storage_class(mut self, storage_class: Option<String>) -> Self {
+        self.storage_class = storage_class;
+        self
+    }
+
+    pub fn full_control(mut self, full_control: bool) -> Self {
+        self.full_control = full_control;
+        self
+    }
+
+    /// Tune part size and concurrency from the object size and observed throughput.
+    pub fn auto(mut self, auto: bool) -> Self {
+        self.auto = auto;
+        self
+    }
+
+    pub fn auto_profile(mut self, auto_profile: AutoProfile) -> Self {
+        self.auto_profile = auto_profile;
+        self
+    }
+
+    pub fn no_metadata(mut self, no_metadata: bool) -> Self {
+        self.no_metadata = no_metadata;
+        self
+    }
+
+    pub fn no_tags(mut self, no_tags: bool) -> Self {
+        self.no_tags = no_tags;
+        self
+    }
+
+    pub fn no_storage_class(mut self, no_storage_class: bool) -> Self {
+        self.no_storage_class = no_storage_class;
+        self
+    }
+
+    pub fn no_acl(mut self, no_acl: bool) -> Self {
+        self.no_acl = no_acl;
+        self
+    }
+
+    /// Skip the narration events (`copy_started` banners, `info` status lines and
+    /// summaries); `true` by default. Progress and warning events are always emitted.
+    pub fn quiet(mut self, quiet: bool) -> Self {
+        self.quiet = quiet;
+        self
+    }
+
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    pub fn force_copy(mut self, force_copy: bool) -> Self {
+        self.force_copy = force_copy;
+        self
+    }
+
+    pub fn verify_integrity(mut self, verify_integrity: VerifyIntegrity) -> Self {
+        self.verify_integrity = verify_integrity;
+        self
+    }
+
+    pub fn checksum_algorithm(self, algorithm: impl Into<String>) -> Self {
+        self.set_checksum_algorithm(Some(algorithm.into()))
+    }
+
+    pub fn set_checksum_algorithm(mut self, algorithm: Option<String>) -> Self {
+        self.checksum_algorithm = algorithm;
+        self
+    }
+
+    pub fn sse(self, sse: impl Into<String>) -> Self {
+        self.set_sse(Some(sse.into()))
+    }
+
+    pub fn set_sse(mut self, sse: Option<String>) -> Self {
+        self.sse = sse;
+        self
+    }
+
+    pub fn sse_kms_key_id(self, key_id: impl Into<String>) -> Self {
+        self.set_sse_kms_key_id(Some(key_id.into()))
+    }
+
+    pub fn set_sse_kms_key_id(mut self, key_id: Option<String>) -> Self {
+        self.sse_kms_key_id = key_id;
+        self
+    }
+
+    /// Glob patterns of the keys a `sync` copies.
+    pub fn include(mut self, patterns: Vec<String>) -> Self {
+        self.include = patterns;
+        self
+    }
+
+    /// Glob patterns of the keys a `sync` leaves out.
+    pub fn exclude(mut self, patterns: Vec<String>) -> Self {
+        self.exclude = patterns;
+        self
+    }
+
+    pub fn resume(mut self, resume: bool) -> Self {
+        self.resume = resume;
+        self
+    }
+
+    pub fn journal_dir(self, journal_dir: impl Into<String>) -> Self {
+        self.set_journal_dir(Some(journal_dir.into()))
+    }
+
+    pub fn set_journal_dir(mut self, journal_dir: Option<String>) -> Self {
+        self.journal_dir = journal_dir;
+        self
+    }
+
+    /// Objects a `sync` copies in parallel.
+    pub fn object_concurrency(mut self, object_concurrency: usize) -> Self {
+        self.object_concurrency = object_concurrency;
+        self
+    }
+
+    /// Delete destination objects a `sync` source no longer has.
+    pub fn delete(mut self, delete: bool) -> Self {
+        self.delete = delete;
+        self
+    }
+
+    pub fn max_delete(self, max_delete: usize) -> Self {
+        self.set_max_delete(Some(max_delete))
+    }
+
+    pub fn set_max_delete(mut self, max_delete: Option<usize>) -> Self {
+        self.max_delete = max_delete;
+        self
+    }
+
+    /// Copy every version under the `sync` prefix, oldest first.
+    pub fn all_versions(mut self, all_versions: bool) -> Self {
+        self.all_versions = all_versions;
+        self
+    }
+
+    pub fn data_path(mut self, data_path: DataPath) -> Self {
+        self.data_path = data_path;
+        self
+    }
+
+    pub fn max_buffer_mb(mut self, max_buffer_mb: usize) -> Self {
+        self.max_buffer_mb = max_buffer_mb;
+        self
+    }
+
+    /// Send progress events to `callback`.
+    pub fn on_progress(self, callback: impl Fn(&ProgressEvent) + Send + Sync + 'static) -> Self {
+        self.set_progress_sink(Some(EventSink::from_callback(Arc::new(callback))))
+    }
+
+    pub fn set_progress_sink(mut self, sink: Option<EventSink>) -> Self {
+        self.progress_sink = sink;
+        self
+    }
+
+    pub fn set_report(mut self, report: Option<RunReport>) -> Self {
+        self.report = report;
+        self
+    }
+
+    pub fn source_version_id(self, version_id: impl Into<String>) -> Self {
+        self.set_source_version_id(Some(version_id.into()))
+    }
+
+    pub fn set_source_version_id(mut self, version_id: Option<String>) -> Self {
+        self.source_version_id = version_id;
+        self
+    }
+
+    pub fn on_source_change(mut self, on_source_change: OnSourceChange) -> Self {
+        self.on_source_change = on_source_change;
+        self
+    }
+
+    pub fn set_dest_guard(mut self, dest_guard: Option<DestinationGuard>) -> Self {
+        self.dest_guard = dest_guard;
+        self
+    }
+
+    pub fn match_source_parts(mut self, match_source_parts: bool) -> Self {
+        self.match_source_parts = match_source_parts;
+        self
+    }
+
+    /// Bandwidth and request-rate limits, shared with every request given the same one.
+    pub fn throttle(mut self, throttle: Throttle) -> Self {
+        self.throttle = throttle;
+        self
+    }
+
+    /// Pause, resume and interrupt handle, shared with every request given the same one.
+    pub fn control(mut self, control: TransferControl) -> Self {
+        self.control = control;
+        self
+    }
+
+    pub fn on_interrupt(mut self, on_interrupt: OnInterrupt) -> Self {
+        self.on_interrupt = on_interrupt;
+        self
+    }
+
+    /// S3 clients to reuse across requests.
+    pub fn clients(mut self, clients: ClientPool) -> Self {
+        self.clients = clients;
+        self
+    }
+
+    /// Copy the object.
+    pub async fn copy(self) -> Result<CopyOutcome> {
+        self.build().await?.copy_single().await
+    }
+
+    /// Copy every object under the source prefix into the destination prefix. When
+    /// some objects fail the error is an `ObjectsFailed` carrying the totals.
+    pub async fn sync(self) -> Result<RunSummary> {
+        let source_prefix = self.source_key.clone();
+        let dest_prefix = self.dest_key.clone();
+        let all_versions = self.all_versions;
+        let app = self.build().await?;
+        if all_versions {
+            app.copy_all_versions_from_prefix(&source_prefix, &dest_prefix)
+                .await
+        } else {
+            app.copy_from_prefix(&source_prefix, &dest_prefix).await
+        }
+    }
+
+    /// Validate the settings, detect missing bucket regions and create the app.
+    pub async fn build(mut self) -> Result<S3CopyApp> {
+        if !(MIN_PART_SIZE_MB..=MAX_PART_SIZE_MB).contains(&self.part_size_mb) {
+            anyhow::bail!(
+                "Part size must be between {} and {} MB",
+                MIN_PART_SIZE_MB,
+                MAX_PART_SIZE_MB
+            );
+        }
+        if !(1..=MAX_CONCURRENT_PARTS).contains(&self.concurrency) {
+            anyhow::bail!("Concurrency must be between 1 and {}", MAX_CONCURRENT_PARTS);
+        }
+        if !(1..=MAX_OBJECT_CONCURRENCY).contains(&self.object_concurrency) {
+            anyhow::bail!(
+                "Object concurrency must be between 1 and {}",
+                MAX_OBJECT_CONCURRENCY
+            );
+        }
+        if self.delete {
+            app::check_delete_scope(
+                &self.source_bucket,
+                &self.source_key,
+                &self.dest_bucket,
+                &self.dest_key,
+            )?;
+        }
+        self.source_config.region = self
+            .bucket_region(&self.source_config, &self.source_bucket)
+            .await?;
+        self.dest_config.region = self
+            .bucket_region(&self.dest_config, &self.dest_bucket)
+            .await?;
+
+        S3CopyApp::new(
+            self.source_bucket,
+            self.source_key,
+            self.dest_bucket,
+            self.dest_key,
+            self.dest_config,
+            self.source_config,
+            self.part_size_mb * 1024 * 1024,
+            self.concurrency,
+            self.storage_class,
+            self.full_control,
+            self.auto,
+            self.auto_profile,
+            self.no_metadata,
+            self.no_tags,
+            self.no_storage_class,
+            self.no_acl,
+            self.quiet,
+            self.dry_run,
+            self.force_copy,
+            self.verify_integrity,
+            self.checksum_algorithm,
+            self.sse,
+            self.sse_kms_key_id,
+            self.include,
+            self.exclude,
+            self.resume,
+            self.journal_dir,
+            self.object_concurrency,
+            self.delete,
+            self.max_delete,
+            self.data_path,
+            self.max_buffer_mb,
+            self.progress_sink,
+            self.report,
+            self.source_version_id,
+            self.on_source_change,
+            self.dest_guard,
+            self.match_source_parts,
+            self.throttle,
+            self.control,
+            self.on_interrupt,
+            self.clients,
+        )
+        .await
+    }
+
+    /// The configured region, or the bucket's.
+    async fn bucket_region(&self, config: &ClientConfig, bucket: &str) -> Result<Option<String>> {
+        if config.region.is_some() {
+            return Ok(config.region.clone());
+        }
+        let client = self.clients.client(config).await;
+        s3_utils::get_bucket_region(&client, bucket, None)
+            .await
+            .map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verifies URI requests resolve like the positional arguments and keep the defaults.
+    #[test]
+    fn requests_from_uris() {
+        let object = CopyRequest::from_uris("s3://src/data/a.bin?versionId=v1", "s3://dst/backup/")
+            .unwrap()
+            .auto(true);
+        assert_eq!(
+            (object.source_bucket.as_str(), object.source_key.as_str()),
+            ("src", "data/a.bin")
+        );
+        assert_eq!(object.dest_key, "backup/a.bin");
+        assert_eq!(object.source_version_id.as_deref(), Some("v1"));
+        assert!(object.auto && object.quiet);
+        assert_eq!(object.part_size_mb, DEFAULT_PART_SIZE_MB);
+
+        let prefix = CopyRequest::from_uris("s3://src/logs/", "s3://dst/").unwrap();
+        assert_eq!(
+            (prefix.source_key.as_str(), prefix.dest_key.as_str()),
+            ("logs/", "")
+        );
+        assert!(CopyRequest::from_uris("s3://src/logs/", "s3://dst/file").is_err());
+        assert!(CopyRequest::from_uris("src/a", "s3://dst/").is_err());
+    }
+
+    /// Ensures out-of-range settings fail before any client is created.
+    #[tokio::test]
+    async fn build_rejects_out_of_range_settings() {
+        let request = CopyRequest::new("src", "a", "dst", "b");
+        assert!(request.clone().part_size_mb(1).build().await.is_err());
+        assert!(request.clone().concurrency(0).build().await.is_err());
+        assert!(request.object_concurrency(1000).build().await.is_err());
+
+        let nested = CopyRequest::new("b", "data/in/", "b", "data/").delete(true);
+        assert!(nested.build().await.is_err());
+    }
+}
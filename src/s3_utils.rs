@@ -1,5 +1,178 @@
+use crate::uri;
 use anyhow::Result;
-use aws_sdk_s3::Client;
+use aws_config::ConfigLoader;
+use aws_config::sts::AssumeRoleProvider;
+use aws_sdk_s3::error::ProvideErrorMetadata;
+use aws_sdk_s3::{Client, config::Region};
+use aws_smithy_runtime::client::http::hyper_014::HyperClientBuilder;
+use aws_smithy_types::retry::RetryConfig;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// Connection settings for one side (source or destination) of a copy.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ClientConfig {
+    pub region: Option<String>,
+    pub profile: Option<String>,
+    /// Custom S3 endpoint (MinIO, Ceph, other S3-compatible stores)
+    pub endpoint_url: Option<String>,
+    pub force_path_style: bool,
+    /// IAM role assumed (via STS AssumeRole) on top of the profile credentials
+    pub role_arn: Option<String>,
+}
+
+impl ClientConfig {
+    /// Build an S3 client from these settings on top of `loader`
+    /// (which carries shared HTTP and retry configuration).
+    pub async fn build_client(&self, mut loader: ConfigLoader) -> Client {
+        if let Some(r) = &self.region {
+            loader = loader.region(Region::new(r.clone()));
+        }
+        if let Some(p) = &self.profile {
+            loader = loader.profile_name(p);
+        }
+        let sdk_config = loader.load().await;
+
+        let mut builder = aws_sdk_s3::config::Builder::from(&sdk_config);
+        if let Some(url) = &self.endpoint_url {
+            builder = builder.endpoint_url(url);
+        }
+        if self.force_path_style {
+            builder = builder.force_path_style(true);
+        }
+        if let Some(role_arn) = &self.role_arn {
+            let provider = AssumeRoleProvider::builder(role_arn)
+                .session_name("s3_largecopy")
+                .configure(&sdk_config)
+                .build()
+                .await;
+            builder = builder.credentials_provider(provider);
+        }
+        Client::from_conf(builder.build())
+    }
+}
+
+/// Retry budget and HTTP connection pool size of a transfer client.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct TransferTuning {
+    max_attempts: u32,
+    max_connections: usize,
+}
+
+/// Pooled clients by connection settings and transfer tuning (`None` for the SDK
+/// defaults).
+type ClientKey = (ClientConfig, Option<TransferTuning>);
+
+/// S3 clients shared by every app of a run, so the tasks of a job reuse connection
+/// pools and credentials. Clients are keyed by their connection settings, retry budget
+/// and connection pool size, so a task never inherits a pool sized for another's
+/// concurrency.
+#[derive(Clone, Default)]
+pub struct ClientPool {
+    clients: Arc<Mutex<HashMap<ClientKey, Client>>>,
+}
+
+impl ClientPool {
+    /// The pooled client for `config` with the SDK's default HTTP client and retries.
+    pub async fn client(&self, config: &ClientConfig) -> Client {
+        self.get_or_build(config, None).await
+    }
+
+    /// The pooled client for copying with up to `max_connections` concurrent requests:
+    /// its HTTP client keeps that many idle connections per host, and requests are
+    /// retried up to `max_attempts` times. Built on first use only.
+    pub async fn transfer_client(
+        &self,
+        config: &ClientConfig,
+        max_attempts: u32,
+        max_connections: usize,
+    ) -> Client {
+        let tuning = TransferTuning {
+            max_attempts,
+            max_connections: max_connections.max(1),
+        };
+        self.get_or_build(config, Some(tuning)).await
+    }
+
+    async fn get_or_build(&self, config: &ClientConfig, tuning: Option<TransferTuning>) -> Client {
+        let mut clients = self.clients.lock().await;
+        let key = (config.clone(), tuning);
+        if let Some(client) = clients.get(&key) {
+            return client.clone();
+        }
+        let client = config.build_client(config_loader(tuning)).await;
+        clients.insert(key, client.clone());
+        client
+    }
+}
+
+fn config_loader(tuning: Option<TransferTuning>) -> ConfigLoader {
+    let loader = aws_config::defaults(aws_config::BehaviorVersion::latest());
+    let Some(tuning) = tuning else {
+        return loader;
+    };
+
+    // Configure a custom Hyper client with increased connection pool limits
+    let mut hyper_builder = hyper::Client::builder();
+    // Match max connections to concurrency to avoid pool bottlenecks
+    hyper_builder.pool_max_idle_per_host(tuning.max_connections);
+    hyper_builder.retry_canceled_requests(true);
+    hyper_builder.http2_only(false); // Allow fallback to HTTP/1.1
+    hyper_builder.pool_idle_timeout(std::time::Duration::from_secs(90));
+
+    let http_client = HyperClientBuilder::new()
+        .hyper_builder(hyper_builder)
+        .build_https();
+    loader
+        .http_client(http_client)
+        .retry_config(RetryConfig::standard().with_max_attempts(tuning.max_attempts))
+}
+
+/// AWS partition a region belongs to.
+pub fn partition_for_region(region: &str) -> &'static str {
+    if region.starts_with("cn-") {
+        "aws-cn"
+    } else if region.starts_with("us-gov-") {
+        "aws-us-gov"
+    } else if region.starts_with("us-isob-") {
+        "aws-iso-b"
+    } else if region.starts_with("us-iso-") {
+        "aws-iso"
+    } else if region.starts_with("eu-isoe-") {
+        "aws-iso-e"
+    } else {
+        "aws"
+    }
+}
+
+/// Server-side copies only work within one S3 service: same endpoint and same partition.
+pub fn requires_streaming(source: &ClientConfig, dest: &ClientConfig) -> bool {
+    if source.endpoint_url != dest.endpoint_url {
+        return true;
+    }
+    match (&source.region, &dest.region) {
+        (Some(src), Some(dst)) => partition_for_region(src) != partition_for_region(dst),
+        _ => false,
+    }
+}
+
+/// Map a GetBucketLocation constraint to a region name.
+pub fn region_from_location(constraint: Option<&str>) -> String {
+    match constraint {
+        None | Some("") => "us-east-1".to_string(),
+        Some("EU") => "eu-west-1".to_string(),
+        Some(s) => s.to_string(),
+    }
+}
+
+/// Error codes returned by S3-compatible stores that do not implement GetBucketLocation.
+fn is_unsupported_location_error(code: Option<&str>) -> bool {
+    matches!(
+        code,
+        Some("NotImplemented" | "MethodNotAllowed" | "XNotImplemented" | "UnsupportedOperation")
+    )
+}
 
 /// Detect the region of an S3 bucket.
 pub async fn get_bucket_region(
@@ -7,23 +180,22 @@ pub async fn get_bucket_region(
     bucket: &str,
     user_override: Option<&String>,
 ) -> Result<String> {
+    // Access point ARNs carry their region, and GetBucketLocation does not accept them.
+    if let Some(region) = uri::access_point_region(bucket) {
+        return Ok(region.to_string());
+    }
     if let Some(r) = user_override {
         return Ok(r.clone());
     }
     match client.get_bucket_location().bucket(bucket).send().await {
-        Ok(loc) => {
-            if let Some(constraint) = loc.location_constraint() {
-                let s = constraint.as_str();
-                if s == "EU" {
-                    Ok("eu-west-1".to_string())
-                } else {
-                    Ok(s.to_string())
-                }
-            } else {
-                Ok("us-east-1".to_string())
-            }
-        }
+        Ok(loc) => Ok(region_from_location(
+            loc.location_constraint().map(|c| c.as_str()),
+        )),
         Err(e) => {
+            if is_unsupported_location_error(e.code()) {
+                // Region is only used for signing on such stores; us-east-1 is the usual default.
+                return Ok("us-east-1".to_string());
+            }
             let err_str = format!("{}", e);
             if err_str.contains("NoSuchBucket")
                 || err_str.contains("NotFound")
@@ -46,3 +218,63 @@ pub async fn get_bucket_region(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Ensures empty and legacy location constraints map to their canonical regions.
+    #[test]
+    fn region_from_location_handles_legacy_values() {
+        assert_eq!(region_from_location(None), "us-east-1");
+        assert_eq!(region_from_location(Some("")), "us-east-1");
+        assert_eq!(region_from_location(Some("EU")), "eu-west-1");
+        assert_eq!(region_from_location(Some("ap-south-1")), "ap-south-1");
+    }
+
+    /// Ensures apps with different concurrency get their own transfer client, so each
+    /// connection pool is sized for its app, while equal settings share one.
+    #[tokio::test]
+    async fn transfer_clients_are_pooled_per_concurrency() {
+        let pool = ClientPool::default();
+        let config = ClientConfig {
+            region: Some("us-east-1".to_string()),
+            endpoint_url: Some("http://localhost:9000".to_string()),
+            ..Default::default()
+        };
+        pool.transfer_client(&config, 5, 8).await;
+        pool.transfer_client(&config, 5, 8).await;
+        pool.transfer_client(&config, 5, 64).await;
+        assert_eq!(pool.clients.lock().await.len(), 2);
+    }
+
+    /// Ensures copies across endpoints or partitions are routed through the streaming path.
+    #[test]
+    fn streaming_required_across_endpoints_and_partitions() {
+        let aws = |region: &str| ClientConfig {
+            region: Some(region.to_string()),
+            ..Default::default()
+        };
+        let minio = ClientConfig {
+            region: Some("us-east-1".to_string()),
+            endpoint_url: Some("http://localhost:9000".to_string()),
+            ..Default::default()
+        };
+
+        assert!(!requires_streaming(&aws("us-east-1"), &aws("eu-west-1")));
+        assert!(requires_streaming(&aws("us-east-1"), &aws("cn-north-1")));
+        assert!(requires_streaming(&aws("us-gov-west-1"), &aws("us-east-1")));
+        assert!(requires_streaming(&minio, &aws("us-east-1")));
+        assert_eq!(partition_for_region("cn-northwest-1"), "aws-cn");
+        assert_eq!(partition_for_region("us-isob-east-1"), "aws-iso-b");
+    }
+
+    /// Verifies stores without GetBucketLocation support are recognized.
+    #[test]
+    fn unsupported_location_errors_are_tolerated() {
+        assert!(is_unsupported_location_error(Some("NotImplemented")));
+        assert!(is_unsupported_location_error(Some("MethodNotAllowed")));
+        assert!(!is_unsupported_location_error(Some("AccessDenied")));
+        assert!(!is_unsupported_location_error(None));
+    }
+}
@@ -0,0 +1,152 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Token bucket refilled continuously at `rate` tokens per second, holding at most
+/// one second of tokens. Callers reserve tokens up front and sleep off any debt, so
+/// requests larger than the bucket (a 5 GiB part under a 500 MiB/s cap) still pass,
+/// just later, and waiters are served in reservation order.
+#[derive(Debug)]
+struct TokenBucket {
+    rate: f64,
+    state: Mutex<BucketState>,
+}
+
+#[derive(Debug)]
+struct BucketState {
+    tokens: f64,
+    updated: Instant,
+}
+
+impl TokenBucket {
+    fn new(rate: f64) -> Self {
+        Self {
+            rate,
+            state: Mutex::new(BucketState {
+                tokens: rate,
+                updated: Instant::now(),
+            }),
+        }
+    }
+
+    /// Take `amount` tokens and return how long the caller must wait for them.
+    fn reserve(&self, amount: f64, now: Instant) -> Duration {
+        let mut state = self.state.lock().unwrap();
+        let elapsed = now.saturating_duration_since(state.updated).as_secs_f64();
+        state.tokens = (state.tokens + elapsed * self.rate).min(self.rate);
+        state.updated = now;
+        state.tokens -= amount;
+        if state.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-state.tokens / self.rate)
+        }
+    }
+}
+
+/// Bandwidth and request-rate caps shared by every clone of the app, so part
+/// windows and prefix-mode workers all draw from the same buckets.
+#[derive(Clone, Debug, Default)]
+pub struct Throttle {
+    bandwidth: Option<Arc<TokenBucket>>,
+    requests: Option<Arc<TokenBucket>>,
+    waited_micros: Arc<AtomicU64>,
+}
+
+impl Throttle {
+    /// `max_bandwidth` in bytes per second, `max_requests_per_second` in requests.
+    pub fn new(max_bandwidth: Option<u64>, max_requests_per_second: Option<u32>) -> Self {
+        Self {
+            bandwidth: max_bandwidth.map(|rate| Arc::new(TokenBucket::new(rate as f64))),
+            requests: max_requests_per_second.map(|rate| Arc::new(TokenBucket::new(rate as f64))),
+            waited_micros: Arc::default(),
+        }
+    }
+
+    /// Wait until `requests` requests moving `bytes` bytes fit under the caps.
+    pub async fn acquire(&self, requests: u32, bytes: u64) {
+        let now = Instant::now();
+        let mut wait = Duration::ZERO;
+        if let Some(bucket) = &self.requests {
+            wait = wait.max(bucket.reserve(requests as f64, now));
+        }
+        if let Some(bucket) = &self.bandwidth {
+            wait = wait.max(bucket.reserve(bytes as f64, now));
+        }
+        if !wait.is_zero() {
+            self.waited_micros
+                .fetch_add(wait.as_micros() as u64, Ordering::Relaxed);
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Total time callers have spent waiting on the caps; a change between two
+    /// readings means the caps were binding in between.
+    pub fn waited(&self) -> Duration {
+        Duration::from_micros(self.waited_micros.load(Ordering::Relaxed))
+    }
+}
+
+/// Parse a bandwidth such as `500MiB/s`, `1.5GB/s`, `800M` or `1048576` into bytes
+/// per second. `K`/`M`/`G`/`T` are decimal, `Ki`/`Mi`/`Gi`/`Ti` binary; the `B` and
+/// `/s` suffixes are optional.
+pub fn parse_bandwidth(value: &str) -> Result<u64, String> {
+    let trimmed = value.trim();
+    let unit = trimmed.strip_suffix("/s").unwrap_or(trimmed);
+    let unit = unit.strip_suffix(['B', 'b']).unwrap_or(unit);
+    let split = unit
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(unit.len());
+    let (number, suffix) = unit.split_at(split);
+    let number: f64 = number
+        .parse()
+        .map_err(|_| format!("invalid bandwidth '{}'", value))?;
+    let multiplier: f64 = match suffix.trim().to_ascii_lowercase().as_str() {
+        "" => 1.0,
+        "k" => 1e3,
+        "m" => 1e6,
+        "g" => 1e9,
+        "t" => 1e12,
+        "ki" => 1024.0,
+        "mi" => 1024.0 * 1024.0,
+        "gi" => 1024.0 * 1024.0 * 1024.0,
+        "ti" => 1024.0 * 1024.0 * 1024.0 * 1024.0,
+        other => return Err(format!("unknown bandwidth unit '{}' in '{}'", other, value)),
+    };
+    let bytes = (number * multiplier).round();
+    if bytes < 1.0 {
+        return Err(format!("bandwidth '{}' must be at least 1 byte/s", value));
+    }
+    Ok(bytes as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verifies decimal and binary units, with and without the `B` and `/s` suffixes.
+    #[test]
+    fn parses_bandwidth_units() {
+        assert_eq!(parse_bandwidth("500MiB/s"), Ok(500 * 1024 * 1024));
+        assert_eq!(parse_bandwidth("1.5GB/s"), Ok(1_500_000_000));
+        assert_eq!(parse_bandwidth("800M"), Ok(800_000_000));
+        assert_eq!(parse_bandwidth("64KiB"), Ok(64 * 1024));
+        assert_eq!(parse_bandwidth("1048576"), Ok(1_048_576));
+        assert!(parse_bandwidth("fast").is_err());
+        assert!(parse_bandwidth("10XB/s").is_err());
+        assert!(parse_bandwidth("0").is_err());
+    }
+
+    /// Ensures the bucket allows a one-second burst, then charges debt at the refill rate.
+    #[test]
+    fn bucket_waits_off_debt_at_the_refill_rate() {
+        let bucket = TokenBucket::new(100.0);
+        let start = bucket.state.lock().unwrap().updated;
+
+        assert_eq!(bucket.reserve(100.0, start), Duration::ZERO);
+        assert_eq!(bucket.reserve(50.0, start), Duration::from_millis(500));
+        // Half a second later the debt is repaid; a request larger than the bucket waits.
+        let later = start + Duration::from_millis(500);
+        assert_eq!(bucket.reserve(200.0, later), Duration::from_secs(2));
+    }
+}
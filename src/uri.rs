@@ -0,0 +1,213 @@
+use std::fmt;
+
+/// Object, prefix or bucket named by an `s3://bucket/key` URI or an access point ARN.
+/// A version can be pinned with a trailing `?versionId=...`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct S3Uri {
+    /// Bucket name, or the access point ARN (which S3 accepts in place of a bucket)
+    pub bucket: String,
+    /// Object key or prefix; empty for the whole bucket
+    pub key: String,
+    pub version_id: Option<String>,
+}
+
+impl S3Uri {
+    /// Parse `s3://bucket/key`, `s3://bucket/prefix/`, `s3://bucket`, and access point
+    /// ARNs with or without the `s3://` scheme, such as
+    /// `arn:aws:s3:us-west-2:123456789012:accesspoint/my-ap/key`.
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let (location, version_id) = match value.rsplit_once("?versionId=") {
+            Some((_, "")) => return Err(format!("empty versionId in '{}'", value)),
+            Some((location, version_id)) => (location, Some(version_id.to_string())),
+            None => (value, None),
+        };
+        let location = location.strip_prefix("s3://").unwrap_or(location);
+
+        let (bucket, key) = if location.starts_with("arn:") {
+            split_access_point_arn(location)
+                .ok_or_else(|| format!("'{}' is not an S3 access point ARN", value))?
+        } else if value.starts_with("s3://") {
+            location.split_once('/').unwrap_or((location, ""))
+        } else {
+            return Err(format!(
+                "expected s3://bucket/key or an access point ARN, got '{}'",
+                value
+            ));
+        };
+        if bucket.is_empty() {
+            return Err(format!("missing bucket in '{}'", value));
+        }
+        Ok(Self {
+            bucket: bucket.to_string(),
+            key: key.to_string(),
+            version_id,
+        })
+    }
+
+    /// Whether the URI names a prefix rather than one object: the bucket root or a key
+    /// ending in `/`.
+    pub fn is_prefix(&self) -> bool {
+        self.key.is_empty() || self.key.ends_with('/')
+    }
+
+    /// Last path segment of the key (the file name of an object).
+    pub fn file_name(&self) -> &str {
+        self.key.rsplit('/').next().unwrap_or_default()
+    }
+}
+
+impl fmt::Display for S3Uri {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "s3://{}/{}", self.bucket, self.key)?;
+        if let Some(version_id) = &self.version_id {
+            write!(f, "?versionId={}", version_id)?;
+        }
+        Ok(())
+    }
+}
+
+/// Keys copied between a source and a destination URI.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CopyScope {
+    Object {
+        source_key: String,
+        source_version_id: Option<String>,
+        dest_key: String,
+    },
+    Prefix {
+        source_prefix: String,
+        dest_prefix: String,
+    },
+}
+
+impl CopyScope {
+    /// A source ending in `/` (or a bare bucket) is copied as a prefix; an object
+    /// copied into a destination prefix keeps its file name.
+    pub fn between(source: &S3Uri, dest: &S3Uri) -> anyhow::Result<Self> {
+        if dest.version_id.is_some() {
+            anyhow::bail!("The destination {} cannot name a version", dest);
+        }
+        if !source.is_prefix() {
+            let dest_key = if dest.is_prefix() {
+                format!("{}{}", dest.key, source.file_name())
+            } else {
+                dest.key.clone()
+            };
+            return Ok(Self::Object {
+                source_key: source.key.clone(),
+                source_version_id: source.version_id.clone(),
+                dest_key,
+            });
+        }
+        if source.version_id.is_some() {
+            anyhow::bail!("A version ID names one object, not the prefix {}", source);
+        }
+        if !dest.is_prefix() {
+            anyhow::bail!(
+                "Copying the prefix {} needs a destination prefix ending in '/', got {}",
+                source,
+                dest
+            );
+        }
+        Ok(Self::Prefix {
+            source_prefix: source.key.clone(),
+            dest_prefix: dest.key.clone(),
+        })
+    }
+}
+
+/// Split an access point ARN into the access point (used as the bucket) and the key
+/// after it. Covers regional, multi-region and Outposts access points.
+fn split_access_point_arn(arn: &str) -> Option<(&str, &str)> {
+    let fields: Vec<&str> = arn.splitn(6, ':').collect();
+    let [_, _, service, _, _, resource] = fields[..] else {
+        return None;
+    };
+    // Segments of the resource up to and including the access point name.
+    let name_segments = match service {
+        "s3" if resource.starts_with("accesspoint/") => 2,
+        "s3-outposts" if resource.starts_with("outpost/") => 4,
+        _ => return None,
+    };
+    let mut end = arn.len() - resource.len();
+    let mut segments = resource.split('/');
+    for index in 0..name_segments {
+        let segment = segments.next().filter(|s| !s.is_empty())?;
+        if index == 2 && segment != "accesspoint" {
+            return None;
+        }
+        end += segment.len() + usize::from(index > 0);
+    }
+    let key = arn[end..].strip_prefix('/').unwrap_or_default();
+    Some((&arn[..end], key))
+}
+
+/// Whether a bucket name is an access point ARN.
+pub fn is_access_point(bucket: &str) -> bool {
+    bucket.starts_with("arn:")
+}
+
+/// Region of an access point ARN; `None` for bucket names and multi-region access points.
+pub fn access_point_region(bucket: &str) -> Option<&str> {
+    if !is_access_point(bucket) {
+        return None;
+    }
+    bucket.split(':').nth(3).filter(|region| !region.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verifies objects, prefixes, bucket roots and pinned versions.
+    #[test]
+    fn parses_s3_uris() {
+        let object = S3Uri::parse("s3://src/path/data.iso").unwrap();
+        assert_eq!(
+            (object.bucket.as_str(), object.key.as_str()),
+            ("src", "path/data.iso")
+        );
+        assert!(!object.is_prefix());
+        assert_eq!(object.file_name(), "data.iso");
+
+        assert!(S3Uri::parse("s3://dst/path/").unwrap().is_prefix());
+        let bucket = S3Uri::parse("s3://dst").unwrap();
+        assert!(bucket.key.is_empty() && bucket.is_prefix());
+
+        let versioned = S3Uri::parse("s3://src/report.csv?versionId=3HL4kqtJ").unwrap();
+        assert_eq!(versioned.key, "report.csv");
+        assert_eq!(versioned.version_id.as_deref(), Some("3HL4kqtJ"));
+        assert_eq!(
+            versioned.to_string(),
+            "s3://src/report.csv?versionId=3HL4kqtJ"
+        );
+
+        assert!(S3Uri::parse("src/data.iso").is_err());
+        assert!(S3Uri::parse("https://src/data.iso").is_err());
+        assert!(S3Uri::parse("s3:///data.iso").is_err());
+        assert!(S3Uri::parse("s3://src/a?versionId=").is_err());
+    }
+
+    /// Verifies access point ARNs keep the access point as the bucket and expose its region.
+    #[test]
+    fn parses_access_point_arns() {
+        let arn = "arn:aws:s3:us-west-2:123456789012:accesspoint/my-ap";
+        let uri = S3Uri::parse(&format!("s3://{}/logs/a.gz", arn)).unwrap();
+        assert_eq!((uri.bucket.as_str(), uri.key.as_str()), (arn, "logs/a.gz"));
+        assert_eq!(S3Uri::parse(arn).unwrap().key, "");
+        assert_eq!(access_point_region(&uri.bucket), Some("us-west-2"));
+
+        let outpost = "arn:aws:s3-outposts:us-east-1:123456789012:outpost/op-01ac5d28a6a232904/accesspoint/ap";
+        let uri = S3Uri::parse(&format!("{}/data/", outpost)).unwrap();
+        assert_eq!((uri.bucket.as_str(), uri.key.as_str()), (outpost, "data/"));
+
+        let multi_region =
+            S3Uri::parse("arn:aws:s3::123456789012:accesspoint/mfzwi23gnjvgw.mrap/k").unwrap();
+        assert_eq!(multi_region.key, "k");
+        assert_eq!(access_point_region(&multi_region.bucket), None);
+        assert_eq!(access_point_region("plain-bucket"), None);
+
+        assert!(S3Uri::parse("arn:aws:s3:::my-bucket/key").is_err());
+        assert!(S3Uri::parse("arn:aws:s3-outposts:us-east-1:1:outpost/op-1/bucket/b").is_err());
+    }
+}
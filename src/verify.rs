@@ -0,0 +1,208 @@
+use anyhow::{Context, Result};
+use aws_sdk_s3::Client;
+use aws_sdk_s3::types::ChecksumAlgorithm;
+use md5::Md5;
+use sha2::{Digest, Sha256};
+
+/// Byte range compared per request pair by `--verify-integrity deep`.
+pub const DEEP_VERIFY_RANGE_SIZE: i64 = 64 * 1024 * 1024;
+
+/// Local digest used to compare source and destination ranges.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DigestAlgorithm {
+    Crc32c,
+    Sha256,
+}
+
+impl DigestAlgorithm {
+    /// SHA-256 when the copy asked for a SHA checksum, CRC32C (much cheaper) otherwise.
+    pub fn for_checksum(algorithm: Option<&ChecksumAlgorithm>) -> Self {
+        match algorithm {
+            Some(ChecksumAlgorithm::Sha256 | ChecksumAlgorithm::Sha1) => Self::Sha256,
+            _ => Self::Crc32c,
+        }
+    }
+
+    fn hasher(self) -> RangeHasher {
+        match self {
+            Self::Crc32c => RangeHasher::Crc32c(0),
+            Self::Sha256 => RangeHasher::Sha256(Box::default()),
+        }
+    }
+}
+
+enum RangeHasher {
+    Crc32c(u32),
+    Sha256(Box<Sha256>),
+}
+
+impl RangeHasher {
+    fn update(&mut self, data: &[u8]) {
+        match self {
+            Self::Crc32c(crc) => *crc = crc32c::crc32c_append(*crc, data),
+            Self::Sha256(hasher) => hasher.update(data),
+        }
+    }
+
+    fn finish(self) -> Vec<u8> {
+        match self {
+            Self::Crc32c(crc) => crc.to_be_bytes().to_vec(),
+            Self::Sha256(hasher) => hasher.finalize().to_vec(),
+        }
+    }
+}
+
+/// Digest of one byte range of an object.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RangeDigest {
+    pub start: i64,
+    pub end: i64,
+    pub source: Vec<u8>,
+    pub dest: Vec<u8>,
+}
+
+/// The lowest byte range whose digests differ.
+pub fn first_mismatch(digests: &[RangeDigest]) -> Option<(i64, i64)> {
+    digests
+        .iter()
+        .filter(|d| d.source != d.dest)
+        .map(|d| (d.start, d.end))
+        .min()
+}
+
+/// ETag S3 assigns to a multipart upload of these parts: the MD5 of the concatenated
+/// binary part MD5s, followed by `-<part count>`. `None` if any part ETag is not an MD5.
+pub fn multipart_etag<S: AsRef<str>>(part_etags: &[S]) -> Option<String> {
+    if part_etags.is_empty() {
+        return None;
+    }
+    let mut hasher = Md5::new();
+    for etag in part_etags {
+        hasher.update(decode_md5_hex(etag.as_ref().trim_matches('"'))?);
+    }
+    let digest: String = hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    Some(format!("\"{}-{}\"", digest, part_etags.len()))
+}
+
+fn decode_md5_hex(hex: &str) -> Option<[u8; 16]> {
+    if hex.len() != 32 {
+        return None;
+    }
+    let mut bytes = [0u8; 16];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(hex.get(i * 2..i * 2 + 2)?, 16).ok()?;
+    }
+    Some(bytes)
+}
+
+/// Ranged GET of `start..=end`, hashed as the body streams in (nothing is buffered).
+#[allow(clippy::too_many_arguments)]
+pub async fn digest_range(
+    client: &Client,
+    bucket: &str,
+    key: &str,
+    version_id: Option<String>,
+    if_match: Option<String>,
+    start: i64,
+    end: i64,
+    algorithm: DigestAlgorithm,
+) -> Result<Vec<u8>> {
+    let response = client
+        .get_object()
+        .bucket(bucket)
+        .key(key)
+        .set_version_id(version_id)
+        .set_if_match(if_match)
+        .range(format!("bytes={}-{}", start, end))
+        .send()
+        .await
+        .with_context(|| {
+            format!(
+                "Failed to read s3://{}/{} (bytes {}-{}) for verification",
+                bucket, key, start, end
+            )
+        })?;
+    let mut body = response.body;
+    let mut hasher = algorithm.hasher();
+    while let Some(chunk) = body.try_next().await.with_context(|| {
+        format!(
+            "Failed to stream s3://{}/{} (bytes {}-{}) for verification",
+            bucket, key, start, end
+        )
+    })? {
+        hasher.update(&chunk);
+    }
+    Ok(hasher.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verifies the digests against known vectors, including incremental updates.
+    #[test]
+    fn digests_match_reference_values() {
+        let mut crc = DigestAlgorithm::Crc32c.hasher();
+        crc.update(b"1234");
+        crc.update(b"56789");
+        assert_eq!(crc.finish(), 0xE306_9283_u32.to_be_bytes().to_vec());
+
+        let mut sha = DigestAlgorithm::Sha256.hasher();
+        sha.update(b"abc");
+        assert_eq!(
+            sha.finish()[..4],
+            [0xba, 0x78, 0x16, 0xbf],
+            "SHA-256(\"abc\") starts with ba7816bf"
+        );
+
+        assert_eq!(
+            DigestAlgorithm::for_checksum(Some(&ChecksumAlgorithm::Sha256)),
+            DigestAlgorithm::Sha256
+        );
+        assert_eq!(
+            DigestAlgorithm::for_checksum(Some(&ChecksumAlgorithm::Crc32)),
+            DigestAlgorithm::Crc32c
+        );
+    }
+
+    /// Verifies the multipart ETag formula against a known two-part upload.
+    #[test]
+    fn multipart_etag_matches_s3_formula() {
+        // Parts "a" and "b": MD5s 0cc175b9c0f1b6a831c399e269772661 and 92eb5ffee6ae2fec3ad71c777531578f
+        let parts = [
+            "\"0cc175b9c0f1b6a831c399e269772661\"",
+            "92eb5ffee6ae2fec3ad71c777531578f",
+        ];
+        let mut concatenated = Vec::new();
+        concatenated.extend(Md5::digest(b"a"));
+        concatenated.extend(Md5::digest(b"b"));
+        let expected: String = Md5::digest(&concatenated)
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect();
+
+        assert_eq!(multipart_etag(&parts), Some(format!("\"{}-2\"", expected)));
+        assert_eq!(multipart_etag(&["dry-run-etag"]), None);
+        assert_eq!(multipart_etag::<&str>(&[]), None);
+    }
+
+    /// Ensures the reported mismatch is the lowest differing range, whatever the order.
+    #[test]
+    fn first_mismatch_is_lowest_offset() {
+        let digest = |start: i64, same: bool| RangeDigest {
+            start,
+            end: start + 9,
+            source: vec![1],
+            dest: vec![if same { 1 } else { 2 }],
+        };
+        assert_eq!(first_mismatch(&[digest(0, true), digest(10, true)]), None);
+        assert_eq!(
+            first_mismatch(&[digest(30, false), digest(0, true), digest(10, false)]),
+            Some((10, 19))
+        );
+    }
+}